@@ -44,6 +44,7 @@ pub enum Descriptor {
     Result(Box<Descriptor>),
     Unit,
     NonNull,
+    JsString,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -150,6 +151,7 @@ impl Descriptor {
             UNIT => Descriptor::Unit,
             CLAMPED => Descriptor::_decode(data, true),
             NONNULL => Descriptor::NonNull,
+            JS_STRING => Descriptor::JsString,
             other => panic!("unknown descriptor: {other}"),
         }
     }
@@ -88,5 +88,6 @@ intrinsics! {
         ExternrefHeapLiveCount = "__wbindgen_externref_heap_live_count",
         InitExternrefTable = "__wbindgen_init_externref_table",
         PanicError = "__wbindgen_panic_error",
+        SpawnThread = "__wbindgen_spawn_thread",
     }
 }
@@ -33,6 +33,9 @@ pub struct Builder<'a, 'b> {
     catch: bool,
     /// Whether or not we're logging the error coming out of this intrinsic
     log_error: bool,
+    /// The type of error thrown by an export (or its promise rejects with), if
+    /// it's known.
+    throws: Option<String>,
 }
 
 /// Helper struct used to create JS to process all instructions in an adapter
@@ -104,6 +107,7 @@ impl<'a, 'b> Builder<'a, 'b> {
             method: None,
             classless_this: false,
             catch: false,
+            throws: None,
         }
     }
 
@@ -127,6 +131,10 @@ impl<'a, 'b> Builder<'a, 'b> {
         self.log_error = log;
     }
 
+    pub fn throws(&mut self, throws: Option<&str>) {
+        self.throws = throws.map(String::from);
+    }
+
     pub fn process(
         &mut self,
         adapter: &Adapter,
@@ -540,6 +548,7 @@ impl<'a, 'b> Builder<'a, 'b> {
                 ret.push_str(v);
             }
         }
+        self.push_throws(&mut ret);
         ret
     }
 
@@ -569,8 +578,23 @@ impl<'a, 'b> Builder<'a, 'b> {
             ts_doc.push_str("@returns ");
             ts_doc.push_str(ret_desc);
         }
+        self.push_throws(&mut ts_doc);
         ts_doc
     }
+
+    /// Appends a `@throws` tag to a doc comment if the type of error thrown is
+    /// known. For async functions this is what the returned promise rejects
+    /// with.
+    fn push_throws(&self, doc: &mut String) {
+        if let Some(throws) = &self.throws {
+            if !doc.is_empty() && !doc.ends_with('\n') {
+                doc.push('\n');
+            }
+            doc.push_str("@throws {");
+            doc.push_str(throws);
+            doc.push('}');
+        }
+    }
 }
 
 impl<'a, 'b> JsBuilder<'a, 'b> {
@@ -655,6 +679,24 @@ impl<'a, 'b> JsBuilder<'a, 'b> {
         self.prelude(&format!("_assertBoolean({arg});"));
     }
 
+    fn assert_string(&mut self, arg: &str) {
+        if !self.cx.config.debug {
+            return;
+        }
+        self.cx.expose_assert_string();
+        self.prelude(&format!("_assertString({arg});"));
+    }
+
+    fn assert_optional_string(&mut self, arg: &str) {
+        if !self.cx.config.debug {
+            return;
+        }
+        self.cx.expose_is_like_none();
+        self.prelude(&format!("if (!isLikeNone({arg})) {{"));
+        self.assert_string(arg);
+        self.prelude("}");
+    }
+
     fn assert_optional_number(&mut self, arg: &str) {
         if !self.cx.config.debug {
             return;
@@ -1427,6 +1469,11 @@ fn instruction(
                 // Borrowed closure without destructor
                 let i = js.tmp();
                 js.prelude(&format!("var state{i} = {{a: {a}, b: {b}}};"));
+                // Dropping a borrowed closure clears both pointers, while
+                // calling a mutable one only clears `a`.
+                let dropped_check = js
+                    .cx
+                    .closure_dropped_check(&format!("state{i}.b === 0"), &format!("cb{i}"));
                 let args = (0..*nargs)
                     .map(|i| format!("arg{i}"))
                     .collect::<Vec<_>>()
@@ -1437,7 +1484,7 @@ fn instruction(
                     // internal pointers while it's being invoked.
                     js.prelude(&format!(
                         "var cb{i} = ({args}) => {{
-                            const a = state{i}.a;
+                            {dropped_check}const a = state{i}.a;
                             state{i}.a = 0;
                             try {{
                                 return {wrapper}(a, state{i}.b, {args});
@@ -1447,9 +1494,16 @@ fn instruction(
                         }};",
                     ));
                 } else {
-                    js.prelude(&format!(
-                        "var cb{i} = ({args}) => {wrapper}(state{i}.a, state{i}.b, {args});",
-                    ));
+                    let call = format!("{wrapper}(state{i}.a, state{i}.b, {args})");
+                    if dropped_check.is_empty() {
+                        js.prelude(&format!("var cb{i} = ({args}) => {call};"));
+                    } else {
+                        js.prelude(&format!(
+                            "var cb{i} = ({args}) => {{
+                                {dropped_check}return {call};
+                            }};",
+                        ));
+                    }
                 }
 
                 match dtor {
@@ -1594,6 +1648,16 @@ fn instruction(
             let val = js.pop();
             js.push(format!("{val} === 0 ? undefined : {val} >>> 0"));
         }
+
+        Instruction::AssertString { optional } => {
+            let val = js.pop();
+            if *optional {
+                js.assert_optional_string(&val);
+            } else {
+                js.assert_string(&val);
+            }
+            js.push(val);
+        }
     }
     Ok(())
 }
@@ -344,6 +344,26 @@ impl<'a> Context<'a> {
             )?;
         }
 
+        // Export the class of panics caught with `panic=unwind`, so that JS can
+        // tell them apart with `instanceof`, unless the name is taken.
+        if self.has_intrinsic("panic_error") && !self.exports.contains_key("PanicError") {
+            define_export(
+                &mut self.exports,
+                "PanicError",
+                &[],
+                ExportEntry::Definition(ExportDefinition {
+                    identifier: "PanicError".to_string(),
+                    comments: None,
+                    definition: "".to_string(),
+                    ts_comments: Some(
+                        "/**\n * Thrown when an exported Rust function panics.\n */\n".to_string(),
+                    ),
+                    ts_definition: "class PanicError extends Error {\n  /** Where the panic happened, e.g. `src/lib.rs:10:5`. */\n  readonly location?: string;\n}\n".to_string(),
+                    private: false,
+                }),
+            )?;
+        }
+
         let body = std::mem::take(&mut self.globals);
         let body = body.trim();
 
@@ -1932,11 +1952,19 @@ if (require('worker_threads').isMainThread) {{
             num: view.num,
         };
         self.expose_wasm_vector_len();
+        // Check `arg` before `malloc`, which may grow memory and detach it
+        // through no fault of the caller.
+        let check = if self.config.debug {
+            self.expose_assert_view_not_stale();
+            "_assertViewNotStale(arg);\n"
+        } else {
+            ""
+        };
         intrinsic(&mut self.intrinsics, ret.to_string().into(), || {
             format!(
                 "
                 function {ret}(arg, malloc) {{
-                    const ptr = malloc(arg.length * {size}, {size}) >>> 0;
+                    {check}const ptr = malloc(arg.length * {size}, {size}) >>> 0;
                     {view}().set(arg, ptr / {size});
                     WASM_VECTOR_LEN = arg.length;
                     return ptr;
@@ -2451,6 +2479,31 @@ if (require('worker_threads').isMainThread) {{
         }
     }
 
+    fn expose_assert_view_not_stale(&mut self) {
+        intrinsic(&mut self.intrinsics, "assert_view_not_stale".into(), || {
+            "
+            function _assertViewNotStale(view) {
+                if (!ArrayBuffer.isView(view)) return;
+                let detached = view.buffer.detached;
+                // Engines without `ArrayBuffer.prototype.detached` report an
+                // empty buffer, but refuse to create new views into it.
+                if (detached === undefined && view.buffer.byteLength === 0) {
+                    try {
+                        new Uint8Array(view.buffer);
+                        detached = false;
+                    } catch (e) {
+                        detached = true;
+                    }
+                }
+                if (detached === true) {
+                    throw new Error('attempted to use a typed array view into Wasm memory after the memory grew; create the view again, e.g. by passing a `TypedArrayView`, after anything that may allocate');
+                }
+            }
+            "
+            .into()
+        });
+    }
+
     fn expose_assert_class(&mut self) {
         intrinsic(&mut self.intrinsics, "assert_class".into(), || {
             "
@@ -2691,6 +2744,16 @@ if (require('worker_threads').isMainThread) {{
         });
     }
 
+    fn expose_assert_string(&mut self) {
+        intrinsic(&mut self.intrinsics, "assert_string".into(), || {
+            "
+            function _assertString(s) {
+                if (typeof(s) !== 'string') throw new Error(`expected a string argument, found ${typeof(s)}`);
+            }
+            ".into()
+        });
+    }
+
     fn expose_assert_non_null(&mut self) {
         intrinsic(&mut self.intrinsics, "assert_non_null".into(), || {
             "
@@ -2725,6 +2788,7 @@ if (require('worker_threads').isMainThread) {{
                 state.a = 0;
                 CLOSURE_DTORS.unregister(state);\
                 ";
+            let dropped_check = self.closure_dropped_check("state.cnt === 0", "real");
             let (state_init, instance_check) = if self.config.generate_reset_state {
                 (
                     "const state = { a: arg0, b: arg1, cnt: 1, dtor, instance: __wbg_instance_id };",
@@ -2742,7 +2806,7 @@ if (require('worker_threads').isMainThread) {{
                 function makeMutClosure(arg0, arg1, dtor, f) {{
                     {state_init}
                     const real = (...args) => {{
-                        {instance_check}
+                        {instance_check}{dropped_check}
                         // First up with a closure we increment the internal reference
                         // count. This ensures that the Rust closure environment won't
                         // be deallocated while we're invoking it.
@@ -2784,6 +2848,7 @@ if (require('worker_threads').isMainThread) {{
                 state.a = 0;
                 CLOSURE_DTORS.unregister(state);\
                 ";
+            let dropped_check = self.closure_dropped_check("state.cnt === 0", "real");
             let (state_init, instance_check) = if self.config.generate_reset_state {
                 (
                     "const state = { a: arg0, b: arg1, cnt: 1, dtor, instance: __wbg_instance_id };",
@@ -2801,7 +2866,7 @@ if (require('worker_threads').isMainThread) {{
                 function makeClosure(arg0, arg1, dtor, f) {{
                     {state_init}
                     const real = (...args) => {{
-                        {instance_check}
+                        {instance_check}{dropped_check}
                         // First up with a closure we increment the internal reference
                         // count. This ensures that the Rust closure environment won't
                         // be deallocated while we're invoking it.
@@ -2826,6 +2891,29 @@ if (require('worker_threads').isMainThread) {{
         });
     }
 
+    /// Returns a statement which throws if `dropped`, naming where `closure`
+    /// was created if known, or nothing without `--debug`.
+    ///
+    /// The location is only known with the `closure-diagnostics` feature of
+    /// the `wasm-bindgen` crate, which stores it as `_wbg_origin`.
+    fn closure_dropped_check(&mut self, dropped: &str, closure: &str) -> String {
+        if !self.config.debug {
+            return String::new();
+        }
+        intrinsic(&mut self.intrinsics, "closure_dropped_error".into(), || {
+            "
+            function _closureDroppedError(closure) {
+                const origin = closure._wbg_origin;
+                return new Error(origin === undefined
+                    ? 'closure invoked after being dropped'
+                    : `closure invoked after being dropped, it was created at ${origin}`);
+            }
+            "
+            .into()
+        });
+        format!("if ({dropped}) throw _closureDroppedError({closure});\n")
+    }
+
     fn expose_closure_finalization(&mut self) {
         intrinsic(&mut self.intrinsics, "closure_finalization".into(), || {
             format!(
@@ -2861,6 +2949,113 @@ if (require('worker_threads').isMainThread) {{
         });
     }
 
+    fn expose_spawn_thread(&mut self) -> Result<(), Error> {
+        if self.has_intrinsic("spawn_thread") {
+            return Ok(());
+        }
+        let entry = match self.aux.thread_entry {
+            Some(id) => self.export_name_of(id),
+            None => bail!("failed to find `__wbindgen_thread_entry` for spawning threads"),
+        };
+        // Once the closure returned, or failed, the thread frees its TLS and
+        // stack.
+        let destroy = match self.aux.thread_destroy {
+            Some(id) => format!("wasm.{}();", self.export_name_of(id)),
+            None => String::new(),
+        };
+        // The worker instantiates the module from the generated JS with the
+        // memory of the spawning thread, then runs the closure `work` points
+        // to. `onExit` is called once the worker exited, with the error it
+        // failed with, if any.
+        let spawn = match self.config.mode {
+            OutputMode::Web => format!(
+                "let threadWorkerUrl;
+                function spawnThread(module, memory, work, onExit) {{
+                    if (threadWorkerUrl === undefined) {{
+                        const src = `import {{ initSync }} from ${{JSON.stringify(import.meta.url)}};
+                            onmessage = ({{ data: [module, memory, work] }}) => {{
+                                const wasm = initSync({{ module, memory }});
+                                try {{
+                                    wasm.{entry}(work);
+                                }} finally {{
+                                    {destroy}
+                                }}
+                                postMessage(null);
+                                close();
+                            }};`;
+                        threadWorkerUrl = URL.createObjectURL(new Blob([src], {{ type: 'text/javascript' }}));
+                    }}
+                    const worker = new Worker(threadWorkerUrl, {{ type: 'module' }});
+                    worker.onmessage = () => onExit(undefined);
+                    worker.onerror = (e) => {{
+                        e.preventDefault();
+                        worker.terminate();
+                        onExit(new Error(e.message));
+                    }};
+                    worker.postMessage([module, memory, work]);
+                }}
+                "
+            ),
+            OutputMode::Node { module } => {
+                let worker = self.import_name(&JsImport {
+                    name: JsImportName::Module {
+                        module: "node:worker_threads".to_string(),
+                        name: "Worker".to_string(),
+                    },
+                    fields: Vec::new(),
+                })?;
+                let run = format!(
+                    "const wasm = initSync({{ module: workerData.module, memory: workerData.memory }});
+                    try {{
+                        wasm.{entry}(workerData.work);
+                    }} finally {{
+                        {destroy}
+                    }}"
+                );
+                let load = if module {
+                    format!(
+                        "import(${{JSON.stringify(import.meta.url)}}).then(({{ initSync }}) => {{
+                            {run}
+                        }});"
+                    )
+                } else {
+                    format!(
+                        "const {{ initSync }} = require(${{JSON.stringify(__filename)}});
+                        {run}"
+                    )
+                };
+                format!(
+                    "function spawnThread(module, memory, work, onExit) {{
+                        const src = `const {{ workerData }} = require('node:worker_threads');
+                            {load}`;
+                        const worker = new {worker}(src, {{ eval: true, workerData: {{ module, memory, work }} }});
+                        let error;
+                        worker.on('error', (e) => {{ error = e; }});
+                        worker.on('exit', (code) => {{
+                            if (error === undefined && code !== 0) {{
+                                error = new Error(`thread exited with code ${{code}}`);
+                            }}
+                            onExit(error);
+                        }});
+                    }}
+                    "
+                )
+            }
+            // The other targets instantiate the module as soon as the generated
+            // JS is imported (`--target module`, `--target deno`), leave it to a
+            // bundler (`--target bundler`), or don't have a URL to load the
+            // generated JS from (`--target no-modules`), so a worker can't
+            // instantiate it with the memory of the spawning thread.
+            _ => bail!(
+                "`wasm_bindgen::thread::spawn` is only supported with `--target web`, \
+                 `--target nodejs` and `--target experimental-nodejs-module`, as the \
+                 other targets can't instantiate the module in a worker with a shared memory"
+            ),
+        };
+        intrinsic(&mut self.intrinsics, "spawn_thread".into(), || spawn.into());
+        Ok(())
+    }
+
     fn generate_reset_state(&mut self) -> Result<(), Error> {
         self.global("let __wbg_instance_id = 0;");
 
@@ -3317,6 +3512,7 @@ if (require('worker_threads').isMainThread) {{
                 generate_jsdoc = export.generate_jsdoc;
                 ret_ty_override = &export.fn_ret_ty_override;
                 ret_desc = &export.fn_ret_desc;
+                builder.throws(export.throws.as_deref());
                 match &export.kind {
                     AuxExportKind::Function(_) => {}
                     AuxExportKind::FunctionThis(_) => {
@@ -3381,10 +3577,11 @@ if (require('worker_threads').isMainThread) {{
 
                 let ts_sig = export.generate_typescript.then_some(ts_sig.as_str());
 
-                // only include `ts_doc` for format if there were arguments or a return var description
-                // this is because if there are no arguments or return var description, `ts_doc`
-                // provides no additional value on top of what `ts_sig` already does
+                // only include `ts_doc` for format if there were arguments or a return var description,
+                // or a known thrown type. this is because otherwise `ts_doc` provides no additional
+                // value on top of what `ts_sig` already does
                 let ts_doc_opts = (ret_desc.is_some()
+                    || export.throws.is_some()
                     || args
                         .as_ref()
                         .is_some_and(|v| v.iter().any(|arg| arg.desc.is_some())))
@@ -4379,6 +4576,15 @@ if (require('worker_threads').isMainThread) {{
                 self.expose_panic_error();
                 format!("new PanicError({})", args[0])
             }
+
+            Intrinsic::SpawnThread => {
+                assert_eq!(args.len(), 4);
+                self.expose_spawn_thread()?;
+                format!(
+                    "spawnThread({}, {}, {}, {})",
+                    args[0], args[1], args[2], args[3]
+                )
+            }
         };
         Ok(expr)
     }
@@ -41,12 +41,14 @@ pub struct Bindgen {
     encode_into: EncodeInto,
     split_linked_modules: bool,
     generate_reset_state: bool,
+    thread_stack_size: u32,
 }
 
 pub struct Output {
     module: walrus::Module,
     stem: String,
     generated: Generated,
+    emitted: Vec<PathBuf>,
 }
 
 struct Generated {
@@ -110,6 +112,7 @@ impl Bindgen {
             omit_default_module_path: true,
             split_linked_modules: false,
             generate_reset_state: false,
+            thread_stack_size: transforms::threads::DEFAULT_THREAD_STACK_SIZE,
         }
     }
 
@@ -297,6 +300,21 @@ impl Bindgen {
         self
     }
 
+    /// Sets the size of the stack allocated for each thread other than the
+    /// main one when threads are enabled, which defaults to 2MB.
+    pub fn thread_stack_size(&mut self, size: u32) -> Result<&mut Bindgen, Error> {
+        let page_size = transforms::threads::PAGE_SIZE;
+        if size == 0 || size % page_size != 0 || size > i32::MAX as u32 {
+            bail!(
+                "the thread stack size must be a nonzero multiple of {page_size} bytes \
+                 which is at most {} bytes",
+                i32::MAX
+            );
+        }
+        self.thread_stack_size = size;
+        Ok(self)
+    }
+
     pub fn generate<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
         self.generate_output()?.emit(path.as_ref())
     }
@@ -358,7 +376,7 @@ impl Bindgen {
             bail!("--experimental-reset-state-function is only supported for --target module, --target web, or --target nodejs")
         }
 
-        let thread_count = transforms::threads::run(&mut module)
+        let thread_count = transforms::threads::run(&mut module, self.thread_stack_size)
             .with_context(|| "failed to prepare module for threading")?;
 
         // If requested, turn all mangled symbols into prettier unmangled
@@ -491,6 +509,7 @@ impl Bindgen {
             module,
             stem: stem.to_string(),
             generated,
+            emitted: Vec::new(),
         })
     }
 
@@ -687,14 +706,23 @@ impl Output {
         self._emit(out_dir.as_ref())
     }
 
+    /// The files written by the last call to `emit`, relative to its output
+    /// directory.
+    pub fn emitted_files(&self) -> &[PathBuf] {
+        &self.emitted
+    }
+
     fn _emit(&mut self, out_dir: &Path) -> Result<(), Error> {
         let wasm_name = format!("{}_bg", self.stem);
         let wasm_path = out_dir.join(&wasm_name).with_extension("wasm");
         fs::create_dir_all(out_dir)?;
 
+        let mut emitted = Vec::new();
+
         let wasm_bytes = self.module.emit_wasm();
         fs::write(&wasm_path, wasm_bytes)
             .with_context(|| format!("failed to write `{}`", wasm_path.display()))?;
+        emitted.push(wasm_path.clone());
 
         let gen = &self.generated;
 
@@ -707,6 +735,7 @@ impl Output {
                 fs::create_dir_all(path.parent().unwrap())?;
                 fs::write(&path, js)
                     .with_context(|| format!("failed to write `{}`", path.display()))?;
+                emitted.push(path);
             }
         }
 
@@ -715,6 +744,7 @@ impl Output {
             fs::create_dir_all(path.parent().unwrap())?;
             fs::write(&path, contents)
                 .with_context(|| format!("failed to write `{}`", path.display()))?;
+            emitted.push(path);
         }
 
         let is_genmode_nodemodule = matches!(gen.mode, OutputMode::Node { module: true });
@@ -735,6 +765,7 @@ impl Output {
             };
             let json = serde_json::to_string_pretty(&pj)?;
             fs::write(out_dir.join("package.json"), json)?;
+            emitted.push(out_dir.join("package.json"));
         }
 
         // And now that we've got all our JS and TypeScript, actually write it
@@ -752,16 +783,19 @@ impl Output {
 
         let js_path = out_dir.join(&self.stem).with_extension(extension);
         write(&js_path, reset_indentation(&gen.js))?;
+        emitted.push(js_path.clone());
 
         if let Some(start) = &gen.start {
             let js_path = out_dir.join(wasm_name).with_extension(extension);
             write(&js_path, reset_indentation(start))?;
+            emitted.push(js_path);
         }
 
         if gen.typescript {
             let ts_path = js_path.with_extension("d.ts");
             fs::write(&ts_path, reset_indentation(&gen.ts))
                 .with_context(|| format!("failed to write `{}`", ts_path.display()))?;
+            emitted.push(ts_path);
         }
 
         if gen.typescript {
@@ -769,8 +803,17 @@ impl Output {
             let ts = wasm2es6js::typescript(&self.module)?;
             fs::write(&ts_path, reset_indentation(&ts))
                 .with_context(|| format!("failed to write `{}`", ts_path.display()))?;
+            emitted.push(ts_path);
         }
 
+        self.emitted = emitted
+            .into_iter()
+            .map(|path| {
+                path.strip_prefix(out_dir)
+                    .map(Path::to_path_buf)
+                    .unwrap_or(path)
+            })
+            .collect();
         Ok(())
     }
 }
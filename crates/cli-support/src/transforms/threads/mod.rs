@@ -9,7 +9,7 @@ use walrus::{
 };
 
 pub const PAGE_SIZE: u32 = 1 << 16;
-const DEFAULT_THREAD_STACK_SIZE: u32 = 1 << 21; // 2MB
+pub const DEFAULT_THREAD_STACK_SIZE: u32 = 1 << 21; // 2MB
 const ATOMIC_MEM_ARG: MemArg = MemArg {
     align: 4,
     offset: 0,
@@ -49,10 +49,13 @@ pub fn is_enabled(module: &Module) -> bool {
 /// * A `start` function is injected (or prepended if one already exists)
 ///   which initializes memory for the first thread and otherwise allocates
 ///   thread ids for all threads.
-/// * Some stack space is prepared for each thread after the first one.
+/// * Some stack space, `stack_size` bytes by default, is prepared for each
+///   thread after the first one.
+/// * A `__wbindgen_thread_destroy` function is exported which frees the stack
+///   and TLS of a thread again.
 ///
 /// More and/or less may happen here over time, stay tuned!
-pub fn run(module: &mut Module) -> Result<Option<ThreadCount>, Error> {
+pub fn run(module: &mut Module, stack_size: u32) -> Result<Option<ThreadCount>, Error> {
     if !is_enabled(module) {
         return Ok(None);
     }
@@ -104,7 +107,7 @@ pub fn run(module: &mut Module) -> Result<Option<ThreadCount>, Error> {
             ValType::I32,
             true,
             false,
-            ConstExpr::Value(Value::I32(DEFAULT_THREAD_STACK_SIZE as i32)),
+            ConstExpr::Value(Value::I32(stack_size as i32)),
         ),
     };
 
@@ -112,7 +115,8 @@ pub fn run(module: &mut Module) -> Result<Option<ThreadCount>, Error> {
 
     let thread_count = inject_start(module, &tls, &stack, thread_counter_addr, memory)?;
 
-    // we expose a `__wbindgen_thread_destroy()` helper function that deallocates stack space.
+    // we expose a `__wbindgen_thread_destroy()` helper function that runs the destructors of
+    // thread-local values and deallocates TLS and stack space.
     //
     // ## Safety
     // After calling this function in a given agent, the instance should be considered
@@ -128,7 +132,7 @@ pub fn run(module: &mut Module) -> Result<Option<ThreadCount>, Error> {
     //   the leader should not call this function with the same set of parameters twice.
     // - Moreover, concurrent calls can lead to UB: the follower could be in the middle of a
     //   call while the leader is destroying its stack! You should make sure that this cannot happen.
-    inject_destroy(module, &tls, &stack, memory)?;
+    inject_destroy(module, &tls, &stack, stack_size, memory)?;
 
     Ok(Some(thread_count))
 }
@@ -344,9 +348,12 @@ fn inject_destroy(
     module: &mut Module,
     tls: &Tls,
     stack: &Stack,
+    stack_size: u32,
     memory: MemoryId,
 ) -> Result<(), Error> {
     let free = find_function(module, "__wbindgen_free")?;
+    // Older versions of the `wasm-bindgen` crate don't export this.
+    let dtors = find_function(module, "__wbindgen_thread_dtors").ok();
 
     let mut builder = FunctionBuilder::new(
         &mut module.types,
@@ -362,11 +369,21 @@ fn inject_destroy(
     // we're being called from the agent that must be destroyed and rely on its globals
     let tls_base = module.locals.add(ValType::I32);
     let stack_alloc = module.locals.add(ValType::I32);
-    let stack_size = module.locals.add(ValType::I32);
+    let stack_size_local = module.locals.add(ValType::I32);
 
-    // Ideally, at this point, we would destroy the values stored in TLS.
-    // We can't really do that without help from the standard library.
+    // Destroy the values stored in TLS first, which only works from the agent
+    // that is being destroyed. The values of `thread_local!`s of the standard
+    // library are still leaked, as it has no hook to destroy them with.
     // See https://github.com/wasm-bindgen/wasm-bindgen/pull/2769#issuecomment-1015775467.
+    if let Some(dtors) = dtors {
+        body.local_get(tls_base).if_else(
+            None,
+            |_| {},
+            |body| {
+                body.call(dtors);
+            },
+        );
+    }
 
     body.local_get(tls_base).if_else(
         None,
@@ -393,9 +410,9 @@ fn inject_destroy(
         |body| {
             // we're destroying somebody else's stack, so we can use our own
             body.local_get(stack_alloc)
-                .local_get(stack_size)
-                .i32_const(DEFAULT_THREAD_STACK_SIZE as i32)
-                .local_get(stack_size)
+                .local_get(stack_size_local)
+                .i32_const(stack_size as i32)
+                .local_get(stack_size_local)
                 .select(None)
                 .i32_const(16)
                 .call(free);
@@ -413,7 +430,10 @@ fn inject_destroy(
         },
     );
 
-    let destroy_id = builder.finish(vec![tls_base, stack_alloc, stack_size], &mut module.funcs);
+    let destroy_id = builder.finish(
+        vec![tls_base, stack_alloc, stack_size_local],
+        &mut module.funcs,
+    );
 
     module.exports.add("__wbindgen_thread_destroy", destroy_id);
 
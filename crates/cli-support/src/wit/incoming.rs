@@ -70,6 +70,14 @@ impl InstructionBuilder<'_, '_> {
                     &[AdapterType::I32]
                 )
             }
+            Descriptor::JsString => {
+                self.js_string(false);
+                self.late_instruction(
+                    &[AdapterType::js_string()],
+                    Instruction::I32FromExternrefOwned,
+                    &[AdapterType::I32],
+                );
+            }
             Descriptor::RustStruct(class) => {
                 self.instruction(
                     &[AdapterType::Struct(class.clone())],
@@ -207,6 +215,14 @@ impl InstructionBuilder<'_, '_> {
                     &[AdapterType::I32],
                 );
             }
+            Descriptor::JsString => {
+                self.js_string(false);
+                self.late_instruction(
+                    &[AdapterType::js_string()],
+                    Instruction::I32FromExternrefBorrow,
+                    &[AdapterType::I32],
+                );
+            }
             Descriptor::String | Descriptor::CachedString => {
                 // This allocation is cleaned up once it's received in Rust.
                 self.instruction(
@@ -281,6 +297,16 @@ impl InstructionBuilder<'_, '_> {
                     &[AdapterType::I32],
                 );
             }
+            Descriptor::JsString => {
+                self.js_string(true);
+                self.late_instruction(
+                    &[AdapterType::js_string().option()],
+                    Instruction::I32FromOptionExternref {
+                        table_and_alloc: None,
+                    },
+                    &[AdapterType::I32],
+                );
+            }
             Descriptor::I8 => self.in_option_sentinel32(AdapterType::S8),
             Descriptor::U8 => self.in_option_sentinel32(AdapterType::U8),
             Descriptor::I16 => self.in_option_sentinel32(AdapterType::S16),
@@ -459,6 +485,16 @@ impl InstructionBuilder<'_, '_> {
         self.output.extend_from_slice(outputs);
     }
 
+    /// Takes a JS string which stays a JS value, checking its type in debug
+    /// mode.
+    fn js_string(&mut self, optional: bool) {
+        let ty = match optional {
+            true => AdapterType::js_string().option(),
+            false => AdapterType::js_string(),
+        };
+        self.instruction(&[ty.clone()], Instruction::AssertString { optional }, &[ty]);
+    }
+
     fn number_i32(&mut self, input: AdapterType) {
         self.instruction(&[input], Instruction::Int32ToWasm, &[AdapterType::I32]);
     }
@@ -77,6 +77,7 @@ pub fn process(
         cx.discover_main()?;
     }
     cx.find_exn_store();
+    cx.find_thread_entry();
 
     cx.verify()?;
 
@@ -584,6 +585,13 @@ impl<'a> Context<'a> {
             None => return Ok(()),
             Some(d) => d.unwrap_function(),
         };
+        let throws = match self
+            .descriptors
+            .remove(&wasm_bindgen_shared::throws_descriptor_name(&wasm_name))
+        {
+            Some(Descriptor::RustStruct(name) | Descriptor::NamedExternref(name)) => Some(name),
+            _ => None,
+        };
 
         let Some((export_id, id)) = self.function_exports.get(&wasm_name).copied() else {
             bail!("{wasm_name} symbol is missing, \
@@ -673,6 +681,7 @@ impl<'a> Context<'a> {
                 variadic: export.function.variadic,
                 fn_ret_ty_override: export.function.ret_ty_override.map(String::from),
                 fn_ret_desc: export.function.ret_desc.map(String::from),
+                throws,
             },
         );
         Ok(())
@@ -1167,6 +1176,7 @@ impl<'a> Context<'a> {
                     variadic: false,
                     fn_ret_ty_override: None,
                     fn_ret_desc: None,
+                    throws: None,
                 },
             );
 
@@ -1202,6 +1212,7 @@ impl<'a> Context<'a> {
                     variadic: false,
                     fn_ret_ty_override: None,
                     fn_ret_desc: None,
+                    throws: None,
                 },
             );
         }
@@ -1636,6 +1647,25 @@ impl<'a> Context<'a> {
             .map(|p| p.1)
     }
 
+    /// Attempts to locate the `__wbindgen_thread_entry` function run by
+    /// spawned threads and stores it in our auxiliary information.
+    ///
+    /// This is only kept if the module spawns threads.
+    fn find_thread_entry(&mut self) {
+        let spawns_threads = self
+            .aux
+            .import_map
+            .values()
+            .any(|import| matches!(import, AuxImport::Intrinsic(Intrinsic::SpawnThread)));
+        if !spawns_threads {
+            return;
+        }
+        self.aux.thread_entry = self
+            .function_exports
+            .get("__wbindgen_thread_entry")
+            .map(|p| p.1);
+    }
+
     fn memory(&self) -> Result<MemoryId, Error> {
         self.memory
             .ok_or_else(|| anyhow!("failed to find memory declaration in module"))
@@ -1699,6 +1729,7 @@ fn verify_constructor_return(class: &str, ret: &Descriptor) -> Result<(), Error>
         | Descriptor::Char
         | Descriptor::CachedString
         | Descriptor::String
+        | Descriptor::JsString
         | Descriptor::Option(_)
         | Descriptor::Enum { .. }
         | Descriptor::Unit => {
@@ -65,6 +65,7 @@ pub struct WasmBindgenAux {
     pub exn_store: Option<walrus::FunctionId>,
     pub stack_pointer: Option<walrus::GlobalId>,
     pub thread_destroy: Option<walrus::FunctionId>,
+    pub thread_entry: Option<walrus::FunctionId>,
 
     /// The imported JSTag for catching JavaScript exceptions in Wasm.
     /// When this is `Some`, all imports with `catch` use Wasm catch wrappers
@@ -100,6 +101,9 @@ pub struct AuxExport {
     pub fn_ret_ty_override: Option<String>,
     /// Function's return description
     pub fn_ret_desc: Option<String>,
+    /// The class or named type of the error the function throws, or that its
+    /// promise rejects with, if known.
+    pub throws: Option<String>,
 }
 
 /// Information about a functions' argument
@@ -490,6 +494,9 @@ impl walrus::CustomSection for WasmBindgenAux {
         if let Some(id) = self.thread_destroy {
             roots.push_func(id);
         }
+        if let Some(id) = self.thread_entry {
+            roots.push_func(id);
+        }
         if let Some(id) = self.js_tag {
             roots.push_tag(id);
         }
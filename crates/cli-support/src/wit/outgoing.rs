@@ -50,13 +50,13 @@ impl InstructionBuilder<'_, '_> {
                     &[AdapterType::Externref],
                 );
             }
-            Descriptor::NamedExternref(name) => {
+            Descriptor::NamedExternref(_) | Descriptor::JsString => {
                 self.instruction(
                     &[AdapterType::I32],
                     Instruction::ExternrefLoadOwned {
                         table_and_drop: None,
                     },
-                    &[AdapterType::NamedExternref(name.clone())],
+                    &[named_externref(arg)],
                 );
             }
             Descriptor::I8 => self.outgoing_i32(AdapterType::S8),
@@ -190,11 +190,11 @@ impl InstructionBuilder<'_, '_> {
                     &[AdapterType::Externref],
                 );
             }
-            Descriptor::NamedExternref(name) => {
+            Descriptor::NamedExternref(_) | Descriptor::JsString => {
                 self.instruction(
                     &[AdapterType::I32],
                     Instruction::TableGet,
-                    &[AdapterType::NamedExternref(name.clone())],
+                    &[named_externref(arg)],
                 );
             }
             Descriptor::CachedString => self.cached_string(false)?,
@@ -298,13 +298,13 @@ impl InstructionBuilder<'_, '_> {
                     &[AdapterType::Externref.option()],
                 );
             }
-            Descriptor::NamedExternref(name) => {
+            Descriptor::NamedExternref(_) | Descriptor::JsString => {
                 self.instruction(
                     &[AdapterType::I32],
                     Instruction::ExternrefLoadOwned {
                         table_and_drop: None,
                     },
-                    &[AdapterType::NamedExternref(name.clone()).option()],
+                    &[named_externref(arg).option()],
                 );
             }
             Descriptor::I8 => self.out_option_sentinel32(AdapterType::S8),
@@ -409,6 +409,7 @@ impl InstructionBuilder<'_, '_> {
         match arg {
             Descriptor::Externref
             | Descriptor::NamedExternref(_)
+            | Descriptor::JsString
             | Descriptor::I8
             | Descriptor::U8
             | Descriptor::I16
@@ -551,11 +552,11 @@ impl InstructionBuilder<'_, '_> {
                     &[AdapterType::Externref.option()],
                 );
             }
-            Descriptor::NamedExternref(name) => {
+            Descriptor::NamedExternref(_) | Descriptor::JsString => {
                 self.instruction(
                     &[AdapterType::I32],
                     Instruction::TableGet,
-                    &[AdapterType::NamedExternref(name.clone()).option()],
+                    &[named_externref(arg).option()],
                 );
             }
             Descriptor::CachedString => self.cached_string(false)?,
@@ -646,3 +647,12 @@ impl InstructionBuilder<'_, '_> {
         );
     }
 }
+
+/// The adapter type of a `NamedExternref` or `JsString` descriptor.
+fn named_externref(arg: &Descriptor) -> AdapterType {
+    match arg {
+        Descriptor::NamedExternref(name) => AdapterType::NamedExternref(name.clone()),
+        Descriptor::JsString => AdapterType::js_string(),
+        _ => unreachable!(),
+    }
+}
@@ -374,6 +374,11 @@ pub enum Instruction {
     I32FromOptionNonNull,
     OptionNonNullFromI32,
     I32FromNonNull,
+    /// pops a JS value, checks in debug mode that it's a string (or
+    /// `undefined`/`null` if `optional`), and pushes it back
+    AssertString {
+        optional: bool,
+    },
 }
 
 impl AdapterType {
@@ -405,6 +410,11 @@ impl AdapterType {
     pub fn option(self) -> AdapterType {
         AdapterType::Option(Box::new(self))
     }
+
+    /// The type of a `JsStr`, a JS string which isn't copied into Rust.
+    pub fn js_string() -> AdapterType {
+        AdapterType::NamedExternref("string".to_string())
+    }
 }
 
 impl NonstandardWitSection {
@@ -1,7 +1,9 @@
-use anyhow::{bail, Error};
+use anyhow::{bail, Context, Error};
 use clap::{Parser, ValueEnum};
+use std::env;
 use std::ffi::OsString;
-use std::path::PathBuf;
+use std::fs;
+use std::path::{Path, PathBuf};
 use wasm_bindgen_cli_support::{Bindgen, EncodeInto};
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -88,11 +90,25 @@ struct Args {
                 If a bundler is used, it needs to be set up accordingly."
     )]
     split_linked_modules: bool,
+    #[arg(
+        long,
+        value_name = "BYTES",
+        help = "Size of the stack allocated for each thread other than the main one, \
+                a multiple of 65536 (defaults to 2MB)"
+    )]
+    thread_stack_size: Option<u32>,
     #[arg(
         long = "experimental-reset-state-function",
         help = "Generate __wbg_reset_state function for WASM reinitialization (experimental)"
     )]
     generate_reset_state: bool,
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Compare the generated JS and TypeScript against snapshots stored in DIR.\n\
+                Set `BLESS=1` in the environment to update the snapshots instead."
+    )]
+    snapshot_dir: Option<PathBuf>,
     // The options below are deprecated. They're still parsed for backwards compatibility,
     // but we don't want to show them in `--help` to avoid distracting users.
     #[arg(long, hide = true)]
@@ -171,6 +187,9 @@ fn rmain(args: &Args) -> Result<(), Error> {
     if let Some(ref name) = args.out_name {
         b.out_name(name);
     }
+    if let Some(size) = args.thread_stack_size {
+        b.thread_stack_size(size)?;
+    }
 
     if let Some(mode) = &args.encode_into {
         let mode = match mode.as_str() {
@@ -183,5 +202,155 @@ fn rmain(args: &Args) -> Result<(), Error> {
         b.encode_into(mode);
     }
 
-    b.generate(&args.out_dir)
+    let mut output = b.generate_output()?;
+    output.emit(&args.out_dir)?;
+
+    if let Some(snapshot_dir) = &args.snapshot_dir {
+        let generated = output
+            .emitted_files()
+            .iter()
+            .map(|path| snapshot_name(path))
+            .filter(|name| is_snapshot_file(name))
+            .collect::<Vec<_>>();
+        check_snapshots(&args.out_dir, &generated, snapshot_dir, bless())?;
+    }
+
+    Ok(())
+}
+
+/// Returns whether `BLESS` is set to a value other than `0` or `false`, which
+/// requests updating the snapshots.
+fn bless() -> bool {
+    env::var("BLESS").is_ok_and(|value| !matches!(value.as_str(), "" | "0" | "false"))
+}
+
+/// Returns whether `name` is a generated JS or TypeScript file that should be
+/// tracked by a snapshot.
+fn is_snapshot_file(name: &str) -> bool {
+    [".js", ".mjs", ".cjs", ".d.ts"]
+        .iter()
+        .any(|ext| name.ends_with(ext))
+}
+
+/// Names the snapshot of the file at the relative `path`, with `/` separators
+/// on every platform.
+fn snapshot_name(path: &Path) -> String {
+    path.iter()
+        .map(|component| component.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Lists the snapshot-tracked files inside `dir` and its subdirectories, like
+/// `snippets/`, sorted by name.
+fn snapshot_files(dir: &Path) -> Result<Vec<String>, Error> {
+    fn visit(root: &Path, dir: &Path, files: &mut Vec<String>) -> Result<(), Error> {
+        for entry in
+            fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.is_dir() {
+                visit(root, &path, files)?;
+                continue;
+            }
+            let name = snapshot_name(path.strip_prefix(root)?);
+            if is_snapshot_file(&name) {
+                files.push(name);
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    if dir.is_dir() {
+        visit(dir, dir, &mut files)?;
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Compares the `generated` JS and TypeScript files in `out_dir` against the
+/// files stored in `snapshot_dir`, or overwrites the snapshots if `bless` is
+/// set. Other files in `out_dir`, like ones left over from earlier runs, are
+/// ignored.
+fn check_snapshots(
+    out_dir: &Path,
+    generated: &[String],
+    snapshot_dir: &Path,
+    bless: bool,
+) -> Result<(), Error> {
+    let stale = snapshot_files(snapshot_dir)?
+        .into_iter()
+        .filter(|name| !generated.contains(name))
+        .collect::<Vec<_>>();
+
+    if bless {
+        for name in generated {
+            let snapshot = snapshot_dir.join(name);
+            let parent = snapshot.parent().unwrap();
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+            fs::copy(out_dir.join(name), snapshot)
+                .with_context(|| format!("failed to update snapshot `{name}`"))?;
+        }
+        for name in &stale {
+            fs::remove_file(snapshot_dir.join(name))
+                .with_context(|| format!("failed to remove stale snapshot `{name}`"))?;
+        }
+        return Ok(());
+    }
+
+    let mut errors = Vec::new();
+    for name in generated {
+        let actual = fs::read_to_string(out_dir.join(name))?;
+        let expected = match fs::read_to_string(snapshot_dir.join(name)) {
+            Ok(expected) => expected,
+            Err(_) => {
+                errors.push(format!("`{name}`: no snapshot exists"));
+                continue;
+            }
+        };
+        if let Some(mismatch) = first_mismatch(&expected, &actual) {
+            errors.push(format!("`{name}`: {mismatch}"));
+        }
+    }
+    for name in &stale {
+        errors.push(format!(
+            "`{name}`: snapshot exists but the file was not generated"
+        ));
+    }
+
+    if !errors.is_empty() {
+        bail!(
+            "generated bindings do not match the snapshots in {}:\n  {}\n\
+             rerun with `BLESS=1` to update the snapshots",
+            snapshot_dir.display(),
+            errors.join("\n  ")
+        );
+    }
+    Ok(())
+}
+
+/// Describes the first line at which `expected` and `actual` differ.
+fn first_mismatch(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (Some(e), Some(a)) => {
+                return Some(format!(
+                    "line {line} differs\n    expected: {e}\n    actual:   {a}"
+                ))
+            }
+            (Some(e), None) => return Some(format!("line {line} is missing: {e}")),
+            (None, Some(a)) => return Some(format!("line {line} was added: {a}")),
+            // Only trailing newlines differ.
+            (None, None) => return Some("trailing newlines differ".to_owned()),
+        }
+    }
 }
@@ -21,6 +21,8 @@ use std::path::PathBuf;
 use std::thread;
 use wasm_bindgen_cli_support::Bindgen;
 
+mod bench;
+mod coverage;
 mod deno;
 mod headless;
 mod node;
@@ -63,6 +65,53 @@ struct Cli {
         help = "Configure formatting of output"
     )]
     format: Option<FormatSetting>,
+    #[arg(
+        long,
+        value_name = "NAME",
+        requires = "bench",
+        conflicts_with = "baseline",
+        help = "Save the results of the benchmarks as a named baseline, comparing against it if it exists"
+    )]
+    save_baseline: Option<String>,
+    #[arg(
+        long,
+        value_name = "NAME",
+        requires = "bench",
+        help = "Compare the benchmarks against a named baseline without overwriting it"
+    )]
+    baseline: Option<String>,
+    #[arg(
+        long,
+        value_name = "FILE",
+        requires = "bench",
+        help = "Write a JSON report with the estimates of all benchmarks to FILE"
+    )]
+    json_report: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "PERCENT",
+        requires = "bench",
+        help = "Fail if a benchmark is significantly slower than the baseline by more than PERCENT"
+    )]
+    fail_on_regression: Option<f64>,
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Merge the coverage profiles of instrumented tests and write `lcov.info` to DIR"
+    )]
+    coverage_output: Option<PathBuf>,
+    #[arg(
+        long,
+        requires = "coverage_output",
+        help = "Also write an HTML coverage summary to the `html` folder of the coverage output"
+    )]
+    coverage_html: bool,
+    #[arg(
+        long,
+        value_name = "SEED",
+        help = "Seed used to generate the inputs of property tests, as reported by a failing test"
+    )]
+    seed: Option<u64>,
     #[arg(
         index = 2,
         value_name = "FILTER",
@@ -77,13 +126,17 @@ impl Cli {
         let include_ignored = self.include_ignored;
         let filtered = tests.filtered;
 
-        format!(
+        let mut args = format!(
             r#"
             // Forward runtime arguments.
             cx.include_ignored({include_ignored:?});
             cx.filtered_count({filtered});
         "#
-        )
+        );
+        if let Some(seed) = self.seed {
+            args.push_str(&format!("cx.property_seed({seed}n);\n"));
+        }
+        args
     }
 }
 
@@ -347,7 +400,7 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
         b.keep_lld_exports(true);
     }
 
-    // The path of benchmark baseline.
+    // The path of the default benchmark baseline.
     let benchmark = if let Ok(path) = std::env::var("WASM_BINDGEN_BENCH_RESULT") {
         PathBuf::from(path)
     } else {
@@ -361,6 +414,34 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
         }
         path.join("wbg_benchmark.json")
     };
+    // A report is needed to check for regressions, even if it isn't requested.
+    let report = cli.json_report.clone().or_else(|| {
+        cli.fail_on_regression
+            .map(|_| tmpdir_path.join("wbg_benchmark_report.json"))
+    });
+    let benchmark = bench::Benchmark::new(
+        benchmark,
+        cli.save_baseline.as_deref(),
+        cli.baseline.as_deref(),
+        report,
+    )?;
+    if test_mode == TestMode::Deno && benchmark.report.is_some() {
+        bail!("`--json-report` and `--fail-on-regression` are not supported in Deno");
+    }
+    benchmark.clear_report()?;
+    let fail_on_regression = cli.fail_on_regression;
+
+    // The coverage mapping has to be read before `wasm-bindgen` consumes the
+    // module.
+    let coverage = match cli.coverage_output.clone() {
+        Some(out) => Some((
+            out,
+            coverage::Mapping::new(&wasm)?,
+            coverage::Profiles::existing()?,
+        )),
+        None => None,
+    };
+    let coverage_html = cli.coverage_html;
 
     // The debug here means adding some assertions and some error messages to the generated js
     // code.
@@ -375,7 +456,7 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
 
     match test_mode {
         TestMode::Node { no_modules } => {
-            node::execute(module, &tmpdir_path, cli, tests, !no_modules, benchmark)?
+            node::execute(module, &tmpdir_path, cli, tests, !no_modules, &benchmark)?
         }
         TestMode::Deno => deno::execute(module, &tmpdir_path, cli, tests)?,
         TestMode::Browser { .. }
@@ -397,7 +478,7 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
                 tests,
                 test_mode,
                 std::env::var("WASM_BINDGEN_TEST_NO_ORIGIN_ISOLATION").is_err(),
-                benchmark,
+                &benchmark,
             )
             .context("failed to spawn server")?;
             let addr = srv.server_addr();
@@ -419,6 +500,14 @@ fn rmain(cli: Cli) -> anyhow::Result<()> {
             headless::run(&addr, &shell, driver_timeout, browser_timeout)?;
         }
     }
+
+    if let Some((out, mapping, existing)) = coverage {
+        coverage::report(&mapping, &existing, &out, coverage_html)
+            .context("failed to generate coverage report")?;
+    }
+    if let (Some(threshold), Some(report)) = (fail_on_regression, &benchmark.report) {
+        bench::check_regressions(report, threshold)?;
+    }
     Ok(())
 }
 
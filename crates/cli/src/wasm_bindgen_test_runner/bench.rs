@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context, Error};
+use serde::Deserialize;
+
+/// Where benchmark data is read from and written to.
+pub struct Benchmark {
+    /// The baseline to compare against.
+    pub import: PathBuf,
+    /// Where to save the results of this run as a baseline, if at all.
+    pub dump: Option<PathBuf>,
+    /// Where to write the machine-readable report of this run, if at all.
+    pub report: Option<PathBuf>,
+}
+
+impl Benchmark {
+    /// Resolves the baseline files relative to `default`, which is used when
+    /// no named baseline is requested.
+    pub fn new(
+        default: PathBuf,
+        save_baseline: Option<&str>,
+        baseline: Option<&str>,
+        report: Option<PathBuf>,
+    ) -> Result<Self, Error> {
+        if let Some(name) = baseline {
+            let import = named_baseline(&default, name);
+            if !import.is_file() {
+                bail!(
+                    "baseline `{name}` does not exist at {}, create it with `--save-baseline {name}`",
+                    import.display()
+                );
+            }
+            return Ok(Benchmark {
+                import,
+                dump: None,
+                report,
+            });
+        }
+
+        let path = match save_baseline {
+            Some(name) => named_baseline(&default, name),
+            None => default,
+        };
+        Ok(Benchmark {
+            import: path.clone(),
+            dump: Some(path),
+            report,
+        })
+    }
+
+    /// Removes the report of an earlier run, so that a run that doesn't write
+    /// one can't be mistaken for it.
+    pub fn clear_report(&self) -> Result<(), Error> {
+        if let Some(report) = &self.report {
+            match fs::remove_file(report) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => {
+                    return Err(e).with_context(|| {
+                        format!("failed to remove the old report {}", report.display())
+                    });
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Path of the baseline called `name`, e.g. `target/wbg_benchmark-main.json`.
+fn named_baseline(default: &Path, name: &str) -> PathBuf {
+    let stem = default
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("wbg_benchmark");
+    default.with_file_name(format!("{stem}-{name}.json"))
+}
+
+#[derive(Deserialize)]
+struct BenchmarkReport {
+    change: Option<BenchmarkChange>,
+}
+
+#[derive(Deserialize)]
+struct BenchmarkChange {
+    mean: ChangeEstimate,
+    regressed: bool,
+}
+
+#[derive(Deserialize)]
+struct ChangeEstimate {
+    point_estimate: f64,
+}
+
+/// Fails if any benchmark in `report` regressed by more than `threshold`
+/// percent compared to its baseline.
+///
+/// The report is only written when at least one benchmark ran, so a missing
+/// report has nothing to check.
+pub fn check_regressions(report: &Path, threshold: f64) -> Result<(), Error> {
+    if !report.exists() {
+        println!("no benchmarks were run, skipping the regression check");
+        return Ok(());
+    }
+    let data = fs::read(report).context("failed to read the benchmark report")?;
+    let report: BTreeMap<String, BenchmarkReport> =
+        serde_json::from_slice(&data).context("failed to parse the benchmark report")?;
+
+    let regressions = report
+        .iter()
+        .filter_map(|(id, bench)| {
+            let change = bench.change.as_ref()?;
+            let percent = change.mean.point_estimate * 100.;
+            (change.regressed && percent > threshold).then(|| format!("{id}: +{percent:.2}%"))
+        })
+        .collect::<Vec<_>>();
+
+    if !regressions.is_empty() {
+        bail!(
+            "benchmarks regressed by more than {threshold}%:\n  {}",
+            regressions.join("\n  ")
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,1033 @@
+//! Coverage reports generated directly by the test runner.
+//!
+//! Instrumented tests dump raw LLVM profiles (`.profraw`) through
+//! `__wbgtest_cov_dump`. Instead of requiring `llvm-profdata` and `llvm-cov`
+//! of the exact LLVM version used by `rustc`, the runner merges the profiles
+//! written during the run, maps their counters to source regions through
+//! the `__llvm_covmap` and `__llvm_covfun` custom sections of the test module,
+//! and writes `lcov.info` plus an optional HTML summary.
+//!
+//! Only line and function coverage is reported, branch and MC/DC regions are
+//! ignored.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::SystemTime;
+
+use anyhow::{bail, ensure, Context, Error};
+
+/// Coverage mapping extracted from a test module.
+pub struct Mapping {
+    functions: Vec<FunctionMapping>,
+    /// Filenames of each compilation unit, keyed by the hash of their encoding.
+    filenames: HashMap<u64, Vec<PathBuf>>,
+}
+
+struct FunctionMapping {
+    name_ref: u64,
+    hash: u64,
+    filenames_ref: u64,
+    /// Indices into the filenames of the compilation unit.
+    files: Vec<usize>,
+    expressions: Vec<Expression>,
+    regions: Vec<Region>,
+}
+
+#[derive(Clone, Copy)]
+enum Counter {
+    Zero,
+    Ref(usize),
+    Subtract(usize),
+    Add(usize),
+}
+
+struct Expression {
+    lhs: Counter,
+    rhs: Counter,
+    add: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum RegionKind {
+    Code,
+    Gap,
+    Skipped,
+}
+
+/// A line and column in a source file.
+type Position = (u32, u32);
+
+struct Region {
+    file: usize,
+    kind: RegionKind,
+    counter: Counter,
+    start: Position,
+    end: Position,
+}
+
+impl Mapping {
+    /// Reads the coverage mapping of `module`, failing if it wasn't compiled
+    /// with `-Cinstrument-coverage`.
+    pub fn new(module: &walrus::Module) -> Result<Self, Error> {
+        let section = |name: &str| {
+            let ids = walrus::IdsToIndices::default();
+            module
+                .customs
+                .iter()
+                .filter(|(_, section)| section.name() == name)
+                .flat_map(|(_, section)| section.data(&ids).into_owned())
+                .collect::<Vec<u8>>()
+        };
+        let covmap = section("__llvm_covmap");
+        let covfun = section("__llvm_covfun");
+        if covmap.is_empty() || covfun.is_empty() {
+            bail!(
+                "the test module contains no coverage mapping, make sure it was compiled with \
+                 `-Cinstrument-coverage` and linked with `--no-gc-sections`"
+            );
+        }
+        Self::parse(&covmap, &covfun)
+    }
+
+    fn parse(covmap: &[u8], covfun: &[u8]) -> Result<Self, Error> {
+        let mut filenames = HashMap::new();
+        let mut r = Reader::new(covmap);
+        while !r.is_empty() {
+            let _records = r.u32()?;
+            let size = r.u32()? as usize;
+            let _coverage_size = r.u32()?;
+            let version = r.u32()?;
+            // Versions are zero-based, 5 is `Version6` which introduced the
+            // compilation directory.
+            ensure!(
+                version >= 5,
+                "unsupported coverage mapping version {}",
+                version + 1
+            );
+            let encoded = r.bytes(size)?;
+            filenames.insert(hash(encoded), decode_filenames(encoded)?);
+            r.align(8);
+        }
+
+        let mut functions = Vec::new();
+        let mut r = Reader::new(covfun);
+        while !r.is_empty() {
+            let name_ref = r.u64()?;
+            let size = r.u32()? as usize;
+            let hash = r.u64()?;
+            let filenames_ref = r.u64()?;
+            let data = r.bytes(size)?;
+            r.align(8);
+            functions.push(
+                decode_function(name_ref, hash, filenames_ref, data)
+                    .context("malformed coverage mapping")?,
+            );
+        }
+
+        Ok(Mapping {
+            functions,
+            filenames,
+        })
+    }
+}
+
+fn decode_filenames(data: &[u8]) -> Result<Vec<PathBuf>, Error> {
+    let mut r = Reader::new(data);
+    let count = r.uleb()? as usize;
+    let uncompressed_len = r.uleb()? as usize;
+    let compressed_len = r.uleb()? as usize;
+    let decompressed;
+    let mut r = if compressed_len > 0 {
+        decompressed = inflate(r.bytes(compressed_len)?, uncompressed_len)?;
+        Reader::new(&decompressed)
+    } else {
+        r
+    };
+
+    let mut filenames = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len = r.uleb()? as usize;
+        let name = String::from_utf8_lossy(r.bytes(len)?).into_owned();
+        // The first filename is the compilation directory, which relative
+        // filenames are based on.
+        let path = match filenames.first() {
+            Some(dir) => Path::new(dir).join(name),
+            None => PathBuf::from(name),
+        };
+        filenames.push(path);
+    }
+    Ok(filenames)
+}
+
+fn decode_function(
+    name_ref: u64,
+    hash: u64,
+    filenames_ref: u64,
+    data: &[u8],
+) -> Result<FunctionMapping, Error> {
+    let mut r = Reader::new(data);
+
+    let files = (0..r.uleb()?)
+        .map(|_| Ok(r.uleb()? as usize))
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let mut expressions = Vec::new();
+    for _ in 0..r.uleb()? {
+        let lhs = decode_counter(r.uleb()?)?;
+        let rhs = decode_counter(r.uleb()?)?;
+        // The kind of an expression is stored in the tag of the counters
+        // referencing it, fix that up below.
+        expressions.push(Expression {
+            lhs,
+            rhs,
+            add: false,
+        });
+    }
+
+    let mut regions = Vec::new();
+    for file in 0..files.len() {
+        let mut line = 0;
+        for _ in 0..r.uleb()? {
+            let encoded = r.uleb()?;
+            // Expansion, branch and MC/DC regions don't contribute to line
+            // coverage and are skipped after reading them.
+            let mut kind = Some(RegionKind::Code);
+            let mut counter = Counter::Zero;
+            if encoded & 0b11 != 0 {
+                counter = decode_counter(encoded)?;
+            } else if encoded & 0b100 != 0 {
+                // Expansion regions describe macro invocations, the expanded
+                // code has regions of its own.
+                kind = None;
+            } else {
+                match encoded >> 3 {
+                    0 => {}
+                    2 => kind = Some(RegionKind::Skipped),
+                    // Branch region, followed by its true and false counters,
+                    // or MC/DC decision region, followed by its bitmap index
+                    // and number of conditions.
+                    4 | 5 => {
+                        kind = None;
+                        r.uleb()?;
+                        r.uleb()?;
+                    }
+                    // MC/DC branch region, followed by two counters and three IDs.
+                    6 => {
+                        kind = None;
+                        for _ in 0..5 {
+                            r.uleb()?;
+                        }
+                    }
+                    other => bail!("unknown region kind {other}"),
+                }
+            }
+
+            line += r.uleb()? as u32;
+            let mut column_start = r.uleb()? as u32;
+            let lines = r.uleb()? as u32;
+            let mut column_end = r.uleb()? as u32;
+            if column_end & (1 << 31) != 0 {
+                column_end &= !(1 << 31);
+                if kind == Some(RegionKind::Code) {
+                    kind = Some(RegionKind::Gap);
+                }
+            }
+            // Regions covering whole lines are encoded as `0..0`.
+            if column_start == 0 && column_end == 0 {
+                column_start = 1;
+                column_end = u32::MAX;
+            }
+
+            let start = (line, column_start);
+            let end = (line + lines, column_end);
+            if let Some(kind) = kind {
+                regions.push(Region {
+                    file,
+                    kind,
+                    counter,
+                    start,
+                    end,
+                });
+            }
+        }
+    }
+
+    // Expression kinds are only known from the counters referring to them.
+    let mut kinds = vec![None; expressions.len()];
+    let mut mark = |counter: Counter| match counter {
+        Counter::Add(id) => kinds.get_mut(id).map(|kind| *kind = Some(true)),
+        Counter::Subtract(id) => kinds.get_mut(id).map(|kind| *kind = Some(false)),
+        _ => None,
+    };
+    for expression in &expressions {
+        mark(expression.lhs);
+        mark(expression.rhs);
+    }
+    for region in &regions {
+        mark(region.counter);
+    }
+    for (expression, kind) in expressions.iter_mut().zip(kinds) {
+        expression.add = kind.unwrap_or(false);
+    }
+
+    Ok(FunctionMapping {
+        name_ref,
+        hash,
+        filenames_ref,
+        files,
+        expressions,
+        regions,
+    })
+}
+
+fn decode_counter(encoded: u64) -> Result<Counter, Error> {
+    let id = usize::try_from(encoded >> 2)?;
+    Ok(match encoded & 0b11 {
+        0 => Counter::Zero,
+        1 => Counter::Ref(id),
+        2 => Counter::Subtract(id),
+        _ => Counter::Add(id),
+    })
+}
+
+/// Counters of all functions found in the profiles, keyed by their name and
+/// structural hash.
+#[derive(Default)]
+struct Profile {
+    counters: HashMap<(u64, u64), Vec<u64>>,
+    names: HashMap<u64, String>,
+}
+
+impl Profile {
+    /// Merges the `.profraw` file in `data` into this profile.
+    fn merge(&mut self, data: &[u8]) -> Result<(), Error> {
+        const MAGIC_64: u64 = 0xff6c_7072_6f66_7281;
+        const MAGIC_32: u64 = 0xff6c_7072_6f66_5281;
+        // Counters are a single byte, where zero means covered.
+        const BYTE_COVERAGE: u64 = 1 << 60;
+
+        let mut r = Reader::new(data);
+        let pointer_size = match r.u64()? {
+            MAGIC_64 => 8,
+            MAGIC_32 => 4,
+            _ => bail!("not a raw profile"),
+        };
+        let version = r.u64()?;
+        let byte_coverage = version & BYTE_COVERAGE != 0;
+        let version = version as u32;
+        ensure!(
+            (8..=10).contains(&version),
+            "unsupported raw profile version {version}"
+        );
+
+        let binary_ids_size = r.u64()? as usize;
+        let num_data = r.u64()? as usize;
+        let padding_before_counters = r.u64()? as usize;
+        let num_counters = r.u64()? as usize;
+        let padding_after_counters = r.u64()? as usize;
+        let (bitmap_bytes, padding_after_bitmap) = if version >= 9 {
+            (r.u64()? as usize, r.u64()? as usize)
+        } else {
+            (0, 0)
+        };
+        let names_size = r.u64()? as usize;
+        let counters_delta = r.u64()?;
+        if version >= 9 {
+            let _bitmap_delta = r.u64()?;
+        }
+        let _names_delta = r.u64()?;
+        if version >= 10 {
+            let _num_vtables = r.u64()?;
+            let _vnames_size = r.u64()?;
+        }
+        let _value_kind_last = r.u64()?;
+        r.bytes(binary_ids_size)?;
+
+        // `NameRef`, `FuncHash`, then the relative `CounterPtr`, optionally
+        // `BitmapPtr`, `FunctionPointer` and `Values`, followed by
+        // `NumCounters`, the value sites and optionally `NumBitmapBytes`.
+        let pointers: usize = if version >= 9 { 4 } else { 3 };
+        let value_sites = if version >= 10 { 3 } else { 2 };
+        let num_counters_offset = 16 + pointers * pointer_size;
+        let mut record_size = num_counters_offset + 4 + value_sites * 2;
+        if version >= 9 {
+            record_size = record_size.next_multiple_of(4) + 4;
+        }
+        let record_size = record_size.next_multiple_of(8);
+
+        let records = r.bytes(num_data * record_size)?;
+        r.bytes(padding_before_counters)?;
+        let counter_size = if byte_coverage { 1 } else { 8 };
+        let counters = r.bytes(num_counters * counter_size)?;
+        r.bytes(padding_after_counters + bitmap_bytes + padding_after_bitmap)?;
+        self.merge_names(r.bytes(names_size)?)?;
+
+        // Pointers are relative to the address of the record they are stored in.
+        let sign_extend = |value: u64| {
+            if pointer_size == 4 {
+                value as u32 as i32 as i64
+            } else {
+                value as i64
+            }
+        };
+        let counters_delta = sign_extend(counters_delta);
+        for (index, record) in records.chunks_exact(record_size).enumerate() {
+            let mut r = Reader::new(record);
+            let name_ref = r.u64()?;
+            let hash = r.u64()?;
+            let counter_ptr = sign_extend(if pointer_size == 4 {
+                r.u32()?.into()
+            } else {
+                r.u64()?
+            });
+            let mut r = Reader::new(&record[num_counters_offset..]);
+            let len = r.u32()? as usize;
+
+            let delta = counters_delta - (index * record_size) as i64;
+            let start = usize::try_from(sign_extend((counter_ptr - delta) as u64))
+                .context("malformed raw profile")?
+                / counter_size;
+            let values = counters
+                .get(start * counter_size..(start + len) * counter_size)
+                .context("malformed raw profile")?;
+            let values = if byte_coverage {
+                values.iter().map(|&byte| u64::from(byte == 0)).collect()
+            } else {
+                values
+                    .chunks_exact(8)
+                    .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+                    .collect()
+            };
+
+            match self.counters.get_mut(&(name_ref, hash)) {
+                Some(existing) if existing.len() == len => {
+                    for (existing, value) in existing.iter_mut().zip(values) {
+                        *existing = existing.saturating_add(value);
+                    }
+                }
+                Some(_) => {}
+                None => {
+                    self.counters.insert((name_ref, hash), values);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn merge_names(&mut self, data: &[u8]) -> Result<(), Error> {
+        let mut r = Reader::new(data);
+        while !r.is_empty() {
+            let uncompressed_len = r.uleb()? as usize;
+            let compressed_len = r.uleb()? as usize;
+            let names = if compressed_len > 0 {
+                inflate(r.bytes(compressed_len)?, uncompressed_len)?
+            } else {
+                r.bytes(uncompressed_len)?.to_vec()
+            };
+            for name in names.split(|&byte| byte == 1) {
+                let name = String::from_utf8_lossy(name).into_owned();
+                self.names.insert(hash(name.as_bytes()), name);
+            }
+        }
+        Ok(())
+    }
+
+    fn evaluate(&self, counters: &[u64], expressions: &[Expression], counter: Counter) -> u64 {
+        let expression = |id: usize| {
+            let Some(expression) = expressions.get(id) else {
+                return 0;
+            };
+            let lhs = self.evaluate(counters, expressions, expression.lhs);
+            let rhs = self.evaluate(counters, expressions, expression.rhs);
+            if expression.add {
+                lhs.saturating_add(rhs)
+            } else {
+                lhs.saturating_sub(rhs)
+            }
+        };
+        match counter {
+            Counter::Zero => 0,
+            Counter::Ref(id) => counters.get(id).copied().unwrap_or(0),
+            Counter::Subtract(id) | Counter::Add(id) => expression(id),
+        }
+    }
+}
+
+/// Line and function coverage of a single source file.
+#[derive(Default)]
+struct FileCoverage {
+    lines: BTreeMap<u32, u64>,
+    /// Functions by name, with the line they start on and their call count.
+    functions: BTreeMap<String, (u32, u64)>,
+}
+
+impl FileCoverage {
+    /// Merges `other` by adding up all counts.
+    ///
+    /// Each report only covers the profiles written since the previous one,
+    /// so their executions never overlap.
+    fn merge(&mut self, other: FileCoverage) {
+        for (line, count) in other.lines {
+            let existing = self.lines.entry(line).or_default();
+            *existing = existing.saturating_add(count);
+        }
+        for (name, (line, count)) in other.functions {
+            let existing = self.functions.entry(name).or_insert((line, 0));
+            existing.1 = existing.1.saturating_add(count);
+        }
+    }
+}
+
+/// Source regions with their execution counts, used to compute line counts.
+#[derive(Default)]
+struct FileRegions {
+    regions: HashMap<(Position, Position), (RegionKind, u64)>,
+}
+
+impl FileRegions {
+    fn add(&mut self, region: &Region, count: u64) {
+        let entry = self
+            .regions
+            .entry((region.start, region.end))
+            .or_insert((region.kind, 0));
+        // Identical regions, e.g. of several instantiations of a generic
+        // function, are combined like `llvm-cov` does.
+        if region.kind != RegionKind::Skipped {
+            entry.0 = region.kind;
+            entry.1 = entry.1.saturating_add(count);
+        }
+    }
+
+    /// Computes line counts the way `llvm-cov` does: a line is covered by the
+    /// regions starting on it and by the innermost region spanning its start.
+    fn lines(&self) -> BTreeMap<u32, u64> {
+        let mut regions = self.regions.iter().collect::<Vec<_>>();
+        // Sort outer regions first, so inner ones take precedence below.
+        regions.sort_by_key(|((start, end), _)| (*start, std::cmp::Reverse(*end)));
+
+        #[derive(Default)]
+        struct Line {
+            /// The count of the innermost region spanning the start of the
+            /// line, `None` if it is skipped.
+            wrapped: Option<Option<u64>>,
+            /// The maximum count of the code regions starting on the line.
+            entry: Option<u64>,
+        }
+
+        let mut lines = BTreeMap::<u32, Line>::new();
+        for (&(start, end), &(kind, count)) in regions {
+            if kind == RegionKind::Code {
+                let entry = &mut lines.entry(start.0).or_default().entry;
+                *entry = Some(entry.map_or(count, |entry| entry.max(count)));
+            }
+            let count = (kind != RegionKind::Skipped).then_some(count);
+            let last = if end.1 > 1 {
+                end.0
+            } else {
+                end.0.saturating_sub(1)
+            };
+            for line in start.0 + 1..=last {
+                lines.entry(line).or_default().wrapped = Some(count);
+            }
+        }
+
+        lines
+            .into_iter()
+            .filter_map(|(number, line)| {
+                let count = line.wrapped.flatten().max(line.entry)?;
+                Some((number, count))
+            })
+            .collect()
+    }
+}
+
+/// The `.profraw` files in the profile directory, with the time they were
+/// last modified.
+pub struct Profiles(HashMap<PathBuf, SystemTime>);
+
+impl Profiles {
+    /// Lists the profiles that currently exist, to tell them apart from the
+    /// ones written by the tests afterwards.
+    pub fn existing() -> Result<Self, Error> {
+        Self::list(&profile_dir()?)
+    }
+
+    fn list(dir: &Path) -> Result<Self, Error> {
+        let mut profiles = HashMap::new();
+        if !dir.is_dir() {
+            return Ok(Profiles(profiles));
+        }
+        for entry in
+            fs::read_dir(dir).with_context(|| format!("failed to read {}", dir.display()))?
+        {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "profraw") {
+                profiles.insert(path, entry.metadata()?.modified()?);
+            }
+        }
+        Ok(Profiles(profiles))
+    }
+}
+
+/// Merges the profiles written since `existing` were listed, and writes
+/// `lcov.info` (and an HTML summary if `html` is set) to `out`.
+///
+/// Profiles left over from earlier runs are ignored, their coverage is
+/// already part of the `lcov.info` they produced.
+pub fn report(mapping: &Mapping, existing: &Profiles, out: &Path, html: bool) -> Result<(), Error> {
+    let profile_dir = profile_dir()?;
+    let mut profile = Profile::default();
+    let mut found = false;
+    for (path, modified) in Profiles::list(&profile_dir)?.0 {
+        if existing.0.get(&path) == Some(&modified) {
+            continue;
+        }
+        let data = fs::read(&path)?;
+        profile
+            .merge(&data)
+            .with_context(|| format!("failed to read profile {}", path.display()))?;
+        found = true;
+    }
+    if !found {
+        bail!(
+            "no `.profraw` files were written to {}, make sure the tests were compiled with \
+             `--cfg=wasm_bindgen_unstable_test_coverage`",
+            profile_dir.display()
+        );
+    }
+
+    let mut files = BTreeMap::<PathBuf, FileCoverage>::new();
+    let mut regions = BTreeMap::<PathBuf, FileRegions>::new();
+    let mut seen = HashSet::new();
+    for function in &mapping.functions {
+        // The same function is mapped in every compilation unit it is used in.
+        if !seen.insert((function.name_ref, function.hash)) {
+            continue;
+        }
+        let Some(filenames) = mapping.filenames.get(&function.filenames_ref) else {
+            continue;
+        };
+        let counters = profile
+            .counters
+            .get(&(function.name_ref, function.hash))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let path = |file: usize| {
+            function
+                .files
+                .get(file)
+                .and_then(|&index| filenames.get(index))
+        };
+
+        for region in &function.regions {
+            let Some(path) = path(region.file) else {
+                continue;
+            };
+            let count = profile.evaluate(counters, &function.expressions, region.counter);
+            regions.entry(path.clone()).or_default().add(region, count);
+        }
+
+        // The first region of a function spans its whole body.
+        if let Some(region) = function.regions.first() {
+            if let Some(path) = path(region.file) {
+                let name = match profile.names.get(&function.name_ref) {
+                    Some(name) => format!("{:#}", rustc_demangle::demangle(name)),
+                    None => format!("{:016x}", function.name_ref),
+                };
+                let count = profile.evaluate(counters, &function.expressions, region.counter);
+                let entry = files
+                    .entry(path.clone())
+                    .or_default()
+                    .functions
+                    .entry(name)
+                    .or_insert((region.start.0, 0));
+                entry.1 = entry.1.saturating_add(count);
+            }
+        }
+    }
+    for (path, regions) in regions {
+        files.entry(path).or_default().lines = regions.lines();
+    }
+
+    fs::create_dir_all(out).with_context(|| format!("failed to create {}", out.display()))?;
+    let lcov = out.join("lcov.info");
+    if lcov.exists() {
+        let previous = fs::read_to_string(&lcov).context("failed to read lcov.info")?;
+        for (path, coverage) in parse_lcov(&previous) {
+            files.entry(path).or_default().merge(coverage);
+        }
+    }
+    fs::write(&lcov, write_lcov(&files)).context("failed to write lcov.info")?;
+    if html {
+        write_html(&files, &out.join("html"))?;
+    }
+    Ok(())
+}
+
+/// The directory `.profraw` files are dumped to, see `LLVM_PROFILE_FILE`.
+fn profile_dir() -> Result<PathBuf, Error> {
+    let path = wasm_bindgen_test_shared::coverage_path(
+        env::var("LLVM_PROFILE_FILE").ok().as_deref(),
+        process::id(),
+        env::temp_dir()
+            .to_str()
+            .context("failed to parse path to temporary directory")?,
+        0,
+    );
+    Ok(match Path::new(&path).parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    })
+}
+
+fn write_lcov(files: &BTreeMap<PathBuf, FileCoverage>) -> String {
+    let mut lcov = String::new();
+    for (path, file) in files {
+        writeln!(lcov, "SF:{}", path.display()).unwrap();
+        for (name, (line, _)) in &file.functions {
+            writeln!(lcov, "FN:{line},{name}").unwrap();
+        }
+        for (name, (_, count)) in &file.functions {
+            writeln!(lcov, "FNDA:{count},{name}").unwrap();
+        }
+        let functions_hit = file.functions.values().filter(|(_, c)| *c > 0).count();
+        writeln!(lcov, "FNF:{}", file.functions.len()).unwrap();
+        writeln!(lcov, "FNH:{functions_hit}").unwrap();
+        for (line, count) in &file.lines {
+            writeln!(lcov, "DA:{line},{count}").unwrap();
+        }
+        let lines_hit = file.lines.values().filter(|c| **c > 0).count();
+        writeln!(lcov, "LF:{}", file.lines.len()).unwrap();
+        writeln!(lcov, "LH:{lines_hit}").unwrap();
+        lcov.push_str("end_of_record\n");
+    }
+    lcov
+}
+
+fn parse_lcov(lcov: &str) -> Vec<(PathBuf, FileCoverage)> {
+    let mut files = Vec::new();
+    let mut current: Option<(PathBuf, FileCoverage)> = None;
+    for line in lcov.lines() {
+        let (key, value) = line.split_once(':').unwrap_or((line, ""));
+        match (key, &mut current) {
+            ("SF", _) => current = Some((PathBuf::from(value), FileCoverage::default())),
+            ("FN", Some((_, file))) => {
+                if let Some((line, name)) = value.split_once(',') {
+                    let line = line.parse().unwrap_or(0);
+                    file.functions.entry(name.to_string()).or_default().0 = line;
+                }
+            }
+            ("FNDA", Some((_, file))) => {
+                if let Some((count, name)) = value.split_once(',') {
+                    let count = count.parse().unwrap_or(0);
+                    file.functions.entry(name.to_string()).or_default().1 = count;
+                }
+            }
+            ("DA", Some((_, file))) => {
+                let mut parts = value.split(',');
+                if let (Some(Ok(line)), Some(Ok(count))) =
+                    (parts.next().map(str::parse), parts.next().map(str::parse))
+                {
+                    file.lines.insert(line, count);
+                }
+            }
+            ("end_of_record", _) => files.extend(current.take()),
+            _ => {}
+        }
+    }
+    files
+}
+
+fn write_html(files: &BTreeMap<PathBuf, FileCoverage>, dir: &Path) -> Result<(), Error> {
+    const STYLE: &str = "<style>\
+        body { font-family: sans-serif; }\
+        table { border-collapse: collapse; }\
+        td, th { padding: 0 0.5em; text-align: left; }\
+        pre { margin: 0; }\
+        .hit { background: #dfd; }\
+        .miss { background: #fdd; }\
+        .count { color: #777; text-align: right; }\
+        </style>";
+
+    fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+
+    let percent = |hit: usize, total: usize| {
+        if total == 0 {
+            100.
+        } else {
+            hit as f64 * 100. / total as f64
+        }
+    };
+
+    let mut index = format!(
+        "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>Coverage</title>{STYLE}</head>\
+         <body><h1>Coverage</h1><table><tr><th>File</th><th>Lines</th><th>Functions</th></tr>"
+    );
+    let (mut lines_hit, mut lines_total) = (0, 0);
+    for (i, (path, file)) in files.iter().enumerate() {
+        let hit = file.lines.values().filter(|c| **c > 0).count();
+        let functions_hit = file.functions.values().filter(|(_, c)| *c > 0).count();
+        lines_hit += hit;
+        lines_total += file.lines.len();
+        write!(
+            index,
+            "<tr><td><a href=\"{i}.html\">{}</a></td><td>{hit}/{} ({:.1}%)</td>\
+             <td>{functions_hit}/{} ({:.1}%)</td></tr>",
+            escape(&path.display().to_string()),
+            file.lines.len(),
+            percent(hit, file.lines.len()),
+            file.functions.len(),
+            percent(functions_hit, file.functions.len()),
+        )
+        .unwrap();
+
+        let mut page = format!(
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{0}</title>{STYLE}</head>\
+             <body><p><a href=\"index.html\">Index</a></p><h1>{0}</h1>",
+            escape(&path.display().to_string())
+        );
+        match fs::read_to_string(path) {
+            Ok(source) => {
+                page.push_str("<table>");
+                for (line, text) in (1..).zip(source.lines()) {
+                    let (class, count) = match file.lines.get(&line) {
+                        Some(0) => ("miss", "0".to_string()),
+                        Some(count) => ("hit", count.to_string()),
+                        None => ("", String::new()),
+                    };
+                    write!(
+                        page,
+                        "<tr class=\"{class}\"><td class=\"count\">{line}</td>\
+                         <td class=\"count\">{count}</td><td><pre>{}</pre></td></tr>",
+                        escape(text)
+                    )
+                    .unwrap();
+                }
+                page.push_str("</table>");
+            }
+            Err(e) => write!(
+                page,
+                "<p>Failed to read the source: {}</p>",
+                escape(&e.to_string())
+            )
+            .unwrap(),
+        }
+        page.push_str("</body></html>");
+        fs::write(dir.join(format!("{i}.html")), page)?;
+    }
+    write!(
+        index,
+        "</table><p>Total: {lines_hit}/{lines_total} lines ({:.1}%)</p></body></html>",
+        percent(lines_hit, lines_total)
+    )
+    .unwrap();
+    fs::write(dir.join("index.html"), index)?;
+    Ok(())
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// The lower 64 bits of the MD5 hash, which LLVM uses to refer to function
+/// names and filenames.
+fn hash(data: &[u8]) -> u64 {
+    u64::from_le_bytes(md5::compute(data).0[..8].try_into().unwrap())
+}
+
+fn inflate(data: &[u8], len: usize) -> Result<Vec<u8>, Error> {
+    let mut out = Vec::with_capacity(len);
+    flate2::read::ZlibDecoder::new(data)
+        .read_to_end(&mut out)
+        .context("failed to decompress coverage data")?;
+    Ok(out)
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Reader { data, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], Error> {
+        let bytes = self
+            .pos
+            .checked_add(len)
+            .and_then(|end| self.data.get(self.pos..end))
+            .context("unexpected end of coverage data")?;
+        self.pos += len;
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, Error> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64, Error> {
+        Ok(u64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    fn uleb(&mut self) -> Result<u64, Error> {
+        let mut result = 0;
+        let mut shift = 0;
+        loop {
+            let byte = self.bytes(1)?[0];
+            ensure!(shift < 64, "malformed LEB128 in coverage data");
+            result |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+    }
+
+    fn align(&mut self, align: usize) {
+        self.pos = self.pos.next_multiple_of(align);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uleb() {
+        let mut r = Reader::new(&[0x00, 0x7f, 0x80, 0x01, 0xe5, 0x8e, 0x26]);
+        assert_eq!(r.uleb().unwrap(), 0);
+        assert_eq!(r.uleb().unwrap(), 127);
+        assert_eq!(r.uleb().unwrap(), 128);
+        assert_eq!(r.uleb().unwrap(), 624_485);
+        assert!(r.is_empty());
+
+        // Truncated and overlong encodings are rejected.
+        assert!(Reader::new(&[0x80]).uleb().is_err());
+        assert!(Reader::new(&[0x80; 11]).uleb().is_err());
+    }
+
+    #[test]
+    fn region_lines() {
+        // One file, no expressions and a single region counted by counter 0,
+        // spanning from line 2 column 1 to line 5 column 2.
+        let function = decode_function(0, 0, 0, &[1, 0, 0, 1, 1, 2, 1, 3, 2]).unwrap();
+        let [region] = &function.regions[..] else {
+            panic!("expected a single region");
+        };
+        assert!(matches!(region.counter, Counter::Ref(0)));
+        assert_eq!((region.start, region.end), ((2, 1), (5, 2)));
+
+        let profile = Profile::default();
+        let count = profile.evaluate(&[7], &function.expressions, region.counter);
+        let mut regions = FileRegions::default();
+        regions.add(region, count);
+        let inner = |kind, start, end, counter| Region {
+            file: 0,
+            kind,
+            counter,
+            start,
+            end,
+        };
+        // An uncovered branch on lines 3 and 4, and skipped code ending on
+        // line 5.
+        regions.add(&inner(RegionKind::Code, (3, 5), (4, 6), Counter::Zero), 0);
+        regions.add(
+            &inner(RegionKind::Skipped, (4, 8), (5, 2), Counter::Zero),
+            0,
+        );
+
+        // Line 3 is covered by the region spanning its start, line 4 by the
+        // branch and line 5 is skipped.
+        assert_eq!(regions.lines(), BTreeMap::from([(2, 7), (3, 7), (4, 0)]));
+    }
+
+    /// Builds a version 8 raw profile of a single function called `name`.
+    fn profraw(name: &str, hash: u64, counters: &[u64]) -> Vec<u8> {
+        const RECORD_SIZE: u64 = 48;
+        let mut data = Vec::new();
+        let mut names = vec![name.len() as u8, 0];
+        names.extend_from_slice(name.as_bytes());
+        for field in [
+            0xff6c_7072_6f66_7281,
+            8,
+            // Binary IDs, records, padding, counters, padding and names.
+            0,
+            1,
+            0,
+            counters.len() as u64,
+            0,
+            names.len() as u64,
+            // The counters start right after the single record.
+            RECORD_SIZE,
+            0,
+            0,
+        ] {
+            data.extend_from_slice(&u64::to_le_bytes(field));
+        }
+        // `NameRef`, `FuncHash`, `CounterPtr`, `FunctionPointer`, `Values`.
+        for field in [super::hash(name.as_bytes()), hash, RECORD_SIZE, 0, 0] {
+            data.extend_from_slice(&u64::to_le_bytes(field));
+        }
+        data.extend_from_slice(&(counters.len() as u32).to_le_bytes());
+        data.extend_from_slice(&[0; 4]);
+        for counter in counters {
+            data.extend_from_slice(&counter.to_le_bytes());
+        }
+        data.extend_from_slice(&names);
+        data
+    }
+
+    #[test]
+    fn merge_profraw() {
+        let data = profraw("foo", 0x1234, &[5, 9]);
+        let mut profile = Profile::default();
+        profile.merge(&data).unwrap();
+        profile.merge(&data).unwrap();
+
+        let name_ref = hash(b"foo");
+        assert_eq!(profile.counters[&(name_ref, 0x1234)], [10, 18]);
+        assert_eq!(profile.names[&name_ref], "foo");
+
+        assert!(Profile::default().merge(&data[8..]).is_err());
+        assert!(Profile::default().merge(&data[..data.len() - 4]).is_err());
+    }
+
+    #[test]
+    fn lcov_round_trip() {
+        let mut file = FileCoverage::default();
+        file.lines.extend([(1, 3), (2, 0), (10, 1)]);
+        file.functions.insert("foo::bar".to_string(), (1, 3));
+        file.functions.insert("foo::baz".to_string(), (10, 0));
+        let files = BTreeMap::from([(PathBuf::from("src/lib.rs"), file)]);
+
+        let lcov = write_lcov(&files);
+        assert!(lcov.contains("FNH:1\n"));
+        assert!(lcov.contains("LH:2\n"));
+        let [(path, parsed)] = &parse_lcov(&lcov)[..] else {
+            panic!("expected a single file in {lcov}");
+        };
+        assert_eq!(path, Path::new("src/lib.rs"));
+        assert_eq!(parsed.lines, files[path].lines);
+        assert_eq!(parsed.functions, files[path].functions);
+
+        let mut merged = parse_lcov(&lcov).remove(0).1;
+        merged.merge(parse_lcov(&lcov).remove(0).1);
+        assert_eq!(merged.lines[&1], 6);
+        assert_eq!(merged.functions["foo::bar"], (1, 6));
+    }
+}
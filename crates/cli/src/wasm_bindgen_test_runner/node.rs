@@ -1,4 +1,4 @@
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use std::process;
 use std::process::Command;
 use std::{env, fs};
@@ -6,6 +6,7 @@ use std::{env, fs};
 use anyhow::bail;
 use anyhow::{Context, Error};
 
+use super::bench::Benchmark;
 use super::Cli;
 use super::Tests;
 
@@ -49,13 +50,38 @@ handlers.on_console_error = wasm.__wbgtest_console_error;
     )
 }
 
+fn dump_bench(benchmark: &Benchmark) -> String {
+    let mut js = String::new();
+    if let Some(dump) = &benchmark.dump {
+        js.push_str(&format!(
+            r#"
+                const benchmark_dump = wasm.__wbgbench_dump();
+                if (benchmark_dump !== undefined)
+                    await fs.writeFile('{}', benchmark_dump);
+            "#,
+            dump.display()
+        ));
+    }
+    if let Some(report) = &benchmark.report {
+        js.push_str(&format!(
+            r#"
+                const benchmark_report = wasm.__wbgbench_report();
+                if (benchmark_report !== undefined)
+                    await fs.writeFile('{}', benchmark_report);
+            "#,
+            report.display()
+        ));
+    }
+    js
+}
+
 pub fn execute(
     module: &str,
     tmpdir: &Path,
     cli: Cli,
     tests: Tests,
     module_format: bool,
-    benchmark: PathBuf,
+    benchmark: &Benchmark,
 ) -> Result<(), Error> {
     let coverage_env = if let Ok(env) = env::var("LLVM_PROFILE_FILE") {
         &format!("\"{env}\"")
@@ -84,7 +110,7 @@ pub fn execute(
 
             if ({is_bench}) {{
                 try {{
-                    const benchmark_import = await fs.readFile('{benchmark}');
+                    const benchmark_import = await fs.readFile('{benchmark_import}');
                     if (benchmark_import !== undefined)
                         wasm.__wbgbench_import(new Uint8Array(benchmark_import));
                 }} catch {{
@@ -100,9 +126,7 @@ pub fn execute(
             }}
 
             if ({is_bench}) {{
-                const benchmark_dump = wasm.__wbgbench_dump();
-                if (benchmark_dump !== undefined)
-                    await fs.writeFile('{benchmark}', benchmark_dump);
+                {dump_bench}
             }}
 
             if (!ok)
@@ -130,7 +154,8 @@ pub fn execute(
         is_bench = cli.bench,
         nocapture = cli.nocapture || cli.bench,
         args = cli.get_args(&tests),
-        benchmark = benchmark.display()
+        benchmark_import = benchmark.import.display(),
+        dump_bench = dump_bench(benchmark),
     );
 
     // Note that we're collecting *JS objects* that represent the functions to
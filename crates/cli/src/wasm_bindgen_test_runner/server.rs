@@ -1,12 +1,13 @@
 use std::borrow::Cow;
 use std::io::{Read, Write};
 use std::net::SocketAddr;
-use std::path::{Path, PathBuf};
+use std::path::Path;
 use std::{env, fs, process};
 
 use anyhow::{anyhow, Context, Error};
 use rouille::{Request, Response, Server};
 
+use super::bench::Benchmark;
 use super::{Cli, TestMode, Tests};
 
 pub(crate) fn spawn(
@@ -18,7 +19,7 @@ pub(crate) fn spawn(
     tests: Tests,
     test_mode: TestMode,
     isolate_origin: bool,
-    benchmark: PathBuf,
+    benchmark: &Benchmark,
 ) -> Result<Server<impl Fn(&Request) -> Response + Send + Sync>, Error> {
     let mut js_to_execute = String::new();
 
@@ -46,9 +47,10 @@ pub(crate) fn spawn(
 
     let bench_import = if test_mode.no_modules() {
         "let __wbgbench_import = wasm_bindgen.__wbgbench_import;
-        let __wbgbench_dump = wasm_bindgen.__wbgbench_dump;"
+        let __wbgbench_dump = wasm_bindgen.__wbgbench_dump;
+        let __wbgbench_report = wasm_bindgen.__wbgbench_report;"
     } else {
-        "__wbgbench_import,__wbgbench_dump,"
+        "__wbgbench_import,__wbgbench_dump,__wbgbench_report,"
     };
 
     let import_bench = r#"
@@ -60,7 +62,10 @@ pub(crate) fn spawn(
         }
     "#;
 
-    let dump_bench = r#"
+    let mut dump_bench = String::new();
+    if benchmark.dump.is_some() {
+        dump_bench.push_str(
+            r#"
         // Dump the benchmark data collected during the benches
         const benchmark_dump = __wbgbench_dump();
 
@@ -70,7 +75,24 @@ pub(crate) fn spawn(
                 body: benchmark_dump
             });
         }
-    "#;
+    "#,
+        );
+    }
+    if benchmark.report.is_some() {
+        dump_bench.push_str(
+            r#"
+        // Send the report of the benches
+        const benchmark_report = __wbgbench_report();
+
+        if (benchmark_report !== undefined) {
+            await fetch("/__wasm_bindgen/bench/report", {
+                method: "POST",
+                body: benchmark_report
+            });
+        }
+    "#,
+        );
+    }
 
     let wbg_import_script = if test_mode.no_modules() {
         format!(
@@ -366,6 +388,9 @@ pub(crate) fn spawn(
 
     // For now, always run forever on this port. We may update this later!
     let tmpdir = tmpdir.to_path_buf();
+    let benchmark_import = benchmark.import.clone();
+    let benchmark_dump = benchmark.dump.clone();
+    let benchmark_report = benchmark.report.clone();
     let srv = Server::new(addr, move |request| {
         // The root path gets our canned `index.html`. The two templates here
         // differ slightly in the default routing of `console.log`, going to an
@@ -414,9 +439,19 @@ pub(crate) fn spawn(
                 Response::empty_204()
             };
         } else if request.url() == "/__wasm_bindgen/bench/fetch" {
-            return handle_benchmark_fetch(&benchmark);
-        } else if request.url() == "/__wasm_bindgen/bench/dump" {
-            return if let Err(e) = handle_benchmark_dump(&benchmark, request) {
+            return handle_benchmark_fetch(&benchmark_import);
+        } else if request.url() == "/__wasm_bindgen/bench/dump"
+            || request.url() == "/__wasm_bindgen/bench/report"
+        {
+            let path = if request.url() == "/__wasm_bindgen/bench/dump" {
+                &benchmark_dump
+            } else {
+                &benchmark_report
+            };
+            let Some(path) = path else {
+                return Response::empty_400();
+            };
+            return if let Err(e) = handle_benchmark_dump(path, request) {
                 let s: &str = &format!("Failed to save benchmark: {e}");
                 log::error!("{s}");
                 let mut ret = Response::text(s);
@@ -31,6 +31,9 @@ export function result_string() {
     }
 }
 
+/**
+ * @throws {RangeError}
+ */
 export function result_void() {
     const ret = wasm.result_void();
     if (ret[1]) {
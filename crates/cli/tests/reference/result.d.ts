@@ -5,4 +5,7 @@ export function result_i32(): number;
 
 export function result_string(): string;
 
+/**
+ * @throws {RangeError}
+ */
 export function result_void(): void;
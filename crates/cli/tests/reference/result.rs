@@ -1,11 +1,17 @@
 use wasm_bindgen::prelude::*;
 
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "RangeError")]
+    pub type RangeError;
+}
+
 #[wasm_bindgen]
 pub fn result_string() -> Result<String, JsValue> {
     Err(JsValue::from(5i32))
 }
 
-#[wasm_bindgen]
+#[wasm_bindgen(throws = RangeError)]
 pub fn result_void() -> Result<(), JsError> {
     Err(JsError::new("message"))
 }
@@ -57,12 +57,20 @@ impl Project {
     }
 
     fn wasm_bindgen_test(&mut self, args: &str) -> anyhow::Result<Output> {
+        self.run("test", args)
+    }
+
+    fn wasm_bindgen_bench(&mut self, args: &str) -> anyhow::Result<Output> {
+        self.run("bench", args)
+    }
+
+    fn run(&mut self, subcommand: &str, args: &str) -> anyhow::Result<Output> {
         self.cargo_toml();
         let mut cargo_cmd = Command::new("cargo");
         let runner = REPO_ROOT.join("crates").join("cli").join("Cargo.toml");
         let output = cargo_cmd
             .current_dir(&self.root)
-            .arg("test")
+            .arg(subcommand)
             .arg("--target")
             .arg("wasm32-unknown-unknown")
             .arg("--")
@@ -137,6 +145,31 @@ fn test_wasm_bindgen_test_runner_list() {
     assert_eq!(lines.next(), None);
 }
 
+#[test]
+fn test_coverage_output_requires_instrumentation() {
+    let output = Project::new("test_coverage_output_requires_instrumentation")
+        .file(
+            "src/lib.rs",
+            r#"
+            #[cfg(test)]
+            mod tests {
+                use wasm_bindgen_test::*;
+
+                #[wasm_bindgen_test]
+                fn test_foo() {}
+            }
+        "#,
+        )
+        .wasm_bindgen_test("--coverage-output coverage")
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("the test module contains no coverage mapping"),
+        "{stderr}"
+    );
+}
+
 /// Test that console.log output in dedicated worker mode is not duplicated.
 /// See: https://github.com/wasm-bindgen/wasm-bindgen/pull/4845#issuecomment-3660688206
 #[test]
@@ -173,3 +206,173 @@ fn test_worker_console_log_no_duplicates() {
         "Expected console_log message to appear exactly once, but it appeared {count} times.\nstdout:\n{stdout}\nstderr:\n{stderr}"
     );
 }
+
+#[test]
+fn test_property_shrinks_and_reproduces() {
+    let mut project = Project::new("test_property_shrinks_and_reproduces");
+    project.file(
+        "src/lib.rs",
+        r#"
+            #[cfg(test)]
+            mod tests {
+                use wasm_bindgen_test::*;
+
+                #[wasm_bindgen_test(property(cases = 1000))]
+                fn below_ten(x: u32) {
+                    assert!(x < 10);
+                }
+
+                // Runs long enough for the generated magnitude to exceed
+                // `i8::MAX`, which must not overflow. Any `i8`, including
+                // `i8::MIN`, can be generated.
+                #[wasm_bindgen_test(property(cases = 300))]
+                fn narrow_signed(x: i8) {
+                    let _ = x;
+                }
+            }
+        "#,
+    );
+
+    let output = project.wasm_bindgen_test("").unwrap();
+    assert!(!output.status.success());
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        combined.contains("tests::narrow_signed ... ok"),
+        "{combined}"
+    );
+    assert!(
+        combined.contains("shrink step(s)): 10\n"),
+        "the failing input wasn't shrunk to its minimum:\n{combined}"
+    );
+
+    // The reported seed reproduces the same failure.
+    let seed = combined
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("seed: "))
+        .unwrap_or_else(|| panic!("no seed reported:\n{combined}"));
+    let output = project
+        .wasm_bindgen_test(&format!("--seed {seed} below_ten"))
+        .unwrap();
+    assert!(!output.status.success());
+    let rerun = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(rerun.contains(&format!("seed: {seed}\n")), "{rerun}");
+    assert!(rerun.contains("shrink step(s)): 10\n"), "{rerun}");
+}
+
+/// A benchmark that runs `iterations` iterations of a loop per sample.
+fn loop_bench(iterations: u32) -> String {
+    format!(
+        r#"
+            use wasm_bindgen_test::{{wasm_bindgen_bench, Criterion}};
+
+            #[wasm_bindgen_bench]
+            fn bench(c: &mut Criterion) {{
+                c.bench_function("loop", |b| {{
+                    b.iter(|| {{
+                        (0..std::hint::black_box({iterations}u32)).fold(0u32, |a, b| a ^ b)
+                    }})
+                }});
+            }}
+        "#
+    )
+}
+
+#[test]
+fn test_bench_baseline_save_and_compare() {
+    let mut project = Project::new("test_bench_baseline_save_and_compare");
+    project
+        .file("src/lib.rs", "")
+        .file("benches/loop.rs", &loop_bench(100));
+
+    // Comparing against a baseline that doesn't exist fails.
+    let output = project.wasm_bindgen_bench("--baseline base").unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("baseline `base` does not exist"),
+        "{stderr}"
+    );
+
+    let output = project.wasm_bindgen_bench("--save-baseline base").unwrap();
+    assert!(output.status.success());
+    let baseline = project.root.join("target").join("wbg_benchmark-base.json");
+    let saved = fs::read(&baseline).unwrap();
+
+    // `--baseline` compares against the saved baseline without overwriting it.
+    let report = project.root.join("report.json");
+    let output = project
+        .wasm_bindgen_bench(&format!(
+            "--baseline base --json-report {}",
+            report.display()
+        ))
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(fs::read(&baseline).unwrap(), saved);
+    let report = fs::read_to_string(&report).unwrap();
+    assert!(report.contains("\"change\""), "{report}");
+}
+
+#[test]
+fn test_bench_fail_on_regression() {
+    let mut project = Project::new("test_bench_fail_on_regression");
+    project
+        .file("src/lib.rs", "")
+        .file("benches/loop.rs", &loop_bench(100));
+    let output = project.wasm_bindgen_bench("--save-baseline base").unwrap();
+    assert!(output.status.success());
+
+    // A stale report from an earlier run isn't checked.
+    let report = project.root.join("report.json");
+    fs::write(
+        &report,
+        r#"{"loop":{"change":{"mean":{"point_estimate":10.0},"regressed":true}}}"#,
+    )
+    .unwrap();
+    let args = format!(
+        "--baseline base --json-report {} --fail-on-regression 50",
+        report.display()
+    );
+    let output = project.wasm_bindgen_bench(&args).unwrap();
+    assert!(output.status.success());
+
+    project.file("benches/loop.rs", &loop_bench(100_000));
+    let output = project.wasm_bindgen_bench(&args).unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("benchmarks regressed by more than 50%"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("loop: +"), "{stderr}");
+}
+
+#[test]
+fn test_bench_fail_on_regression_without_benchmarks() {
+    let output = Project::new("test_bench_fail_on_regression_without_benchmarks")
+        .file("src/lib.rs", "")
+        .file(
+            "benches/empty.rs",
+            r#"
+            use wasm_bindgen_test::{wasm_bindgen_bench, Criterion};
+
+            #[wasm_bindgen_bench]
+            fn bench(_: &mut Criterion) {}
+        "#,
+        )
+        .wasm_bindgen_bench("--fail-on-regression 5")
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("no benchmarks were run, skipping the regression check"),
+        "{stdout}"
+    );
+}
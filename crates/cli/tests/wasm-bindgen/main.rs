@@ -539,3 +539,99 @@ fn constructor_cannot_return_option_struct() {
         .wasm_bindgen("--target web")
         .unwrap_err();
 }
+
+#[test]
+fn thread_stack_size_must_be_page_multiple() {
+    Project::new("thread_stack_size_must_be_page_multiple")
+        .file(
+            "src/lib.rs",
+            r#"
+                use wasm_bindgen::prelude::*;
+
+                #[wasm_bindgen]
+                pub fn foo() {}
+            "#,
+        )
+        .wasm_bindgen("--thread-stack-size 1000")
+        .unwrap_err();
+}
+
+#[test]
+fn thread_stack_size_must_fit_in_i32() {
+    Project::new("thread_stack_size_must_fit_in_i32")
+        .file(
+            "src/lib.rs",
+            r#"
+                use wasm_bindgen::prelude::*;
+
+                #[wasm_bindgen]
+                pub fn foo() {}
+            "#,
+        )
+        .wasm_bindgen("--thread-stack-size 2147483648")
+        .unwrap_err();
+}
+
+#[test]
+fn snapshot_dir_detects_changes() {
+    let mut project = Project::new("snapshot_dir_detects_changes");
+    project.file(
+        "src/lib.rs",
+        r#"
+            use wasm_bindgen::prelude::*;
+            #[wasm_bindgen(inline_js = "export function bar() { return 1; }")]
+            extern "C" {
+                fn bar() -> u32;
+            }
+            #[wasm_bindgen]
+            pub fn foo(a: u32) -> u32 { a + bar() }
+        "#,
+    );
+    let out_dir = project.wasm_bindgen("").unwrap();
+
+    // Snapshots cover the inline JS snippets too.
+    let snapshot_dir = project.root.join("snapshots");
+    let args = format!("--snapshot-dir {}", snapshot_dir.display());
+    let bless = env::var("BLESS").is_ok_and(|value| !matches!(value.as_str(), "" | "0" | "false"));
+    if !bless {
+        let err = project.wasm_bindgen(&args).unwrap_err();
+        assert!(err.to_string().contains("`snippets/"));
+    }
+    copy_snapshot_files(&out_dir, &snapshot_dir);
+    let snapshot_out_dir = project.wasm_bindgen(&args).unwrap();
+
+    // `BLESS` overwrites the snapshots instead of comparing them.
+    if bless {
+        return;
+    }
+
+    // Files left in the output directory by earlier runs aren't snapshotted.
+    fs::write(snapshot_out_dir.join("stale.js"), "").unwrap();
+    project.wasm_bindgen(&args).unwrap();
+
+    let d_ts = snapshot_dir.join("snapshot_dir_detects_changes.d.ts");
+    let contents = fs::read_to_string(&d_ts).unwrap();
+    fs::write(&d_ts, contents.replace("a: number", "b: number")).unwrap();
+    let err = project.wasm_bindgen(&args).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("`snapshot_dir_detects_changes.d.ts`: line"));
+}
+
+/// Copies the JS and TypeScript files in `src`, including its subdirectories,
+/// to `dst`.
+fn copy_snapshot_files(src: &Path, dst: &Path) {
+    fs::create_dir_all(dst).unwrap();
+    for entry in fs::read_dir(src).unwrap() {
+        let path = entry.unwrap().path();
+        let dst = dst.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_snapshot_files(&path, &dst);
+        } else if [".js", ".d.ts"]
+            .iter()
+            .any(|ext| path.to_str().unwrap().ends_with(ext))
+        {
+            fs::copy(&path, dst).unwrap();
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Control over when and in which order the futures of [`spawn_local`] are
+//! polled.
+//!
+//! By default, futures spawned on a thread are polled in a microtask as soon
+//! as they're woken. Applications driving their work from a game loop, a
+//! `requestAnimationFrame` callback or an `AudioWorklet` can instead poll
+//! them at a time of their choosing by installing a scheduler with
+//! [`set_scheduler`] and calling [`run_until_stalled`] from it.
+//!
+//! Futures can also be given a [`Priority`] with [`spawn_local_with_priority`],
+//! so that UI-critical futures are polled before background ones.
+//!
+//! [`spawn_local`]: crate::spawn_local
+
+use alloc::rc::Rc;
+use core::future::Future;
+
+use crate::queue::Queue;
+use crate::task::Task;
+
+/// The priority of a future spawned with [`spawn_local_with_priority`].
+///
+/// Whenever futures are polled, woken futures of a higher priority are polled
+/// before the ones of a lower priority, and futures of the same priority in
+/// the order they were woken in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    /// For futures which the user is waiting on, like ones updating the UI.
+    High,
+    /// The priority of futures spawned with [`spawn_local`](crate::spawn_local).
+    #[default]
+    Normal,
+    /// For background work which can wait for other futures.
+    Low,
+}
+
+impl Priority {
+    pub(crate) const COUNT: usize = 3;
+}
+
+/// Runs a Rust `Future` on the current thread with the given `priority`.
+///
+/// This is the same as [`spawn_local`](crate::spawn_local) otherwise, which
+/// spawns futures with [`Priority::Normal`].
+pub fn spawn_local_with_priority<F>(future: F, priority: Priority)
+where
+    F: Future<Output = ()> + 'static,
+{
+    Task::spawn(future, priority);
+}
+
+/// Sets the scheduler of the current thread, which is called instead of
+/// queueing a microtask whenever futures were woken and need to be polled.
+///
+/// The scheduler shouldn't poll the futures itself, but arrange for
+/// [`run_until_stalled`] to be called later, for example in the next
+/// `requestAnimationFrame` callback or `setTimeout(0)`. It isn't called again
+/// until then. A scheduler which does nothing leaves it to the application to
+/// call [`run_until_stalled`] regularly, like from the `process()` method of
+/// an `AudioWorkletProcessor`.
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::prelude::*;
+/// use wasm_bindgen_futures::executor;
+///
+/// #[wasm_bindgen]
+/// extern "C" {
+///     #[wasm_bindgen(js_name = requestAnimationFrame)]
+///     fn request_animation_frame(callback: &Closure<dyn FnMut()>);
+/// }
+///
+/// let run = Closure::new(executor::run_until_stalled);
+/// executor::set_scheduler(move || request_animation_frame(&run));
+/// ```
+pub fn set_scheduler(scheduler: impl Fn() + 'static) {
+    Queue::with(|queue| queue.set_scheduler(Some(Rc::new(scheduler))));
+}
+
+/// Restores the default scheduler of the current thread, which polls woken
+/// futures in a microtask.
+///
+/// Futures which are already waiting for a scheduled [`run_until_stalled`] call
+/// are still polled by it.
+pub fn reset_scheduler() {
+    Queue::with(|queue| queue.set_scheduler(None));
+}
+
+/// Polls the woken futures of the current thread until none of them are
+/// woken anymore, including the ones woken while polling.
+///
+/// This does nothing when called while futures are polled, like from a future
+/// spawned with [`spawn_local`](crate::spawn_local).
+pub fn run_until_stalled() {
+    Queue::with(Queue::run_until_stalled);
+}
@@ -56,10 +56,22 @@ use wasm_bindgen::__rt::panic_to_panic_error;
 use wasm_bindgen::convert::{FromWasmAbi, Upcast};
 use wasm_bindgen::{prelude::*, JsError, JsGeneric};
 
+pub mod executor;
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", target_feature = "atomics"))))]
+#[cfg(all(feature = "std", target_feature = "atomics"))]
+pub mod pool;
 mod queue;
 #[cfg_attr(docsrs, doc(cfg(feature = "futures-core-03-stream")))]
 #[cfg(feature = "futures-core-03-stream")]
 pub mod stream;
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", target_feature = "atomics"))))]
+#[cfg(all(feature = "std", target_feature = "atomics"))]
+pub mod sync;
+pub mod time;
+
+#[cfg_attr(docsrs, doc(cfg(all(feature = "std", target_feature = "atomics"))))]
+#[cfg(all(feature = "std", target_feature = "atomics"))]
+pub use sync::{block_on, BlockError};
 
 pub use js_sys;
 pub use wasm_bindgen;
@@ -86,7 +98,8 @@ mod task {
 /// to run in the background and cannot contain any stack references.
 ///
 /// The `future` will always be run on the next microtask tick even if it
-/// immediately returns `Poll::Ready`.
+/// immediately returns `Poll::Ready`, unless a different scheduler was set
+/// with [`executor::set_scheduler`].
 ///
 /// # Panics
 ///
@@ -96,7 +109,7 @@ pub fn spawn_local<F>(future: F)
 where
     F: Future<Output = ()> + 'static,
 {
-    task::Task::spawn(future);
+    task::Task::spawn(future, executor::Priority::Normal);
 }
 
 struct Inner<T = JsValue> {
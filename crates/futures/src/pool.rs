@@ -0,0 +1,638 @@
+//! A work-stealing thread pool running `Send` futures on workers.
+//!
+//! A [`ThreadPool`] spawns a fixed number of threads with
+//! [`wasm_bindgen::thread::spawn`], each of which runs futures spawned onto
+//! the pool until the pool is shut down. Every thread has its own queue of
+//! futures to run and steals from the queues of the others when it runs out of
+//! work, sleeping with `Atomics.wait` once there's nothing left to do.
+//!
+//! The threads of the pool block instead of yielding to their event loop, so
+//! futures on the pool can't wait for JS promises. This is what the pool is
+//! for: CPU-heavy work which otherwise needs hand-written worker glue, whose
+//! results are awaited on the main thread through a [`JoinHandle`] like any
+//! other future.
+//!
+//! If a thread of the pool fails, for example because a future panicked when
+//! building with `panic=abort`, the pool stops: its other threads exit once
+//! they're done polling, and all futures which didn't complete yet resolve
+//! their [`JoinHandle`]s to an error.
+
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use alloc::sync::{Arc, Weak};
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell, UnsafeCell};
+use core::fmt;
+use core::future::{poll_fn, Future};
+use core::pin::Pin;
+use core::ptr;
+use core::sync::atomic::Ordering::SeqCst;
+use core::sync::atomic::{AtomicBool, AtomicI32, AtomicPtr, AtomicU8, AtomicUsize};
+use core::task::{Context, Poll, Waker};
+use std::sync::Mutex;
+use std::task::Wake;
+use wasm_bindgen::JsValue;
+
+type BoxFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// A pool of threads running `Send` futures, created with
+/// [`ThreadPool::new`].
+///
+/// Dropping the pool shuts it down like [`ThreadPool::shutdown`] without
+/// waiting for its threads to exit.
+///
+/// The threads' exits are noticed by the event loop of the thread which
+/// created the pool, so [`ThreadPool::shutdown`] only completes, and a failed
+/// thread is only detected, once that thread yields to its event loop.
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::prelude::*;
+/// use wasm_bindgen_futures::pool::ThreadPool;
+///
+/// #[wasm_bindgen]
+/// pub async fn sum_of_squares(n: u64) -> Result<u64, JsValue> {
+///     let pool = ThreadPool::new(4);
+///     let handles: Vec<_> = (0..4)
+///         .map(|i| pool.spawn(async move { (i..n).step_by(4).map(|x| x * x).sum::<u64>() }))
+///         .collect();
+///     let mut sum = 0;
+///     for handle in handles {
+///         sum += handle.await.map_err(|_| JsValue::from_str("task panicked"))?;
+///     }
+///     pool.shutdown().await?;
+///     Ok(sum)
+/// }
+/// ```
+pub struct ThreadPool {
+    shared: Arc<Shared>,
+    exits: Rc<Exits>,
+}
+
+/// How the threads of a pool exited, updated by the thread which created the
+/// pool.
+struct Exits {
+    /// The number of threads which didn't exit yet.
+    running: Cell<usize>,
+    /// The error reported by the first thread which failed.
+    error: RefCell<Option<JsValue>>,
+    waker: RefCell<Option<Waker>>,
+}
+
+impl ThreadPool {
+    /// Creates a pool of `threads` threads.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threads` is zero or a thread can't be spawned, see
+    /// [`wasm_bindgen::thread::spawn`].
+    pub fn new(threads: usize) -> ThreadPool {
+        assert!(threads > 0, "a thread pool needs at least one thread");
+        let shared = Arc::new(Shared {
+            injector: Injector::default(),
+            queues: (0..threads).map(|_| Mutex::default()).collect(),
+            futex: AtomicI32::new(0),
+            sleeping: AtomicUsize::new(0),
+            live: AtomicUsize::new(0),
+            shutdown: AtomicBool::new(false),
+            failed: AtomicBool::new(false),
+            joins: SpinLock::new(Vec::new()),
+        });
+        let exits = Rc::new(Exits {
+            running: Cell::new(threads),
+            error: RefCell::new(None),
+            waker: RefCell::new(None),
+        });
+        for index in 0..threads {
+            let their_shared = shared.clone();
+            let thread = wasm_bindgen::thread::spawn(move || {
+                Worker {
+                    shared: their_shared,
+                    index,
+                }
+                .run()
+            });
+            let shared = shared.clone();
+            let exits = exits.clone();
+            crate::spawn_local(async move {
+                if let Err(error) = thread.join_async().await {
+                    shared.fail();
+                    exits.error.borrow_mut().get_or_insert(error);
+                }
+                exits.running.set(exits.running.get() - 1);
+                if let Some(waker) = exits.waker.borrow_mut().take() {
+                    waker.wake();
+                }
+            });
+        }
+        ThreadPool { shared, exits }
+    }
+
+    /// Returns the number of threads of the pool.
+    pub fn threads(&self) -> usize {
+        self.shared.queues.len()
+    }
+
+    /// Spawns `future` onto the pool, returning a [`JoinHandle`] which
+    /// resolves to its output.
+    ///
+    /// The future runs on the threads of the pool, possibly moving between
+    /// them whenever it's woken. Dropping the [`JoinHandle`] doesn't cancel
+    /// the future. If the pool failed, the [`JoinHandle`] resolves to an error
+    /// instead.
+    pub fn spawn<F>(&self, future: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let join = Arc::new(JoinState {
+            result: SpinLock::new(None),
+            waker: SpinLock::new(None),
+        });
+        let their_join = join.clone();
+        self.shared.register(&join);
+        let future: BoxFuture = Box::pin(async move {
+            let output = CatchUnwind(future).await;
+            *their_join.result.lock() = Some(output);
+            if let Some(waker) = their_join.waker.lock().take() {
+                waker.wake();
+            }
+        });
+
+        self.shared.live.fetch_add(1, SeqCst);
+        let task = Arc::new(Task {
+            future: Mutex::new(Some(future)),
+            state: AtomicU8::new(SCHEDULED),
+            shared: self.shared.clone(),
+        });
+        self.shared.schedule(task);
+        JoinHandle { join }
+    }
+
+    /// Shuts the pool down once all futures spawned onto it completed,
+    /// resolving once all of its threads exited.
+    ///
+    /// Futures which never complete keep the pool alive. If a thread of the
+    /// pool failed, this rejects with the error it reported as soon as the
+    /// failure is noticed, without waiting for the other threads.
+    pub async fn shutdown(self) -> Result<(), JsValue> {
+        self.shared.shutdown();
+        poll_fn(|cx| {
+            if let Some(error) = self.exits.error.borrow_mut().take() {
+                return Poll::Ready(Err(error));
+            }
+            if self.exits.running.get() == 0 {
+                return Poll::Ready(Ok(()));
+            }
+            *self.exits.waker.borrow_mut() = Some(cx.waker().clone());
+            Poll::Pending
+        })
+        .await
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        self.shared.shutdown();
+    }
+}
+
+impl fmt::Debug for ThreadPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ThreadPool")
+            .field("threads", &self.threads())
+            .finish_non_exhaustive()
+    }
+}
+
+/// A handle to a future spawned with [`ThreadPool::spawn`], which resolves to
+/// its output.
+///
+/// Like [`std::thread::JoinHandle::join`], this resolves to an error with the
+/// panic payload if the future panicked, which can only happen when building
+/// with `panic=unwind`. If a thread of the pool failed before the future
+/// completed, it resolves to an error with a `&str` payload instead.
+pub struct JoinHandle<T> {
+    join: Arc<JoinState<T>>,
+}
+
+struct JoinState<T> {
+    result: SpinLock<Option<std::thread::Result<T>>>,
+    waker: SpinLock<Option<Waker>>,
+}
+
+/// A [`JoinState`] of any output type, which is resolved to an error if the
+/// pool fails.
+trait Abandon: Send + Sync {
+    fn abandon(&self);
+}
+
+impl<T: Send> Abandon for JoinState<T> {
+    fn abandon(&self) {
+        self.result
+            .lock()
+            .get_or_insert_with(|| Err(Box::new(POOL_FAILED)));
+        if let Some(waker) = self.waker.lock().take() {
+            waker.wake();
+        }
+    }
+}
+
+const POOL_FAILED: &str = "a thread of the pool failed";
+
+impl<T> Future for JoinHandle<T> {
+    type Output = std::thread::Result<T>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Register the waker before checking the result so that a result
+        // stored in the meantime wakes us up.
+        *self.join.waker.lock() = Some(cx.waker().clone());
+        match self.join.result.lock().take() {
+            Some(result) => Poll::Ready(result),
+            None => Poll::Pending,
+        }
+    }
+}
+
+impl<T> fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinHandle").finish_non_exhaustive()
+    }
+}
+
+/// Polls a future, catching panics when building with `panic=unwind`.
+struct CatchUnwind<F>(F);
+
+impl<F: Future> Future for CatchUnwind<F> {
+    type Output = std::thread::Result<F::Output>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let future = unsafe { self.map_unchecked_mut(|this| &mut this.0) };
+        #[cfg(panic = "unwind")]
+        let poll = match std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| future.poll(cx)))
+        {
+            Ok(poll) => poll.map(Ok),
+            Err(payload) => Poll::Ready(Err(payload)),
+        };
+        #[cfg(not(panic = "unwind"))]
+        let poll = future.poll(cx).map(Ok);
+        poll
+    }
+}
+
+/// The task is neither queued nor running.
+const IDLE: u8 = 0;
+/// The task is in a queue, waiting to be run.
+const SCHEDULED: u8 = 1;
+/// The task is being polled.
+const RUNNING: u8 = 2;
+/// The task was woken while being polled, so it's polled again afterwards.
+const NOTIFIED: u8 = 3;
+/// The future of the task completed.
+const DONE: u8 = 4;
+
+struct Task {
+    // Only ever locked by the thread running the task.
+    future: Mutex<Option<BoxFuture>>,
+    state: AtomicU8,
+    shared: Arc<Shared>,
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        let mut state = self.state.load(SeqCst);
+        loop {
+            let next = match state {
+                IDLE => SCHEDULED,
+                RUNNING => NOTIFIED,
+                _ => return,
+            };
+            match self.state.compare_exchange(state, next, SeqCst, SeqCst) {
+                Ok(_) => break,
+                Err(actual) => state = actual,
+            }
+        }
+        // A running task is scheduled again by its thread once it's done
+        // polling.
+        if state == IDLE {
+            self.shared.schedule(self.clone());
+        }
+    }
+}
+
+impl Task {
+    fn run(self: Arc<Self>) {
+        let prev = self.state.swap(RUNNING, SeqCst);
+        debug_assert_eq!(prev, SCHEDULED);
+
+        let waker = Waker::from(self.clone());
+        let mut cx = Context::from_waker(&waker);
+        let mut future = self.future.lock().unwrap();
+        let poll = match future.as_mut() {
+            Some(future) => future.as_mut().poll(&mut cx),
+            None => return,
+        };
+
+        if poll.is_ready() {
+            *future = None;
+            self.state.store(DONE, SeqCst);
+            if self.shared.live.fetch_sub(1, SeqCst) == 1 {
+                // The last future completed, so a pool which was shut down
+                // can exit now.
+                self.shared.notify(u32::MAX);
+            }
+            return;
+        }
+
+        // If we were woken while polling, poll again after the other work that
+        // was scheduled in the meantime.
+        if self
+            .state
+            .compare_exchange(RUNNING, IDLE, SeqCst, SeqCst)
+            .is_err()
+        {
+            self.state.store(SCHEDULED, SeqCst);
+            drop(future);
+            let shared = self.shared.clone();
+            shared.schedule(self);
+        }
+    }
+}
+
+struct Shared {
+    /// Tasks scheduled from outside the pool.
+    injector: Injector,
+    /// The queue of each thread, from whose front other threads steal.
+    queues: Vec<Mutex<VecDeque<Arc<Task>>>>,
+    /// Bumped whenever work is scheduled, for sleeping threads to wait on.
+    futex: AtomicI32,
+    /// The number of threads sleeping or about to sleep.
+    sleeping: AtomicUsize,
+    /// The number of spawned futures which didn't complete yet.
+    live: AtomicUsize,
+    shutdown: AtomicBool,
+    /// Set once a thread of the pool failed.
+    failed: AtomicBool,
+    /// The join states of spawned futures, to resolve if the pool fails.
+    joins: SpinLock<Vec<Weak<dyn Abandon>>>,
+}
+
+#[thread_local]
+static CURRENT_WORKER: Cell<Option<(*const Shared, usize)>> = Cell::new(None);
+
+impl Shared {
+    fn schedule(&self, task: Arc<Task>) {
+        // Tasks scheduled by a thread of the pool are run by it unless stolen,
+        // everything else goes through the lock-free injector, as other
+        // threads, like the main thread of a browser, can't block to wait for
+        // a lock.
+        match CURRENT_WORKER.get() {
+            Some((shared, index)) if ptr::eq(shared, self) => {
+                self.queues[index].lock().unwrap().push_back(task);
+            }
+            _ => self.injector.push(task),
+        }
+        self.notify(1);
+    }
+
+    fn notify(&self, count: u32) {
+        self.futex.fetch_add(1, SeqCst);
+        if self.sleeping.load(SeqCst) > 0 {
+            unsafe {
+                core::arch::wasm32::memory_atomic_notify(self.futex.as_ptr(), count);
+            }
+        }
+    }
+
+    fn shutdown(&self) {
+        self.shutdown.store(true, SeqCst);
+        self.notify(u32::MAX);
+    }
+
+    fn is_finished(&self) -> bool {
+        self.failed.load(SeqCst) || (self.shutdown.load(SeqCst) && self.live.load(SeqCst) == 0)
+    }
+
+    /// Keeps track of the join state of a spawned future, resolving it right
+    /// away if the pool already failed.
+    fn register<T: Send + 'static>(&self, join: &Arc<JoinState<T>>) {
+        {
+            let mut joins = self.joins.lock();
+            // Forget futures which completed and whose handle was dropped,
+            // once that might make a difference.
+            if joins.len() == joins.capacity() {
+                joins.retain(|join| join.strong_count() > 0);
+            }
+            let join: Arc<dyn Abandon> = join.clone();
+            joins.push(Arc::downgrade(&join));
+        }
+        if self.failed.load(SeqCst) {
+            join.abandon();
+        }
+    }
+
+    /// Stops the pool after one of its threads failed, so that the futures
+    /// it was running never complete and `live` is never decremented for
+    /// them.
+    fn fail(&self) {
+        self.failed.store(true, SeqCst);
+        let joins = core::mem::take(&mut *self.joins.lock());
+        for join in joins {
+            if let Some(join) = join.upgrade() {
+                join.abandon();
+            }
+        }
+        self.notify(u32::MAX);
+    }
+}
+
+struct Worker {
+    shared: Arc<Shared>,
+    index: usize,
+}
+
+impl Worker {
+    fn run(self) {
+        CURRENT_WORKER.set(Some((Arc::as_ptr(&self.shared), self.index)));
+        loop {
+            if self.shared.failed.load(SeqCst) {
+                break;
+            }
+            if let Some(task) = self.find_task() {
+                task.run();
+                continue;
+            }
+            if self.shared.is_finished() {
+                break;
+            }
+            self.sleep();
+        }
+        CURRENT_WORKER.set(None);
+    }
+
+    fn find_task(&self) -> Option<Arc<Task>> {
+        let queues = &self.shared.queues;
+        if let Some(task) = queues[self.index].lock().unwrap().pop_back() {
+            return Some(task);
+        }
+
+        // Take everything scheduled from outside the pool, leaving the rest
+        // for other threads to steal.
+        let mut injected = self.shared.injector.take_all();
+        if let Some(task) = injected.pop_front() {
+            if !injected.is_empty() {
+                queues[self.index].lock().unwrap().extend(injected);
+                self.shared.notify(1);
+            }
+            return Some(task);
+        }
+
+        // Steal half of the queue of the first other thread with work.
+        for offset in 1..queues.len() {
+            let victim = (self.index + offset) % queues.len();
+            let mut stolen = {
+                let mut queue = queues[victim].lock().unwrap();
+                let half = (queue.len() + 1) / 2;
+                queue.drain(..half).collect::<VecDeque<_>>()
+            };
+            if let Some(task) = stolen.pop_front() {
+                queues[self.index].lock().unwrap().extend(stolen);
+                return Some(task);
+            }
+        }
+        None
+    }
+
+    fn has_work(&self) -> bool {
+        !self.shared.injector.is_empty()
+            || self
+                .shared
+                .queues
+                .iter()
+                .any(|queue| !queue.lock().unwrap().is_empty())
+    }
+
+    fn sleep(&self) {
+        let shared = &self.shared;
+        // Anything scheduled after reading the futex changes it, in which case
+        // the wait returns immediately.
+        let futex = shared.futex.load(SeqCst);
+        shared.sleeping.fetch_add(1, SeqCst);
+        if !self.has_work() && !shared.is_finished() {
+            unsafe {
+                core::arch::wasm32::memory_atomic_wait32(shared.futex.as_ptr(), futex, -1);
+            }
+        }
+        shared.sleeping.fetch_sub(1, SeqCst);
+    }
+}
+
+/// A lock-free stack of tasks which any thread can push to.
+#[derive(Default)]
+struct Injector {
+    head: AtomicPtr<Node>,
+}
+
+struct Node {
+    task: Arc<Task>,
+    next: *mut Node,
+}
+
+impl Injector {
+    fn push(&self, task: Arc<Task>) {
+        let node = Box::into_raw(Box::new(Node {
+            task,
+            next: ptr::null_mut(),
+        }));
+        let mut head = self.head.load(SeqCst);
+        loop {
+            unsafe { (*node).next = head };
+            match self.head.compare_exchange(head, node, SeqCst, SeqCst) {
+                Ok(_) => return,
+                Err(actual) => head = actual,
+            }
+        }
+    }
+
+    /// Takes all pushed tasks, oldest first.
+    fn take_all(&self) -> VecDeque<Arc<Task>> {
+        let mut node = self.head.swap(ptr::null_mut(), SeqCst);
+        let mut tasks = VecDeque::new();
+        while !node.is_null() {
+            let boxed = unsafe { Box::from_raw(node) };
+            node = boxed.next;
+            tasks.push_front(boxed.task);
+        }
+        tasks
+    }
+
+    fn is_empty(&self) -> bool {
+        self.head.load(SeqCst).is_null()
+    }
+}
+
+impl Drop for Injector {
+    fn drop(&mut self) {
+        drop(self.take_all());
+    }
+}
+
+/// A lock which spins instead of blocking, for the short critical sections
+/// of state shared with threads which can't block.
+struct SpinLock<T> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T: Send> Send for SpinLock<T> {}
+unsafe impl<T: Send> Sync for SpinLock<T> {}
+
+impl<T> SpinLock<T> {
+    fn new(value: T) -> Self {
+        SpinLock {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    fn lock(&self) -> SpinLockGuard<'_, T> {
+        while self
+            .locked
+            .compare_exchange_weak(false, true, SeqCst, SeqCst)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+        SpinLockGuard { lock: self }
+    }
+}
+
+struct SpinLockGuard<'a, T> {
+    lock: &'a SpinLock<T>,
+}
+
+impl<T> core::ops::Deref for SpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T> core::ops::DerefMut for SpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T> Drop for SpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.locked.store(false, SeqCst);
+    }
+}
@@ -6,6 +6,8 @@ use js_sys::Promise;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsError;
 
+use crate::executor::Priority;
+
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen]
@@ -18,28 +20,48 @@ extern "C" {
 }
 
 struct QueueState {
-    // The queue of Tasks which are to be run in order. In practice this is all the
-    // synchronous work of futures, and each `Task` represents calling `poll` on
-    // a future "at the right time".
-    tasks: RefCell<VecDeque<Rc<crate::task::Task>>>,
+    // The queues of Tasks which are to be run in order, one per priority, the
+    // highest first. In practice this is all the synchronous work of futures,
+    // and each `Task` represents calling `poll` on a future "at the right time".
+    tasks: RefCell<[VecDeque<Rc<crate::task::Task>>; Priority::COUNT]>,
 
     // This flag indicates whether we've scheduled `run_all` to run in the future.
     // This is used to ensure that it's only scheduled once.
     is_scheduled: Cell<bool>,
+
+    // Whether tasks are being run, to ignore nested calls to `run_until_stalled`.
+    is_running: Cell<bool>,
+
+    // The scheduler set with `executor::set_scheduler`, which is called instead
+    // of queueing a microtask.
+    scheduler: RefCell<Option<Rc<dyn Fn()>>>,
 }
 
 impl QueueState {
+    fn pop(&self) -> Option<Rc<crate::task::Task>> {
+        self.tasks
+            .borrow_mut()
+            .iter_mut()
+            .find_map(|tasks| tasks.pop_front())
+    }
+
+    fn len(&self) -> usize {
+        self.tasks.borrow().iter().map(VecDeque::len).sum()
+    }
+
     fn run_all(&self) {
         // "consume" the schedule
-        let _was_scheduled = self.is_scheduled.replace(false);
-        debug_assert!(_was_scheduled);
+        self.is_scheduled.set(false);
+        if self.is_running.replace(true) {
+            return;
+        }
 
         // Stop when all tasks that have been scheduled before this tick have been run.
         // Tasks that are scheduled while running tasks will run on the next tick.
-        let mut task_count_left = self.tasks.borrow().len();
+        let mut task_count_left = self.len();
         while task_count_left > 0 {
             task_count_left -= 1;
-            let task = match self.tasks.borrow_mut().pop_front() {
+            let task = match self.pop() {
                 Some(task) => task,
                 None => break,
             };
@@ -48,6 +70,21 @@ impl QueueState {
 
         // All of the Tasks have been run, so it's now possible to schedule the
         // next tick again
+        self.is_running.set(false);
+    }
+
+    fn run_until_stalled(&self) {
+        if self.is_running.replace(true) {
+            return;
+        }
+        // Tasks woken while running are run as well, so there's no need to
+        // schedule another run until we're done.
+        self.is_scheduled.set(true);
+        while let Some(task) = self.pop() {
+            task.run();
+        }
+        self.is_scheduled.set(false);
+        self.is_running.set(false);
     }
 }
 
@@ -61,11 +98,14 @@ pub(crate) struct Queue {
 impl Queue {
     // Schedule a task to run on the next tick
     pub(crate) fn schedule_task(&self, task: Rc<crate::task::Task>) {
-        self.state.tasks.borrow_mut().push_back(task);
+        self.state.tasks.borrow_mut()[task.priority as usize].push_back(task);
         // Use queueMicrotask to execute as soon as possible. If it does not exist
         // fall back to the promise resolution
         if !self.state.is_scheduled.replace(true) {
-            if self.has_queue_microtask {
+            let scheduler = self.state.scheduler.borrow().clone();
+            if let Some(scheduler) = scheduler {
+                scheduler();
+            } else if self.has_queue_microtask {
                 queueMicrotask(&self.closure);
             } else {
                 let _ = self.promise.then_map(&self.closure);
@@ -85,7 +125,9 @@ impl Queue {
     fn new() -> Self {
         let state = Rc::new(QueueState {
             is_scheduled: Cell::new(false),
-            tasks: RefCell::new(VecDeque::new()),
+            is_running: Cell::new(false),
+            tasks: RefCell::new(Default::default()),
+            scheduler: RefCell::new(None),
         });
 
         let has_queue_microtask = js_sys::global()
@@ -112,6 +154,14 @@ impl Queue {
         }
     }
 
+    pub(crate) fn set_scheduler(&self, scheduler: Option<Rc<dyn Fn()>>) {
+        *self.state.scheduler.borrow_mut() = scheduler;
+    }
+
+    pub(crate) fn run_until_stalled(&self) {
+        self.state.run_until_stalled();
+    }
+
     pub(crate) fn with<R>(f: impl FnOnce(&Self) -> R) -> R {
         use once_cell::unsync::Lazy;
 
@@ -4,6 +4,10 @@
 //! turning objects implementing the async iterator protocol into `Stream`s
 //! that produce values that can be awaited from.
 //!
+//! With the `web-streams` feature, it also converts between JS
+//! `ReadableStream`s and Rust `Stream`s, and between JS `WritableStream`s and
+//! Rust `Sink`s, in both directions.
+//!
 
 use crate::JsFuture;
 use core::future::Future;
@@ -14,6 +18,18 @@ use js_sys::{AsyncIterator, IteratorNext};
 use wasm_bindgen::convert::FromWasmAbi;
 use wasm_bindgen::{prelude::*, JsGeneric};
 
+#[cfg(feature = "web-streams")]
+mod readable;
+#[cfg(feature = "web-streams")]
+mod writable;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "web-streams")))]
+#[cfg(feature = "web-streams")]
+pub use readable::{readable_stream, ByobReader, ReaderStream};
+#[cfg_attr(docsrs, doc(cfg(feature = "web-streams")))]
+#[cfg(feature = "web-streams")]
+pub use writable::{writable_stream, WriterSink};
+
 /// A `Stream` that yields values from an underlying `AsyncIterator`.
 pub struct JsStream<T = JsValue> {
     iter: AsyncIterator<T>,
@@ -0,0 +1,282 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::future::{poll_fn, Future};
+use core::marker::PhantomData;
+use core::panic::AssertUnwindSafe;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_core::stream::Stream;
+use js_sys::{Promise, Uint8Array};
+use wasm_bindgen::prelude::*;
+use web_sys::{
+    ReadableStream, ReadableStreamByobReader, ReadableStreamDefaultController,
+    ReadableStreamDefaultReader, ReadableStreamReadResult, UnderlyingSource,
+};
+
+use crate::{future_to_promise, JsFuture};
+
+/// Cancels a reader's stream without leaving its promise unhandled, which
+/// rejects if the stream already errored.
+fn cancel(promise: Promise) {
+    drop(JsFuture::from(promise));
+}
+
+/// A `Stream` that yields the chunks read from a JS `ReadableStream`.
+///
+/// The stream is locked to this reader until it's dropped, which cancels the
+/// stream unless it was read to the end, then releases the lock. A chunk is only requested from the
+/// stream when the previous one was consumed, so a slow consumer applies
+/// backpressure to the stream's source.
+pub struct ReaderStream<T = JsValue> {
+    reader: ReadableStreamDefaultReader,
+    read: Option<JsFuture>,
+    done: bool,
+    _chunk: PhantomData<fn() -> T>,
+}
+
+impl<T> ReaderStream<T> {
+    /// Locks `stream` to a new reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns the JS exception if `stream` is already locked.
+    pub fn new(stream: &ReadableStream) -> Result<ReaderStream<T>, JsValue> {
+        Ok(ReaderStream {
+            reader: ReadableStreamDefaultReader::new(stream)?,
+            read: None,
+            done: false,
+            _chunk: PhantomData,
+        })
+    }
+}
+
+impl<T: JsCast> Stream for ReaderStream<T> {
+    type Item = Result<T, JsValue>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+
+        let this = &mut *self;
+        let read = this
+            .read
+            .get_or_insert_with(|| JsFuture::from(this.reader.read()));
+        let result = ready!(Pin::new(read).poll(cx));
+        this.read = None;
+
+        match result {
+            Ok(result) => {
+                let result = result.unchecked_into::<ReadableStreamReadResult>();
+                if result.get_done().unwrap_or_default() {
+                    this.done = true;
+                    Poll::Ready(None)
+                } else {
+                    Poll::Ready(Some(Ok(result.get_value().unchecked_into())))
+                }
+            }
+            Err(e) => {
+                this.done = true;
+                Poll::Ready(Some(Err(e)))
+            }
+        }
+    }
+}
+
+impl<T> Drop for ReaderStream<T> {
+    fn drop(&mut self) {
+        if !self.done {
+            cancel(self.reader.cancel());
+        }
+        self.reader.release_lock();
+    }
+}
+
+/// Reads the bytes of a JS byte stream into Rust slices, with a
+/// "bring your own buffer" reader.
+///
+/// The stream hands back the buffer it read into with every read, and is
+/// locked to this reader until it's dropped, which cancels the stream unless
+/// it was read to the end, then releases the lock.
+pub struct ByobReader {
+    reader: ReadableStreamByobReader,
+    // The buffer read into by the last read, reused by the next one.
+    buffer: Option<Uint8Array>,
+    // A read which didn't complete before its future was dropped, completed
+    // by the next one.
+    read: Option<JsFuture>,
+    // The bytes of the last read which didn't fit into the slice read into.
+    rest: Option<Uint8Array>,
+    done: bool,
+}
+
+impl ByobReader {
+    /// Locks `stream` to a new reader.
+    ///
+    /// # Errors
+    ///
+    /// Returns the JS exception if `stream` is already locked or isn't a byte
+    /// stream.
+    pub fn new(stream: &ReadableStream) -> Result<ByobReader, JsValue> {
+        Ok(ByobReader {
+            reader: ReadableStreamByobReader::new(stream)?,
+            buffer: None,
+            read: None,
+            rest: None,
+            done: false,
+        })
+    }
+
+    /// Reads bytes from the stream into `buf`, returning how many were read.
+    ///
+    /// This returns `Ok(0)` once the stream ended, or if `buf` is empty. The
+    /// bytes are read into a JS buffer and copied into `buf`, as the stream
+    /// takes ownership of the buffer it reads into, which can't be the memory
+    /// of the module.
+    ///
+    /// This is cancel-safe: if the returned future is dropped before it
+    /// completes, its read is kept and completed by the next call, so no bytes
+    /// are lost. Bytes which don't fit into the `buf` of that call are
+    /// returned by the calls after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the stream if it errored.
+    pub async fn read(&mut self, buf: &mut [u8]) -> Result<usize, JsValue> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        if let Some(rest) = self.rest.take() {
+            return Ok(self.copy_chunk(rest, buf));
+        }
+        if self.done {
+            return Ok(0);
+        }
+
+        let read = match &mut self.read {
+            Some(read) => read,
+            read => {
+                let len = u32::try_from(buf.len()).unwrap_or(u32::MAX);
+                let buffer = match self.buffer.take() {
+                    Some(buffer) if buffer.byte_length() >= len => buffer,
+                    _ => Uint8Array::new_with_length(len),
+                };
+                let view = Uint8Array::new_with_byte_offset_and_length(&buffer.buffer(), 0, len);
+                read.insert(JsFuture::from(
+                    self.reader.read_with_array_buffer_view(&view),
+                ))
+            }
+        };
+        let result = read.await;
+        self.read = None;
+        let result = match result {
+            Ok(result) => result.unchecked_into::<ReadableStreamReadResult>(),
+            Err(e) => {
+                self.done = true;
+                return Err(e);
+            }
+        };
+        // The value is `undefined` if the stream was cancelled.
+        let value = result.get_value();
+        if value.is_undefined() {
+            self.done = true;
+            return Ok(0);
+        }
+        let value = value.unchecked_into::<Uint8Array>();
+        self.buffer = Some(Uint8Array::new(&value.buffer()));
+        if result.get_done().unwrap_or_default() {
+            self.done = true;
+            return Ok(0);
+        }
+
+        Ok(self.copy_chunk(value, buf))
+    }
+
+    /// Copies as much of `chunk` into `buf` as fits, keeping the rest for the
+    /// next read.
+    fn copy_chunk(&mut self, chunk: Uint8Array, buf: &mut [u8]) -> usize {
+        let len = chunk
+            .length()
+            .min(u32::try_from(buf.len()).unwrap_or(u32::MAX));
+        chunk.subarray(0, len).copy_to(&mut buf[..len as usize]);
+        if len < chunk.length() {
+            self.rest = Some(chunk.subarray(len, chunk.length()));
+        }
+        len as usize
+    }
+}
+
+impl Drop for ByobReader {
+    fn drop(&mut self) {
+        if !self.done {
+            cancel(self.reader.cancel());
+        }
+        self.reader.release_lock();
+    }
+}
+
+/// Converts a Rust `Stream` into a JS `ReadableStream`, which can be returned
+/// from an exported function.
+///
+/// The chunks of the `ReadableStream` are the items of `stream`, which is
+/// only polled when the `ReadableStream` wants more chunks, and the
+/// `ReadableStream` errors with the error of the first item which is an
+/// `Err`. `stream` is dropped once it ended, errored or the `ReadableStream`
+/// was cancelled.
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::prelude::*;
+/// use wasm_bindgen_futures::stream::readable_stream;
+///
+/// #[wasm_bindgen]
+/// pub fn numbers(count: u32) -> web_sys::ReadableStream {
+///     readable_stream(futures_util::stream::iter((0..count).map(Ok::<_, JsValue>)))
+/// }
+/// ```
+pub fn readable_stream<S, T>(stream: S) -> ReadableStream
+where
+    S: Stream<Item = Result<T, JsValue>> + 'static,
+    T: Into<JsValue>,
+{
+    let stream = Rc::new(RefCell::new(Some(Box::pin(stream))));
+
+    let pull = Closure::<dyn FnMut(ReadableStreamDefaultController) -> Promise>::new({
+        let stream = stream.clone();
+        move |controller: ReadableStreamDefaultController| {
+            let stream = stream.clone();
+            // The stream doesn't call `pull` again until the returned promise
+            // settled, so items are never requested concurrently.
+            future_to_promise(AssertUnwindSafe(async move {
+                let next = poll_fn(|cx| match &mut *stream.borrow_mut() {
+                    Some(stream) => stream.as_mut().poll_next(cx),
+                    None => Poll::Ready(None),
+                })
+                .await;
+                match next {
+                    Some(Ok(chunk)) => controller.enqueue_with_chunk(&chunk.into())?,
+                    // Rejecting errors the stream.
+                    Some(Err(e)) => {
+                        stream.borrow_mut().take();
+                        return Err(e);
+                    }
+                    None => {
+                        stream.borrow_mut().take();
+                        controller.close()?;
+                    }
+                }
+                Ok(JsValue::undefined())
+            }))
+        }
+    });
+    let cancel = Closure::<dyn FnMut(JsValue)>::new(move |_reason| {
+        stream.borrow_mut().take();
+    });
+
+    let source = UnderlyingSource::new();
+    source.set_pull(pull.into_js_value().unchecked_ref());
+    source.set_cancel(cancel.into_js_value().unchecked_ref());
+    ReadableStream::new_with_underlying_source(&source).unwrap_throw()
+}
@@ -0,0 +1,173 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::future::{poll_fn, Future};
+use core::marker::PhantomData;
+use core::panic::AssertUnwindSafe;
+use core::pin::Pin;
+use core::task::{ready, Context, Poll};
+use futures_sink::Sink;
+use js_sys::Promise;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsError;
+use web_sys::{UnderlyingSink, WritableStream, WritableStreamDefaultWriter};
+
+use crate::{future_to_promise, JsFuture};
+
+/// A `Sink` that writes its items as chunks to a JS `WritableStream`.
+///
+/// The sink is only ready for another item while the stream's queue isn't
+/// full, as indicated by its `desiredSize`, so the stream's backpressure is
+/// applied to the sender. Flushing waits until all items were written, and
+/// closing closes the stream. The stream is locked to this sink until it's
+/// dropped.
+pub struct WriterSink<T = JsValue> {
+    writer: WritableStreamDefaultWriter,
+    ready: Option<JsFuture>,
+    write: Option<JsFuture>,
+    close: Option<JsFuture>,
+    _item: PhantomData<fn(T)>,
+}
+
+impl<T> WriterSink<T> {
+    /// Locks `stream` to a new writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns the JS exception if `stream` is already locked.
+    pub fn new(stream: &WritableStream) -> Result<WriterSink<T>, JsValue> {
+        Ok(WriterSink {
+            writer: WritableStreamDefaultWriter::new(stream)?,
+            ready: None,
+            write: None,
+            close: None,
+            _item: PhantomData,
+        })
+    }
+}
+
+impl<T: Into<JsValue>> Sink<T> for WriterSink<T> {
+    type Error = JsValue;
+
+    fn poll_ready(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), JsValue>> {
+        let this = &mut *self;
+        if this.ready.is_none() {
+            if let Ok(Some(size)) = this.writer.desired_size() {
+                if size > 0.0 {
+                    return Poll::Ready(Ok(()));
+                }
+            }
+            // Resolves once the queue has room again, or rejects if the
+            // stream errored.
+            this.ready = Some(JsFuture::from(this.writer.ready()));
+        }
+        let result = ready!(Pin::new(this.ready.as_mut().unwrap()).poll(cx));
+        this.ready = None;
+        Poll::Ready(result.map(drop))
+    }
+
+    fn start_send(mut self: Pin<&mut Self>, item: T) -> Result<(), JsValue> {
+        let write = self.writer.write_with_chunk(&item.into());
+        // Writes complete in order, and if one fails all later ones fail as
+        // well, so waiting for the last write is enough to flush.
+        self.write = Some(JsFuture::from(write));
+        Ok(())
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), JsValue>> {
+        if let Some(write) = &mut self.write {
+            let result = ready!(Pin::new(write).poll(cx));
+            self.write = None;
+            result?;
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), JsValue>> {
+        ready!(self.as_mut().poll_flush(cx))?;
+        let this = &mut *self;
+        let close = this
+            .close
+            .get_or_insert_with(|| JsFuture::from(this.writer.close()));
+        Pin::new(close).poll(cx).map_ok(drop)
+    }
+}
+
+impl<T> Drop for WriterSink<T> {
+    fn drop(&mut self) {
+        self.writer.release_lock();
+    }
+}
+
+/// Converts a Rust `Sink` into a JS `WritableStream`, which can be returned
+/// from an exported function.
+///
+/// Every chunk written to the `WritableStream` is sent to `sink` and flushed,
+/// and closing the `WritableStream` closes `sink`. Chunks which aren't a `T`
+/// error the `WritableStream`, as do errors of `sink`. `sink` is dropped
+/// once it's closed or the `WritableStream` was aborted.
+pub fn writable_stream<S, T>(sink: S) -> WritableStream
+where
+    S: Sink<T, Error = JsValue> + 'static,
+    T: JsCast,
+{
+    let sink = Rc::new(RefCell::new(Some(Box::pin(sink))));
+
+    // The stream only calls `write` and `close` once the previous call's
+    // promise settled, so the sink is never used concurrently.
+    fn with_sink<S, R>(
+        sink: &RefCell<Option<Pin<Box<S>>>>,
+        f: impl FnOnce(Pin<&mut S>) -> Result<R, JsValue>,
+    ) -> Result<R, JsValue> {
+        match &mut *sink.borrow_mut() {
+            Some(sink) => f(sink.as_mut()),
+            None => Err(JsError::new("the sink was already closed").into()),
+        }
+    }
+
+    fn poll_sink<S>(
+        sink: &RefCell<Option<Pin<Box<S>>>>,
+        f: impl FnOnce(Pin<&mut S>) -> Poll<Result<(), JsValue>>,
+    ) -> Poll<Result<(), JsValue>> {
+        match with_sink(sink, |sink| Ok(f(sink))) {
+            Ok(poll) => poll,
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+
+    let write = Closure::<dyn FnMut(JsValue) -> Promise>::new({
+        let sink = sink.clone();
+        move |chunk: JsValue| {
+            let sink = sink.clone();
+            future_to_promise(AssertUnwindSafe(async move {
+                let chunk = chunk
+                    .dyn_into::<T>()
+                    .map_err(|_| JsError::new("unexpected type of chunk"))?;
+                poll_fn(|cx| poll_sink(&sink, |sink| sink.poll_ready(cx))).await?;
+                with_sink(&sink, |sink| sink.start_send(chunk))?;
+                poll_fn(|cx| poll_sink(&sink, |sink| sink.poll_flush(cx))).await?;
+                Ok(JsValue::undefined())
+            }))
+        }
+    });
+    let close = Closure::<dyn FnMut() -> Promise>::new({
+        let sink = sink.clone();
+        move || {
+            let sink = sink.clone();
+            future_to_promise(AssertUnwindSafe(async move {
+                poll_fn(|cx| poll_sink(&sink, |sink| sink.poll_close(cx))).await?;
+                sink.borrow_mut().take();
+                Ok(JsValue::undefined())
+            }))
+        }
+    });
+    let abort = Closure::<dyn FnMut(JsValue)>::new(move |_reason| {
+        sink.borrow_mut().take();
+    });
+
+    let underlying_sink = UnderlyingSink::new();
+    underlying_sink.set_write(write.into_js_value().unchecked_ref());
+    underlying_sink.set_close(close.into_js_value().unchecked_ref());
+    underlying_sink.set_abort(abort.into_js_value().unchecked_ref());
+    WritableStream::new_with_underlying_sink(&underlying_sink).unwrap_throw()
+}
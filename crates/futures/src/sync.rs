@@ -0,0 +1,345 @@
+//! Blocking primitives for threads which can block, with async fallbacks for
+//! the ones which can't.
+//!
+//! Workers can block with `Atomics.wait`, but the main thread of a browser
+//! can't. [`block_on`], [`Mutex::lock`] and [`Condvar::wait`] block where this
+//! is allowed and return a [`BlockError`] otherwise, in which case
+//! [`Mutex::lock_async`] and [`Condvar::wait_async`] wait without blocking
+//! through `Atomics.waitAsync` instead.
+
+use alloc::sync::Arc;
+use core::cell::{Cell, UnsafeCell};
+use core::fmt;
+use core::future::Future;
+use core::ops::{Deref, DerefMut};
+use core::pin::pin;
+use core::sync::atomic::AtomicI32;
+use core::sync::atomic::Ordering::SeqCst;
+use core::task::{Context, Poll, Waker};
+use std::task::Wake;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsError;
+
+use crate::JsFuture;
+
+/// The error returned when trying to block on a thread which can't block,
+/// like the main thread of a browser.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockError;
+
+impl fmt::Display for BlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("blocking is not allowed on this thread, wait asynchronously instead")
+    }
+}
+
+impl std::error::Error for BlockError {}
+
+impl From<BlockError> for JsValue {
+    fn from(error: BlockError) -> JsValue {
+        JsError::new(&error.to_string()).into()
+    }
+}
+
+/// Returns whether the current thread is allowed to block with
+/// `Atomics.wait`, which is the case on workers and in Node.js, but not on the
+/// main thread of a browser.
+pub fn can_block() -> bool {
+    #[thread_local]
+    static CAN_BLOCK: Cell<Option<bool>> = Cell::new(None);
+
+    if let Some(can_block) = CAN_BLOCK.get() {
+        return can_block;
+    }
+    // `Atomics.wait` throws before comparing the value if the thread can't
+    // block, and otherwise returns immediately as the value differs.
+    static PROBE: AtomicI32 = AtomicI32::new(0);
+    let memory = wasm_bindgen::memory().unchecked_into::<js_sys::WebAssembly::Memory>();
+    let array = js_sys::Int32Array::new(&memory.buffer());
+    let can_block = js_sys::Atomics::wait(&array, PROBE.as_ptr() as u32 / 4, 1).is_ok();
+    CAN_BLOCK.set(Some(can_block));
+    can_block
+}
+
+fn wait(atomic: &AtomicI32, value: i32) {
+    unsafe {
+        core::arch::wasm32::memory_atomic_wait32(atomic.as_ptr(), value, -1);
+    }
+}
+
+async fn wait_async(atomic: &AtomicI32, value: i32) {
+    // `None` means the value already changed.
+    if let Some(promise) = crate::task::wait_async(atomic, value) {
+        let _ = JsFuture::from(promise).await;
+    }
+}
+
+fn notify(atomic: &AtomicI32, count: u32) {
+    unsafe {
+        core::arch::wasm32::memory_atomic_notify(atomic.as_ptr(), count);
+    }
+}
+
+/// Runs `future` to completion on the current thread, blocking it whenever
+/// the future is pending.
+///
+/// This is for waiting on work done by other threads, like a
+/// [`JoinHandle`](crate::pool::JoinHandle) of a
+/// [`ThreadPool`](crate::pool::ThreadPool). As the thread doesn't yield to its
+/// event loop while blocked, `future` must not wait for anything that only
+/// the current thread's event loop drives, like a [`JsFuture`], or it never
+/// completes.
+///
+/// # Errors
+///
+/// Returns a [`BlockError`] without polling `future` if the current thread
+/// can't block, see [`can_block`].
+pub fn block_on<F: Future>(future: F) -> Result<F::Output, BlockError> {
+    if !can_block() {
+        return Err(BlockError);
+    }
+
+    struct Parker {
+        notified: AtomicI32,
+    }
+
+    impl Wake for Parker {
+        fn wake(self: Arc<Self>) {
+            self.wake_by_ref();
+        }
+
+        fn wake_by_ref(self: &Arc<Self>) {
+            if self.notified.swap(1, SeqCst) == 0 {
+                notify(&self.notified, 1);
+            }
+        }
+    }
+
+    let parker = Arc::new(Parker {
+        notified: AtomicI32::new(0),
+    });
+    let waker = Waker::from(parker.clone());
+    let mut cx = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        parker.notified.store(0, SeqCst);
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return Ok(output);
+        }
+        while parker.notified.load(SeqCst) == 0 {
+            wait(&parker.notified, 0);
+        }
+    }
+}
+
+const UNLOCKED: i32 = 0;
+const LOCKED: i32 = 1;
+/// Locked with threads waiting for the lock.
+const CONTENDED: i32 = 2;
+
+/// A mutual exclusion lock which can be locked by blocking or asynchronously.
+///
+/// [`lock`](Mutex::lock) blocks until the lock is acquired, which isn't
+/// allowed on all threads, while [`lock_async`](Mutex::lock_async) can be
+/// awaited on any thread. Unlike [`std::sync::Mutex`], this isn't poisoned
+/// when a thread panics while holding the lock.
+pub struct Mutex<T: ?Sized> {
+    state: AtomicI32,
+    value: UnsafeCell<T>,
+}
+
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+
+impl<T> Mutex<T> {
+    /// Creates an unlocked mutex holding `value`.
+    pub const fn new(value: T) -> Mutex<T> {
+        Mutex {
+            state: AtomicI32::new(UNLOCKED),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// Consumes the mutex, returning the value it holds.
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    /// Acquires the lock, blocking the current thread until it's available.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BlockError`] if the lock is held by another thread and the
+    /// current thread can't block, see [`can_block`].
+    pub fn lock(&self) -> Result<MutexGuard<'_, T>, BlockError> {
+        if let Some(guard) = self.try_lock() {
+            return Ok(guard);
+        }
+        if !can_block() {
+            return Err(BlockError);
+        }
+        while self.state.swap(CONTENDED, SeqCst) != UNLOCKED {
+            wait(&self.state, CONTENDED);
+        }
+        Ok(MutexGuard { mutex: self })
+    }
+
+    /// Acquires the lock, waiting asynchronously until it's available.
+    pub async fn lock_async(&self) -> MutexGuard<'_, T> {
+        if let Some(guard) = self.try_lock() {
+            return guard;
+        }
+        while self.state.swap(CONTENDED, SeqCst) != UNLOCKED {
+            wait_async(&self.state, CONTENDED).await;
+        }
+        MutexGuard { mutex: self }
+    }
+
+    /// Acquires the lock if it's available, without waiting.
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        self.state
+            .compare_exchange(UNLOCKED, LOCKED, SeqCst, SeqCst)
+            .ok()
+            .map(|_| MutexGuard { mutex: self })
+    }
+
+    /// Returns a mutable reference to the value, which needs no locking as the
+    /// mutex is borrowed mutably.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    fn unlock(&self) {
+        if self.state.swap(UNLOCKED, SeqCst) == CONTENDED {
+            notify(&self.state, 1);
+        }
+    }
+}
+
+impl<T: Default> Default for Mutex<T> {
+    fn default() -> Mutex<T> {
+        Mutex::new(T::default())
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for Mutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("Mutex");
+        match self.try_lock() {
+            Some(guard) => d.field("data", &&*guard),
+            None => d.field("data", &format_args!("<locked>")),
+        };
+        d.finish_non_exhaustive()
+    }
+}
+
+/// A guard holding the lock of a [`Mutex`], which is released when the guard
+/// is dropped.
+///
+/// The lock isn't tied to the thread that acquired it, so unlike
+/// [`std::sync::MutexGuard`] the guard can be held across an `.await` in a
+/// future that moves between threads. It can only be shared with other threads
+/// if the value it guards is `Sync`.
+pub struct MutexGuard<'a, T: ?Sized> {
+    mutex: &'a Mutex<T>,
+}
+
+unsafe impl<T: ?Sized + Sync> Sync for MutexGuard<'_, T> {}
+
+impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for MutexGuard<'_, T> {
+    fn drop(&mut self) {
+        self.mutex.unlock();
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for MutexGuard<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// A condition variable, for waiting until a [`Mutex`] holds a value matching
+/// some condition.
+///
+/// Like [`std::sync::Condvar`], waiting can wake up spuriously, so the
+/// condition should be checked in a loop.
+#[derive(Default)]
+pub struct Condvar {
+    // Bumped on every notification, for waiters to wait on.
+    seq: AtomicI32,
+}
+
+impl Condvar {
+    /// Creates a condition variable.
+    pub const fn new() -> Condvar {
+        Condvar {
+            seq: AtomicI32::new(0),
+        }
+    }
+
+    /// Releases the lock of `guard` and blocks the current thread until it's
+    /// notified, acquiring the lock again before returning.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BlockError`] if the current thread can't block, see
+    /// [`can_block`], in which case the lock is released.
+    pub fn wait<'a, T: ?Sized>(
+        &self,
+        guard: MutexGuard<'a, T>,
+    ) -> Result<MutexGuard<'a, T>, BlockError> {
+        if !can_block() {
+            return Err(BlockError);
+        }
+        let mutex = guard.mutex;
+        let seq = self.seq.load(SeqCst);
+        drop(guard);
+        // Returns immediately if notified since the lock was released.
+        wait(&self.seq, seq);
+        mutex.lock()
+    }
+
+    /// Releases the lock of `guard` and waits asynchronously until notified,
+    /// acquiring the lock again before resolving.
+    pub async fn wait_async<'a, T: ?Sized>(&self, guard: MutexGuard<'a, T>) -> MutexGuard<'a, T> {
+        let mutex = guard.mutex;
+        let seq = self.seq.load(SeqCst);
+        drop(guard);
+        wait_async(&self.seq, seq).await;
+        mutex.lock_async().await
+    }
+
+    /// Wakes up one thread or task waiting on this condition variable.
+    pub fn notify_one(&self) {
+        self.seq.fetch_add(1, SeqCst);
+        notify(&self.seq, 1);
+    }
+
+    /// Wakes up all threads and tasks waiting on this condition variable.
+    pub fn notify_all(&self) {
+        self.seq.fetch_add(1, SeqCst);
+        notify(&self.seq, u32::MAX);
+    }
+}
+
+impl fmt::Debug for Condvar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Condvar").finish_non_exhaustive()
+    }
+}
@@ -12,6 +12,8 @@ use core::sync::atomic::Ordering::SeqCst;
 use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
 use wasm_bindgen::prelude::*;
 
+use crate::executor::Priority;
+
 const SLEEPING: i32 = 0;
 const AWAKE: i32 = 1;
 
@@ -85,25 +87,29 @@ pub(crate) struct Task {
     waker: Waker,
     // See `singlethread.rs` for why this is an internal `Option`.
     inner: RefCell<Option<Inner>>,
+    pub(crate) priority: Priority,
 }
 
 impl Task {
-    pub(crate) fn spawn(future: impl Future<Output = ()> + 'static) {
+    pub(crate) fn spawn(future: impl Future<Output = ()> + 'static, priority: Priority) {
         let atomic = AtomicWaker::new();
         let waker = unsafe { Waker::from_raw(AtomicWaker::into_raw_waker(atomic.clone())) };
         let this = Rc::new(Task {
             atomic,
             waker,
             inner: RefCell::new(None),
+            priority,
         });
 
         let closure = {
             let this = Rc::clone(&this);
             Closure::new(move |_| {
                 // The promise resolution acts like a wake, so ensure the state
-                // transitions to AWAKE before entering `run`.
+                // transitions to AWAKE before `run`, which then happens
+                // whenever the queue's scheduler runs tasks.
                 this.atomic.wake_by_ref();
-                this.run();
+                let this = Rc::clone(&this);
+                crate::queue::Queue::with(move |queue| queue.schedule_task(this));
             })
         };
         *this.inner.borrow_mut() = Some(Inner {
@@ -173,7 +179,7 @@ impl Task {
     }
 }
 
-fn wait_async(ptr: &AtomicI32, current_value: i32) -> Option<js_sys::Promise> {
+pub(crate) fn wait_async(ptr: &AtomicI32, current_value: i32) -> Option<js_sys::Promise> {
     // If `Atomics.waitAsync` isn't defined then we use our fallback, otherwise
     // we use the native function.
     return if Atomics::get_wait_async().is_undefined() {
@@ -6,6 +6,8 @@ use core::mem::ManuallyDrop;
 use core::pin::Pin;
 use core::task::{Context, RawWaker, RawWakerVTable, Waker};
 
+use crate::executor::Priority;
+
 struct Inner {
     future: Pin<Box<dyn Future<Output = ()> + 'static>>,
     waker: Waker,
@@ -44,15 +46,18 @@ pub(crate) struct Task {
 
     // This is used to ensure that the Task will only be queued once
     is_queued: Cell<bool>,
+
+    pub(crate) priority: Priority,
 }
 
 impl Task {
-    pub(crate) fn spawn<F: Future<Output = ()> + 'static>(future: F) {
+    pub(crate) fn spawn<F: Future<Output = ()> + 'static>(future: F, priority: Priority) {
         let this = Rc::new(Self {
             #[cfg(debug_assertions)]
             console: create_task(core::any::type_name::<F>()).ok(),
             inner: RefCell::new(None),
             is_queued: Cell::new(true),
+            priority,
         });
 
         let waker = unsafe { Waker::from_raw(Task::into_raw_waker(Rc::clone(&this))) };
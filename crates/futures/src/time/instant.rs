@@ -2,7 +2,7 @@
 //!
 //! See <https://github.com/rust-lang/rust/blob/1.83.0/library/std/src/time.rs#L271-L468>.
 
-use core::ops::Sub;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::time::Duration;
 
 use super::js::PERFORMANCE;
@@ -60,6 +60,51 @@ impl Instant {
     pub fn elapsed(&self) -> Duration {
         Self::now() - *self
     }
+
+    /// See [`std::time::Instant::checked_add()`].
+    pub fn checked_add(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_add(duration).map(Self)
+    }
+
+    /// See [`std::time::Instant::checked_sub()`].
+    pub fn checked_sub(&self, duration: Duration) -> Option<Self> {
+        self.0.checked_sub(duration).map(Self)
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// This function may panic if the resulting point in time cannot be
+    /// represented by the underlying data structure. See
+    /// [`Instant::checked_add()`] for a version without panic.
+    fn add(self, rhs: Duration) -> Self {
+        self.checked_add(rhs)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl AddAssign<Duration> for Instant {
+    fn add_assign(&mut self, rhs: Duration) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Duration> for Instant {
+    type Output = Self;
+
+    fn sub(self, rhs: Duration) -> Self {
+        self.checked_sub(rhs)
+            .expect("overflow when subtracting duration from instant")
+    }
+}
+
+impl SubAssign<Duration> for Instant {
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self = *self - rhs;
+    }
 }
 
 impl Sub<Self> for Instant {
@@ -0,0 +1,33 @@
+//! Bindings to the JS API.
+
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// Type for the [`Performance` object](https://developer.mozilla.org/en-US/docs/Web/API/Performance).
+    pub(super) type Performance;
+
+    /// Holds the [`Performance`](https://developer.mozilla.org/en-US/docs/Web/API/Performance) object.
+    #[wasm_bindgen(thread_local_v2, js_namespace = globalThis, js_name = performance)]
+    pub(super) static PERFORMANCE: Option<Performance>;
+
+    /// Binding to [`Performance.now()`](https://developer.mozilla.org/en-US/docs/Web/API/Performance/now).
+    #[wasm_bindgen(method)]
+    pub(super) fn now(this: &Performance) -> f64;
+
+    /// Holds the [`Performance.timeOrigin`](https://developer.mozilla.org/en-US/docs/Web/API/Performance/timeOrigin).
+    #[cfg(target_feature = "atomics")]
+    #[wasm_bindgen(thread_local_v2, js_namespace = ["globalThis", "performance"], js_name = timeOrigin)]
+    pub(super) static TIME_ORIGIN: f64;
+
+    /// Binding to [`setTimeout()`](https://developer.mozilla.org/en-US/docs/Web/API/Window/setTimeout).
+    ///
+    /// Returns a number in browsers and Deno, but a `Timeout` object in
+    /// Node.js.
+    #[wasm_bindgen(js_name = setTimeout)]
+    pub(super) fn set_timeout(handler: &Closure<dyn FnMut()>, timeout: i32) -> JsValue;
+
+    /// Binding to [`clearTimeout()`](https://developer.mozilla.org/en-US/docs/Web/API/Window/clearTimeout).
+    #[wasm_bindgen(js_name = clearTimeout)]
+    pub(super) fn clear_timeout(id: &JsValue);
+}
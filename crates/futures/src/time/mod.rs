@@ -0,0 +1,345 @@
+//! Sleeping, timeouts and intervals on top of the global `setTimeout` and
+//! `clearTimeout` functions.
+//!
+//! These work in every scope providing them, like windows, workers, Node.js
+//! and Deno. Deadlines are measured with [`Instant`], which is also the
+//! `Instant` re-exported by `wasm-bindgen-test`, and pending timers are
+//! cleared as soon as the future waiting on them is dropped.
+//!
+//! Like with `setTimeout`, a timer never completes before its deadline, but
+//! may complete arbitrarily later, for example when the event loop is busy or
+//! the timers of background tabs are throttled. Timers are bound to the event
+//! loop of the thread which first polled them.
+
+use alloc::rc::Rc;
+use core::cell::{Cell, RefCell};
+use core::fmt;
+use core::future::{poll_fn, Future};
+use core::pin::Pin;
+use core::task::{ready, Context, Poll, Waker};
+use core::time::Duration;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsError;
+
+mod instant;
+mod js;
+
+pub use instant::Instant;
+
+/// The deadline used for durations too long to be represented, which is
+/// about 30 years from now.
+fn far_future() -> Instant {
+    Instant::now() + Duration::from_secs(86400 * 365 * 30)
+}
+
+/// A timer registered with `setTimeout`, which is cleared when dropped.
+struct Timer {
+    id: JsValue,
+    state: Rc<TimerState>,
+    _closure: Closure<dyn FnMut()>,
+}
+
+struct TimerState {
+    fired: Cell<bool>,
+    waker: RefCell<Option<Waker>>,
+}
+
+impl Timer {
+    fn new(delay: Duration, waker: &Waker) -> Timer {
+        let state = Rc::new(TimerState {
+            fired: Cell::new(false),
+            waker: RefCell::new(Some(waker.clone())),
+        });
+        let closure = Closure::new({
+            let state = state.clone();
+            move || {
+                state.fired.set(true);
+                let waker = state.waker.borrow_mut().take();
+                if let Some(waker) = waker {
+                    waker.wake();
+                }
+            }
+        });
+
+        // `setTimeout` takes whole milliseconds, so round up to not fire
+        // early, and fires immediately for delays that don't fit in an `i32`,
+        // so clamp them and start another timer once this one fired.
+        let mut millis = delay.as_millis();
+        if delay.subsec_nanos() % 1_000_000 != 0 {
+            millis += 1;
+        }
+        let millis = i32::try_from(millis).unwrap_or(i32::MAX);
+
+        Timer {
+            id: js::set_timeout(&closure, millis),
+            state,
+            _closure: closure,
+        }
+    }
+
+    fn register(&self, waker: &Waker) {
+        let mut current = self.state.waker.borrow_mut();
+        match &*current {
+            Some(current) if current.will_wake(waker) => {}
+            _ => *current = Some(waker.clone()),
+        }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        if !self.state.fired.get() {
+            js::clear_timeout(&self.id);
+        }
+    }
+}
+
+/// Waits until `duration` has elapsed.
+///
+/// The timer is only started once the returned future is first polled, but
+/// the duration is measured from when this function is called.
+pub fn sleep(duration: Duration) -> Sleep {
+    sleep_until(
+        Instant::now()
+            .checked_add(duration)
+            .unwrap_or_else(far_future),
+    )
+}
+
+/// Waits until `deadline` is reached, completing immediately if it already
+/// passed.
+pub fn sleep_until(deadline: Instant) -> Sleep {
+    Sleep {
+        deadline,
+        timer: None,
+    }
+}
+
+/// A future completing once its deadline is reached, created by [`sleep`] or
+/// [`sleep_until`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Sleep {
+    deadline: Instant,
+    timer: Option<Timer>,
+}
+
+impl Sleep {
+    /// Returns the instant this future completes at.
+    pub fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    /// Returns whether the deadline was reached.
+    pub fn is_elapsed(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+
+    /// Changes the deadline of this future, which can be reused this way even
+    /// after it completed.
+    pub fn reset(&mut self, deadline: Instant) {
+        self.deadline = deadline;
+        self.timer = None;
+    }
+
+    fn poll_deadline(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        let remaining = match self.deadline.checked_duration_since(Instant::now()) {
+            Some(remaining) if !remaining.is_zero() => remaining,
+            _ => {
+                self.timer = None;
+                return Poll::Ready(());
+            }
+        };
+        match &self.timer {
+            Some(timer) if !timer.state.fired.get() => timer.register(cx.waker()),
+            // Start a timer for the first time, or again if the last one
+            // fired before the deadline due to rounding or clamping.
+            _ => self.timer = Some(Timer::new(remaining, cx.waker())),
+        }
+        Poll::Pending
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        self.poll_deadline(cx)
+    }
+}
+
+impl fmt::Debug for Sleep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sleep")
+            .field("deadline", &self.deadline)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Waits for `future` to complete, giving up once `duration` has elapsed.
+///
+/// Resolves to an [`Elapsed`] error if `future` didn't complete in time, in
+/// which case it's dropped along with the returned future.
+pub fn timeout<F: Future>(duration: Duration, future: F) -> Timeout<F> {
+    Timeout {
+        future,
+        sleep: sleep(duration),
+    }
+}
+
+/// Waits for `future` to complete, giving up once `deadline` is reached.
+///
+/// See [`timeout`].
+pub fn timeout_at<F: Future>(deadline: Instant, future: F) -> Timeout<F> {
+    Timeout {
+        future,
+        sleep: sleep_until(deadline),
+    }
+}
+
+/// A future waiting for another future with a deadline, created by
+/// [`timeout`] or [`timeout_at`].
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct Timeout<F> {
+    future: F,
+    sleep: Sleep,
+}
+
+impl<F> Timeout<F> {
+    /// Returns the instant this future gives up at.
+    pub fn deadline(&self) -> Instant {
+        self.sleep.deadline()
+    }
+
+    /// Consumes this future, returning the future it was waiting for.
+    pub fn into_inner(self) -> F {
+        self.future
+    }
+}
+
+impl<F: Future> Future for Timeout<F> {
+    type Output = Result<F::Output, Elapsed>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // SAFETY: `future` is structurally pinned, it's never moved out of a
+        // pinned `Timeout` and `Timeout` doesn't implement `Drop`.
+        let this = unsafe { self.get_unchecked_mut() };
+        let future = unsafe { Pin::new_unchecked(&mut this.future) };
+        // Poll the future first, so that it still completes if it's ready at
+        // the deadline.
+        if let Poll::Ready(output) = future.poll(cx) {
+            return Poll::Ready(Ok(output));
+        }
+        this.sleep.poll_deadline(cx).map(|()| Err(Elapsed(())))
+    }
+}
+
+impl<F> fmt::Debug for Timeout<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Timeout")
+            .field("deadline", &self.deadline())
+            .finish_non_exhaustive()
+    }
+}
+
+/// The error returned by [`Timeout`] when the deadline was reached before the
+/// future completed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Elapsed(());
+
+impl fmt::Display for Elapsed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("deadline has elapsed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Elapsed {}
+
+impl From<Elapsed> for JsValue {
+    fn from(error: Elapsed) -> JsValue {
+        JsError::new(&alloc::string::ToString::to_string(&error)).into()
+    }
+}
+
+/// Creates an [`Interval`] ticking every `period`, for the first time once
+/// `period` has elapsed.
+///
+/// # Panics
+///
+/// Panics if `period` is zero.
+pub fn interval(period: Duration) -> Interval {
+    assert!(!period.is_zero(), "`period` must be non-zero");
+    Interval {
+        period,
+        sleep: sleep(period),
+    }
+}
+
+/// Ticks at a fixed period, created by [`interval`].
+///
+/// Unlike `setInterval`, ticks are only produced while the interval is polled.
+/// Ticks which were missed, because the interval wasn't polled in time, are
+/// skipped: the next tick happens immediately, and the following ones at
+/// their original schedule again. With the `futures-core-03-stream` feature,
+/// this is also a `Stream` of ticks.
+pub struct Interval {
+    period: Duration,
+    sleep: Sleep,
+}
+
+impl Interval {
+    /// Returns the period between ticks.
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Waits for the next tick, resolving to the instant it was scheduled at.
+    pub async fn tick(&mut self) -> Instant {
+        poll_fn(|cx| self.poll_tick(cx)).await
+    }
+
+    /// Polls for the next tick, returning the instant it was scheduled at.
+    pub fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<Instant> {
+        ready!(self.sleep.poll_deadline(cx));
+        let tick = self.sleep.deadline();
+        let now = Instant::now();
+        let next = match tick.checked_add(self.period) {
+            Some(next) if next > now => next,
+            Some(_) => {
+                // Skip the missed ticks, keeping the next one on schedule.
+                let behind = (now - tick).as_nanos() % self.period.as_nanos();
+                now + (self.period - Duration::from_nanos(behind as u64))
+            }
+            None => far_future(),
+        };
+        self.sleep.reset(next);
+        Poll::Ready(tick)
+    }
+
+    /// Schedules the next tick one period from now.
+    pub fn reset(&mut self) {
+        let next = Instant::now()
+            .checked_add(self.period)
+            .unwrap_or_else(far_future);
+        self.sleep.reset(next);
+    }
+}
+
+impl fmt::Debug for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interval")
+            .field("period", &self.period)
+            .field("next", &self.sleep.deadline())
+            .finish()
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "futures-core-03-stream")))]
+#[cfg(feature = "futures-core-03-stream")]
+impl futures_core::Stream for Interval {
+    type Item = Instant;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Instant>> {
+        self.poll_tick(cx).map(Some)
+    }
+}
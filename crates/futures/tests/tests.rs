@@ -150,6 +150,110 @@ async fn can_create_multiple_futures_from_same_promise() {
     b.await.unwrap();
 }
 
+#[wasm_bindgen_test]
+async fn custom_scheduler_polls_by_priority() {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+    use wasm_bindgen_futures::executor::{self, Priority};
+
+    let runs = Rc::new(Cell::new(0));
+    let run = Closure::new({
+        let runs = runs.clone();
+        move || {
+            runs.set(runs.get() + 1);
+            executor::run_until_stalled();
+        }
+    });
+    executor::set_scheduler(move || setTimeout(&run));
+
+    let order = Rc::new(RefCell::new(Vec::new()));
+    let (done_tx, done_rx) = oneshot::channel();
+    let mut done_tx = Some(done_tx);
+    for (name, priority) in [
+        ("low", Priority::Low),
+        ("normal", Priority::Normal),
+        ("high", Priority::High),
+    ] {
+        let order = order.clone();
+        let done_tx = if priority == Priority::Low {
+            done_tx.take()
+        } else {
+            None
+        };
+        executor::spawn_local_with_priority(
+            async move {
+                order.borrow_mut().push(name);
+                if let Some(done_tx) = done_tx {
+                    done_tx.send(()).unwrap();
+                }
+            },
+            priority,
+        );
+    }
+    done_rx.await.unwrap();
+    executor::reset_scheduler();
+
+    assert_eq!(*order.borrow(), ["high", "normal", "low"]);
+    assert_eq!(runs.get(), 1);
+}
+
+#[wasm_bindgen_test]
+async fn sleep_and_timeout_wait_for_their_deadline() {
+    use std::time::Duration;
+    use wasm_bindgen_futures::time::{self, Instant};
+
+    let start = Instant::now();
+    time::sleep(Duration::from_millis(20)).await;
+    assert!(start.elapsed() >= Duration::from_millis(20));
+
+    let result = time::timeout(Duration::from_millis(10), std::future::pending::<()>()).await;
+    assert!(result.is_err());
+    let result = time::timeout(Duration::from_secs(10), async { 42 }).await;
+    assert_eq!(result, Ok(42));
+}
+
+#[wasm_bindgen_test]
+async fn dropped_sleep_never_wakes() {
+    use std::sync::atomic::{AtomicUsize, Ordering::SeqCst};
+    use std::sync::Arc;
+    use std::task::{Context, Wake, Waker};
+    use std::time::Duration;
+    use wasm_bindgen_futures::time;
+
+    struct CountWakes(AtomicUsize);
+
+    impl Wake for CountWakes {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, SeqCst);
+        }
+    }
+
+    let wakes = Arc::new(CountWakes(AtomicUsize::new(0)));
+    let waker = Waker::from(wakes.clone());
+    let mut sleep = time::sleep(Duration::from_millis(10));
+    let mut cx = Context::from_waker(&waker);
+    assert!(std::future::Future::poll(std::pin::Pin::new(&mut sleep), &mut cx).is_pending());
+    drop(sleep);
+
+    time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(wakes.0.load(SeqCst), 0);
+}
+
+#[wasm_bindgen_test]
+async fn interval_ticks_every_period() {
+    use std::time::Duration;
+    use wasm_bindgen_futures::time::{self, Instant};
+
+    let period = Duration::from_millis(10);
+    let start = Instant::now();
+    let mut interval = time::interval(period);
+    let first = interval.tick().await;
+    let second = interval.tick().await;
+    assert!(first >= start + period);
+    assert!(second >= first + period);
+    assert!(Instant::now() >= second);
+}
+
 #[cfg(target_feature = "atomics")]
 struct WaitAsyncMockGuard {
     original: JsValue,
@@ -202,6 +306,161 @@ async fn wait_async_promise_callback_runs_without_wake() {
     done_rx.await.expect("task finished");
 }
 
+#[cfg(target_feature = "atomics")]
+#[wasm_bindgen_test]
+async fn spawned_thread_is_joined() {
+    let numbers: Vec<u32> = (1..=100).collect();
+    let handle = wasm_bindgen::thread::spawn(move || numbers.iter().sum::<u32>());
+    assert_eq!(handle.join_async().await.unwrap(), 5050);
+}
+
+#[cfg(target_feature = "atomics")]
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(thread_local_v2, js_name = globalThis)]
+    static GLOBAL_THIS: JsValue;
+}
+
+#[cfg(target_feature = "atomics")]
+#[wasm_bindgen_test]
+async fn thread_dtors_run_when_thread_exits() {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    static DROPPED: AtomicU32 = AtomicU32::new(0);
+
+    unsafe fn record_drop(data: *mut u8) {
+        DROPPED.fetch_add(data as u32, Ordering::SeqCst);
+    }
+
+    let handle = wasm_bindgen::thread::spawn(|| unsafe {
+        // Registers the destructor of the thread-local static first.
+        assert!(GLOBAL_THIS.with(JsValue::is_object));
+        wasm_bindgen::__rt::register_thread_dtor(1 as *mut u8, record_drop);
+        wasm_bindgen::__rt::register_thread_dtor(2 as *mut u8, record_drop);
+    });
+    handle.join_async().await.unwrap();
+    assert_eq!(DROPPED.load(Ordering::SeqCst), 3);
+}
+
+#[cfg(target_feature = "atomics")]
+#[wasm_bindgen_test]
+async fn thread_pool_runs_futures() {
+    use wasm_bindgen_futures::pool::ThreadPool;
+
+    // Wakes itself once, so that tasks are scheduled again by the pool.
+    struct YieldNow(bool);
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            if self.0 {
+                return Poll::Ready(());
+            }
+            self.0 = true;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+
+    let pool = ThreadPool::new(2);
+    let handles: Vec<_> = (0..16u32)
+        .map(|i| {
+            pool.spawn(async move {
+                YieldNow(false).await;
+                i * i
+            })
+        })
+        .collect();
+    let mut sum = 0;
+    for handle in handles {
+        sum += handle.await.unwrap();
+    }
+    assert_eq!(sum, (0..16).map(|i| i * i).sum());
+    pool.shutdown().await.unwrap();
+}
+
+#[cfg(all(target_feature = "atomics", not(panic = "unwind")))]
+#[wasm_bindgen_test]
+async fn thread_pool_fails_when_a_thread_fails() {
+    use wasm_bindgen_futures::pool::ThreadPool;
+
+    let pool = ThreadPool::new(2);
+    // Never completes, so only the failure of the pool resolves its handle.
+    let pending = pool.spawn(std::future::pending::<()>());
+    // Traps its thread, as panics abort.
+    let panicking = pool.spawn(async { panic!("task failed") });
+    assert!(panicking.await.is_err());
+    assert!(pending.await.is_err());
+    assert!(pool.spawn(async {}).await.is_err());
+    assert!(pool.shutdown().await.is_err());
+}
+
+#[cfg(target_feature = "atomics")]
+#[wasm_bindgen_test]
+fn block_on_blocks_only_where_allowed() {
+    use wasm_bindgen_futures::sync::can_block;
+    use wasm_bindgen_futures::{block_on, BlockError};
+
+    let expected = if can_block() { Ok(1) } else { Err(BlockError) };
+    assert_eq!(block_on(async { 1 }), expected);
+}
+
+#[cfg(target_feature = "atomics")]
+#[wasm_bindgen_test]
+async fn condvar_wait_async_is_notified_by_other_thread() {
+    use wasm_bindgen_futures::pool::ThreadPool;
+    use wasm_bindgen_futures::sync::{Condvar, Mutex};
+
+    static READY: Mutex<bool> = Mutex::new(false);
+    static CONDVAR: Condvar = Condvar::new();
+
+    let pool = ThreadPool::new(1);
+    let handle = pool.spawn(async {
+        *READY.lock().unwrap() = true;
+        CONDVAR.notify_all();
+    });
+
+    let mut ready = READY.lock_async().await;
+    while !*ready {
+        ready = CONDVAR.wait_async(ready).await;
+    }
+    drop(ready);
+
+    handle.await.unwrap();
+    pool.shutdown().await.unwrap();
+}
+
+#[cfg(target_feature = "atomics")]
+#[wasm_bindgen_test]
+async fn mutex_guard_can_be_held_across_await_on_pool() {
+    use std::cell::Cell;
+    use wasm_bindgen_futures::pool::ThreadPool;
+    use wasm_bindgen_futures::sync::{Mutex, MutexGuard};
+
+    // Only resolves if `MutexGuard<Cell<u32>>` isn't `Sync`, otherwise both
+    // impls apply and the call below is ambiguous.
+    trait AmbiguousIfSync<A> {
+        fn check() {}
+    }
+    impl<T: ?Sized> AmbiguousIfSync<()> for T {}
+    impl<T: ?Sized + Sync> AmbiguousIfSync<u8> for T {}
+    <MutexGuard<'static, Cell<u32>> as AmbiguousIfSync<_>>::check();
+
+    static COUNT: Mutex<u32> = Mutex::new(0);
+
+    let pool = ThreadPool::new(1);
+    let handle = pool.spawn(async {
+        let mut count = COUNT.lock_async().await;
+        futures_lite::future::yield_now().await;
+        *count += 1;
+    });
+    handle.await.unwrap();
+    pool.shutdown().await.unwrap();
+
+    assert_eq!(*COUNT.lock_async().await, 1);
+}
+
 #[cfg(feature = "futures-core-03-stream")]
 #[wasm_bindgen_test]
 async fn can_use_an_async_iterable_as_stream() {
@@ -224,6 +483,151 @@ async fn can_use_an_async_iterable_as_stream() {
     assert_eq!(stream.next().await, None);
 }
 
+#[cfg(feature = "web-streams")]
+#[wasm_bindgen_test]
+async fn rust_stream_round_trips_through_readable_stream() {
+    use futures_lite::stream::{self, StreamExt};
+    use wasm_bindgen_futures::stream::{readable_stream, ReaderStream};
+
+    let readable = readable_stream(stream::iter([1, 2, 3].map(Ok::<u32, JsValue>)));
+    let mut reader = ReaderStream::<JsValue>::new(&readable).unwrap();
+    assert!(readable.locked());
+    assert_eq!(reader.next().await, Some(Ok(JsValue::from(1))));
+    assert_eq!(reader.next().await, Some(Ok(JsValue::from(2))));
+    assert_eq!(reader.next().await, Some(Ok(JsValue::from(3))));
+    assert_eq!(reader.next().await, None);
+    drop(reader);
+    assert!(!readable.locked());
+}
+
+#[cfg(feature = "web-streams")]
+#[wasm_bindgen_test]
+async fn rust_sink_round_trips_through_writable_stream() {
+    use futures_sink::Sink;
+    use std::cell::RefCell;
+    use std::future::poll_fn;
+    use std::pin::Pin;
+    use std::rc::Rc;
+    use std::task::{Context, Poll};
+    use wasm_bindgen_futures::stream::{writable_stream, WriterSink};
+
+    struct Collect(Rc<RefCell<Vec<JsValue>>>, bool);
+
+    impl Sink<JsValue> for Collect {
+        type Error = JsValue;
+
+        fn poll_ready(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), JsValue>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn start_send(self: Pin<&mut Self>, item: JsValue) -> Result<(), JsValue> {
+            self.0.borrow_mut().push(item);
+            Ok(())
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), JsValue>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(mut self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Result<(), JsValue>> {
+            self.1 = true;
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    let collected = Rc::new(RefCell::new(Vec::new()));
+    let writable = writable_stream(Collect(collected.clone(), false));
+    let mut sink = WriterSink::<JsValue>::new(&writable).unwrap();
+    for i in 0..3 {
+        poll_fn(|cx| Pin::new(&mut sink).poll_ready(cx))
+            .await
+            .unwrap();
+        Pin::new(&mut sink).start_send(JsValue::from(i)).unwrap();
+    }
+    poll_fn(|cx| Pin::new(&mut sink).poll_close(cx))
+        .await
+        .unwrap();
+    assert_eq!(*collected.borrow(), [0, 1, 2].map(JsValue::from));
+}
+
+#[cfg(feature = "web-streams")]
+#[wasm_bindgen_test]
+async fn byob_reader_reads_into_slices() {
+    use wasm_bindgen_futures::stream::ByobReader;
+
+    let readable = js_sys::Function::new_no_args(
+        "return new ReadableStream({
+            type: 'bytes',
+            start(controller) {
+                controller.enqueue(new Uint8Array([1, 2, 3, 4, 5]));
+                controller.close();
+            },
+        })",
+    )
+    .call0(&JsValue::undefined())
+    .unwrap()
+    .unchecked_into::<web_sys::ReadableStream>();
+
+    let mut reader = ByobReader::new(&readable).unwrap();
+    let mut buf = [0; 3];
+    assert_eq!(reader.read(&mut buf).await, Ok(3));
+    assert_eq!(buf, [1, 2, 3]);
+    assert_eq!(reader.read(&mut buf).await, Ok(2));
+    assert_eq!(buf[..2], [4, 5]);
+    assert_eq!(reader.read(&mut buf).await, Ok(0));
+}
+
+#[cfg(feature = "web-streams")]
+#[wasm_bindgen_test]
+async fn byob_reader_read_is_cancel_safe() {
+    use futures_lite::future::poll_once;
+    use wasm_bindgen_futures::stream::ByobReader;
+
+    let stream_and_controller = js_sys::Function::new_no_args(
+        "let controller;
+        const stream = new ReadableStream({
+            type: 'bytes',
+            start(c) {
+                controller = c;
+            },
+        });
+        return [stream, controller];",
+    )
+    .call0(&JsValue::undefined())
+    .unwrap()
+    .unchecked_into::<js_sys::Array>();
+    let readable = stream_and_controller
+        .get(0)
+        .unchecked_into::<web_sys::ReadableStream>();
+    let controller = stream_and_controller.get(1);
+
+    let mut reader = ByobReader::new(&readable).unwrap();
+    let mut buf = [0; 8];
+    assert_eq!(poll_once(reader.read(&mut buf)).await, None);
+
+    let enqueue = js_sys::Reflect::get(&controller, &"enqueue".into())
+        .unwrap()
+        .unchecked_into::<js_sys::Function>();
+    enqueue
+        .call1(&controller, &js_sys::Uint8Array::from(&[1, 2, 3, 4, 5][..]))
+        .unwrap();
+    let close = js_sys::Reflect::get(&controller, &"close".into())
+        .unwrap()
+        .unchecked_into::<js_sys::Function>();
+    close.call0(&controller).unwrap();
+
+    let mut buf = [0; 2];
+    assert_eq!(reader.read(&mut buf).await, Ok(2));
+    assert_eq!(buf, [1, 2]);
+    assert_eq!(reader.read(&mut buf).await, Ok(2));
+    assert_eq!(buf, [3, 4]);
+    assert_eq!(reader.read(&mut buf).await, Ok(1));
+    assert_eq!(buf[..1], [5]);
+    assert_eq!(reader.read(&mut buf).await, Ok(0));
+    drop(reader);
+    assert!(!readable.locked());
+}
+
 #[wasm_bindgen_test]
 #[should_panic]
 async fn should_panic() {
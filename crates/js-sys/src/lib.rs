@@ -13306,6 +13306,10 @@ macro_rules! arrays {
             /// Finally, the returned object is disconnected from the input
             /// slice's lifetime, so there's no guarantee that the data is read
             /// at the right time.
+            ///
+            /// [`wasm_bindgen::TypedArrayView`] is a safe alternative which
+            /// mutably borrows the slice and creates the view again after
+            /// memory grew.
             pub unsafe fn view(rust: &[$ty]) -> $name {
                 wasm_bindgen::__rt::wbg_cast(rust)
             }
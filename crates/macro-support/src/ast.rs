@@ -95,6 +95,9 @@ pub struct Export {
     /// Whether or not this function should be flagged as the Wasm start
     /// function.
     pub start: bool,
+    /// The error type set with `throws`, if any. Otherwise the error type of a
+    /// returned `Result` is used if it's an exported struct.
+    pub throws: Option<Path>,
     /// Path to wasm_bindgen
     pub wasm_bindgen: Path,
     /// Path to wasm_bindgen_futures
@@ -438,6 +441,9 @@ pub struct Struct {
     pub comments: Vec<String>,
     /// Whether this struct is inspectable (provides toJSON/toString properties to JS)
     pub is_inspectable: bool,
+    /// Whether `&self` methods can be called while others are running, which
+    /// means the struct can't be borrowed mutably
+    pub reentrant: bool,
     /// Whether to generate a typescript definition for this struct
     pub generate_typescript: bool,
     /// Whether to skip exporting this struct from the module exports
@@ -226,6 +226,76 @@ impl ToTokens for ast::Struct {
         let free_fn = Ident::new(&shared::free_function(&name_str), Span::call_site());
         let unwrap_fn = Ident::new(&shared::unwrap_function(&name_str), Span::call_site());
         let wasm_bindgen = &self.wasm_bindgen;
+        // `reentrant` structs are never borrowed mutably, so freeing them
+        // doesn't need to claim ownership, and a method still borrowing one
+        // keeps it alive until it returns.
+        let free_delayed = if self.reentrant {
+            quote! { { let _ = allow_delayed; true } }
+        } else {
+            quote! { allow_delayed != 0 }
+        };
+        // `reentrant` structs are never borrowed mutably, so their shared
+        // borrows don't need to be counted.
+        let borrow_impls = if self.reentrant {
+            quote! {
+                #[automatically_derived]
+                impl #wasm_bindgen::convert::RefFromWasmAbi for #name {
+                    type Abi = u32;
+                    type Anchor = #wasm_bindgen::__rt::RcShared<#name>;
+
+                    unsafe fn ref_from_abi(js: Self::Abi) -> Self::Anchor {
+                        use #wasm_bindgen::__rt::alloc::rc::Rc;
+
+                        let js = js as *mut #wasm_bindgen::__rt::WasmRefCell<#name>;
+                        #wasm_bindgen::__rt::assert_not_null(js);
+
+                        Rc::increment_strong_count(js);
+                        let rc = Rc::from_raw(js);
+                        #wasm_bindgen::__rt::RcShared::new(rc)
+                    }
+                }
+            }
+        } else {
+            quote! {
+                #[automatically_derived]
+                impl #wasm_bindgen::convert::RefFromWasmAbi for #name {
+                    type Abi = u32;
+                    type Anchor = #wasm_bindgen::__rt::RcRef<#name>;
+
+                    unsafe fn ref_from_abi(js: Self::Abi) -> Self::Anchor {
+                        use #wasm_bindgen::__rt::alloc::rc::Rc;
+
+                        let js = js as *mut #wasm_bindgen::__rt::WasmRefCell<#name>;
+                        #wasm_bindgen::__rt::assert_not_null(js);
+
+                        Rc::increment_strong_count(js);
+                        let rc = Rc::from_raw(js);
+                        #wasm_bindgen::__rt::RcRef::new(rc)
+                    }
+                }
+
+                #[automatically_derived]
+                impl #wasm_bindgen::convert::RefMutFromWasmAbi for #name {
+                    type Abi = u32;
+                    type Anchor = #wasm_bindgen::__rt::RcRefMut<#name>;
+
+                    unsafe fn ref_mut_from_abi(js: Self::Abi) -> Self::Anchor {
+                        use #wasm_bindgen::__rt::alloc::rc::Rc;
+
+                        let js = js as *mut #wasm_bindgen::__rt::WasmRefCell<#name>;
+                        #wasm_bindgen::__rt::assert_not_null(js);
+
+                        Rc::increment_strong_count(js);
+                        let rc = Rc::from_raw(js);
+                        #wasm_bindgen::__rt::RcRefMut::new(rc)
+                    }
+                }
+            }
+        };
+        // TypeScript type of `WeakHandle<#name>`.
+        let weak_handle_ts = format!("{{ deref(): {name_str} | undefined }}");
+        let weak_handle_ts_len = weak_handle_ts.len() as u32;
+        let weak_handle_ts_chars = weak_handle_ts.chars().map(|c| c as u32);
         (quote! {
             #[automatically_derived]
             impl #wasm_bindgen::__rt::marker::SupportsConstructor for #name {}
@@ -244,6 +314,16 @@ impl ToTokens for ast::Struct {
                 }
             }
 
+            #[automatically_derived]
+            unsafe impl #wasm_bindgen::ExportedStruct for #name {
+                fn describe_weak_handle() {
+                    use #wasm_bindgen::describe::*;
+                    inform(NAMED_EXTERNREF);
+                    inform(#weak_handle_ts_len);
+                    #(inform(#weak_handle_ts_chars);)*
+                }
+            }
+
             #[automatically_derived]
             impl #wasm_bindgen::convert::IntoWasmAbi for #name {
                 type Abi = u32;
@@ -312,7 +392,8 @@ impl ToTokens for ast::Struct {
                 pub unsafe extern "C-unwind" fn #free_fn(ptr: u32, allow_delayed: u32) {
                     use #wasm_bindgen::__rt::alloc::rc::Rc;
 
-                    if allow_delayed != 0 {
+                    let allow_delayed = #free_delayed;
+                    if allow_delayed {
                         // Just drop the implicit `Rc` owned by JS, and then if the value is still
                         // referenced it'll be kept alive by its other `Rc`s.
                         let ptr = ptr as *mut #wasm_bindgen::__rt::WasmRefCell<#name>;
@@ -326,44 +407,12 @@ impl ToTokens for ast::Struct {
                 }
             };
 
-            #[automatically_derived]
-            impl #wasm_bindgen::convert::RefFromWasmAbi for #name {
-                type Abi = u32;
-                type Anchor = #wasm_bindgen::__rt::RcRef<#name>;
-
-                unsafe fn ref_from_abi(js: Self::Abi) -> Self::Anchor {
-                    use #wasm_bindgen::__rt::alloc::rc::Rc;
-
-                    let js = js as *mut #wasm_bindgen::__rt::WasmRefCell<#name>;
-                    #wasm_bindgen::__rt::assert_not_null(js);
-
-                    Rc::increment_strong_count(js);
-                    let rc = Rc::from_raw(js);
-                    #wasm_bindgen::__rt::RcRef::new(rc)
-                }
-            }
-
-            #[automatically_derived]
-            impl #wasm_bindgen::convert::RefMutFromWasmAbi for #name {
-                type Abi = u32;
-                type Anchor = #wasm_bindgen::__rt::RcRefMut<#name>;
-
-                unsafe fn ref_mut_from_abi(js: Self::Abi) -> Self::Anchor {
-                    use #wasm_bindgen::__rt::alloc::rc::Rc;
-
-                    let js = js as *mut #wasm_bindgen::__rt::WasmRefCell<#name>;
-                    #wasm_bindgen::__rt::assert_not_null(js);
-
-                    Rc::increment_strong_count(js);
-                    let rc = Rc::from_raw(js);
-                    #wasm_bindgen::__rt::RcRefMut::new(rc)
-                }
-            }
+            #borrow_impls
 
             #[automatically_derived]
             impl #wasm_bindgen::convert::LongRefFromWasmAbi for #name {
                 type Abi = u32;
-                type Anchor = #wasm_bindgen::__rt::RcRef<#name>;
+                type Anchor = <Self as #wasm_bindgen::convert::RefFromWasmAbi>::Anchor;
 
                 unsafe fn long_ref_from_abi(js: Self::Abi) -> Self::Anchor {
                     <Self as #wasm_bindgen::convert::RefFromWasmAbi>::ref_from_abi(js)
@@ -564,6 +613,9 @@ impl TryToTokens for ast::Export {
         let generated_name = self.rust_symbol();
         let export_name = self.export_name();
         let mut args = vec![];
+        // Conversions of async methods which happen when they're called,
+        // instead of when their future is first polled.
+        let mut sync_conversions = vec![];
         let mut arg_conversions = vec![];
         let mut converted_arguments = vec![];
         let ret = Ident::new("_ret", Span::call_site());
@@ -578,6 +630,12 @@ impl TryToTokens for ast::Export {
         let name = &self.rust_name;
         let wasm_bindgen = &self.wasm_bindgen;
         let wasm_bindgen_futures = &self.wasm_bindgen_futures;
+        // How this method is named in conflicting borrow errors.
+        let site = format!(
+            "{}.{}",
+            self.js_class.as_deref().unwrap_or_default(),
+            self.function.name
+        );
         let receiver = match self.method_self {
             Some(ast::MethodSelf::ByValue) => {
                 let class = self.rust_class.as_ref().unwrap();
@@ -590,36 +648,75 @@ impl TryToTokens for ast::Export {
             }
             Some(ast::MethodSelf::RefMutable) => {
                 let class = self.rust_class.as_ref().unwrap();
-                arg_conversions.push(quote! {
-                    let mut me = unsafe {
-                        <#class as #wasm_bindgen::convert::RefMutFromWasmAbi>
-                            ::ref_mut_from_abi(me)
-                    };
-                    let me = &mut *me;
-                });
+                if self.function.r#async {
+                    // `self` is borrowed when the method is called, and stays
+                    // borrowed until the returned promise settles. Calls made
+                    // in the meantime return a rejected promise.
+                    sync_conversions.push(quote! {
+                        let me = unsafe {
+                            #wasm_bindgen::__rt::RcRefMutAsync::<#class>::new(me, #site)
+                        };
+                    });
+                    arg_conversions.push(quote! {
+                        let mut me = match me {
+                            #wasm_bindgen::__rt::core::result::Result::Ok(me) => me,
+                            #wasm_bindgen::__rt::core::result::Result::Err(e) => {
+                                return #wasm_bindgen::__rt::core::result::Result::Err(e);
+                            }
+                        };
+                        let me = &mut *me;
+                    });
+                } else {
+                    arg_conversions.push(quote! {
+                        let _site = unsafe {
+                            #wasm_bindgen::__rt::BorrowSite::<#class>::enter(me, #site, true)
+                        };
+                        let mut me = unsafe {
+                            <#class as #wasm_bindgen::convert::RefMutFromWasmAbi>
+                                ::ref_mut_from_abi(me)
+                        };
+                        let me = &mut *me;
+                    });
+                }
                 quote! { me.#name }
             }
             Some(ast::MethodSelf::RefShared) => {
                 let class = self.rust_class.as_ref().unwrap();
-                let (trait_, func, borrow) = if self.function.r#async {
-                    (
-                        quote!(LongRefFromWasmAbi),
-                        quote!(long_ref_from_abi),
-                        quote!(
-                            <<#class as #wasm_bindgen::convert::LongRefFromWasmAbi>
-                                ::Anchor as #wasm_bindgen::__rt::core::borrow::Borrow<#class>>
-                                ::borrow(&me)
-                        ),
-                    )
+                if self.function.r#async {
+                    // Like `&mut self`, `self` is borrowed when the method is
+                    // called rather than when the future is first polled, and
+                    // a conflicting borrow returns a rejected promise.
+                    sync_conversions.push(quote! {
+                        let me = unsafe {
+                            #wasm_bindgen::__rt::BorrowSite::<#class>::enter_async(me, #site)
+                        }
+                        .map(|site| (site, unsafe {
+                            <#class as #wasm_bindgen::convert::LongRefFromWasmAbi>
+                                ::long_ref_from_abi(me)
+                        }));
+                    });
+                    arg_conversions.push(quote! {
+                        let (_site, me) = match me {
+                            #wasm_bindgen::__rt::core::result::Result::Ok(me) => me,
+                            #wasm_bindgen::__rt::core::result::Result::Err(e) => {
+                                return #wasm_bindgen::__rt::core::result::Result::Err(e);
+                            }
+                        };
+                        let me = <<#class as #wasm_bindgen::convert::LongRefFromWasmAbi>
+                            ::Anchor as #wasm_bindgen::__rt::core::borrow::Borrow<#class>>
+                            ::borrow(&me);
+                    });
                 } else {
-                    (quote!(RefFromWasmAbi), quote!(ref_from_abi), quote!(&*me))
-                };
-                arg_conversions.push(quote! {
-                    let me = unsafe {
-                        <#class as #wasm_bindgen::convert::#trait_>::#func(me)
-                    };
-                    let me = #borrow;
-                });
+                    arg_conversions.push(quote! {
+                        let _site = unsafe {
+                            #wasm_bindgen::__rt::BorrowSite::<#class>::enter(me, #site, false)
+                        };
+                        let me = unsafe {
+                            <#class as #wasm_bindgen::convert::RefFromWasmAbi>::ref_from_abi(me)
+                        };
+                        let me = &*me;
+                    });
+                }
                 quote! { me.#name }
             }
             None => match &self.rust_class {
@@ -771,9 +868,12 @@ impl TryToTokens for ast::Export {
                 }
             } else {
                 call = quote! {
-                    #wasm_bindgen_futures::future_to_promise(async move {
-                        #call
-                    }).into()
+                    {
+                        #(#sync_conversions)*
+                        #wasm_bindgen_futures::future_to_promise(async move {
+                            #call
+                        }).into()
+                    }
                 }
             }
         } else {
@@ -921,11 +1021,44 @@ impl TryToTokens for ast::Export {
                 #describe_args
                 #describe_ret
             },
-            attrs,
+            attrs: attrs.clone(),
             wasm_bindgen: &self.wasm_bindgen,
         }
         .to_tokens(into);
 
+        // What the function throws (or what its promise rejects with) gets
+        // its own descriptor, so that it can be documented in the bindings.
+        // Without an explicit `throws` this describes the error type of a
+        // returned `Result` if it's an exported struct, and an untyped value
+        // otherwise.
+        let describe_throws = match (&self.throws, result_error_type(syn_ret)) {
+            _ if self.start => None,
+            (Some(throws), _) => Some(quote! {
+                <#throws as WasmDescribe>::describe();
+            }),
+            (None, Some(err)) => Some(quote! {
+                use #wasm_bindgen::__rt::DescribeThrows;
+                (&&#wasm_bindgen::__rt::ThrowsWrapper::<#err>(
+                    #wasm_bindgen::__rt::core::marker::PhantomData,
+                ))
+                    .describe_throws();
+            }),
+            (None, None) => None,
+        };
+        if let Some(describe_throws) = describe_throws {
+            let throws = Ident::new(
+                &shared::throws_descriptor_name(&export_name),
+                Span::call_site(),
+            );
+            Descriptor {
+                ident: &throws,
+                inner: describe_throws,
+                attrs,
+                wasm_bindgen: &self.wasm_bindgen,
+            }
+            .to_tokens(into);
+        }
+
         Ok(())
     }
 }
@@ -1864,6 +1997,7 @@ impl TryToTokens for ast::ImportFunction {
 
                 unsafe {
                     let #ret_ident = {
+                        let _defer = #wasm_bindgen::__rt::DeferEvictions::new();
                         #(#arg_conversions)*
                         #import_name(#(#abi_argument_names),*)
                     };
@@ -2587,6 +2721,24 @@ fn respan(input: TokenStream, span: &dyn ToTokens) -> TokenStream {
     new_tokens.into_iter().collect()
 }
 
+/// Returns `E` if `ty` is spelled as `Result<T, E>`.
+fn result_error_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(path) = get_ty(ty) else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.iter().collect::<Vec<_>>()[..] {
+        [_, syn::GenericArgument::Type(err)] => Some(err),
+        _ => None,
+    }
+}
+
 fn get_ty(mut ty: &syn::Type) -> &syn::Type {
     while let syn::Type::Group(g) = ty {
         ty = &g.elem;
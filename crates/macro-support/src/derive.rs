@@ -0,0 +1,431 @@
+//! Implementation of `#[derive(IntoJsValue, TryFromJsValue)]`.
+//!
+//! Structs with named fields are converted to and from plain JS objects,
+//! newtype structs to and from their only field, tuple structs to and from
+//! arrays and unit structs to and from `null`. Enums are externally tagged:
+//! unit variants are represented by their name as a string and all other
+//! variants by an object with a single property named after the variant.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::Diagnostic;
+
+/// Options parsed from `#[js(...)]` attributes on the type.
+struct ContainerOpts {
+    wasm_bindgen: syn::Path,
+}
+
+/// Options parsed from `#[js(...)]` attributes on a field or variant.
+#[derive(Default)]
+struct MemberOpts {
+    rename: Option<String>,
+}
+
+fn container_opts(attrs: &[syn::Attribute]) -> Result<ContainerOpts, Diagnostic> {
+    let mut wasm_bindgen = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("js")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("wasm_bindgen") {
+                if wasm_bindgen.is_some() {
+                    return Err(meta.error("found duplicate `wasm_bindgen`"));
+                }
+                wasm_bindgen = Some(meta.value()?.parse::<syn::Path>()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `wasm_bindgen`"))
+            }
+        })?;
+    }
+    Ok(ContainerOpts {
+        wasm_bindgen: wasm_bindgen.unwrap_or_else(|| syn::parse_quote! { ::wasm_bindgen }),
+    })
+}
+
+fn member_opts(attrs: &[syn::Attribute]) -> Result<MemberOpts, Diagnostic> {
+    let mut opts = MemberOpts::default();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("js")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                if opts.rename.is_some() {
+                    return Err(meta.error("found duplicate `rename`"));
+                }
+                opts.rename = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                Ok(())
+            } else {
+                Err(meta.error("expected `rename`"))
+            }
+        })?;
+    }
+    Ok(opts)
+}
+
+/// A field of a struct or variant, with the name it has in JS.
+struct Field<'a> {
+    ty: &'a syn::Type,
+    /// The member used to access the field in Rust.
+    member: syn::Member,
+    /// The local variable the field is bound to when destructuring.
+    binding: Ident,
+    /// The property name for named fields.
+    js_name: Option<String>,
+}
+
+fn fields(fields: &syn::Fields) -> Result<Vec<Field<'_>>, Diagnostic> {
+    let mut ret = Vec::new();
+    let mut errors = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let opts = match member_opts(&field.attrs) {
+            Ok(opts) => opts,
+            Err(e) => {
+                errors.push(e);
+                continue;
+            }
+        };
+        let (member, js_name) = match &field.ident {
+            Some(ident) => {
+                let name = ident.to_string();
+                let name = name.strip_prefix("r#").map(String::from).unwrap_or(name);
+                (
+                    syn::Member::Named(ident.clone()),
+                    Some(opts.rename.unwrap_or(name)),
+                )
+            }
+            None => {
+                if opts.rename.is_some() {
+                    errors.push(err_span!(
+                        field,
+                        "`rename` is only supported on named fields"
+                    ));
+                }
+                (syn::Member::Unnamed(i.into()), None)
+            }
+        };
+        ret.push(Field {
+            ty: &field.ty,
+            member,
+            binding: format_ident!("__field{}", i),
+            js_name,
+        });
+    }
+    Diagnostic::from_vec(errors)?;
+    Ok(ret)
+}
+
+/// Adds a `T: bound` predicate for every field type if the type is generic,
+/// so that fields of generic types get the conversions they need.
+fn where_clause<'a>(
+    generics: &syn::Generics,
+    types: impl Iterator<Item = &'a syn::Type>,
+    bound: TokenStream,
+) -> Option<syn::WhereClause> {
+    let mut where_clause = generics.where_clause.clone();
+    if generics.params.is_empty() {
+        return where_clause;
+    }
+    let where_clause_ref = where_clause.get_or_insert_with(|| syn::parse_quote!(where));
+    for ty in types {
+        where_clause_ref
+            .predicates
+            .push(syn::parse_quote!(#ty: #bound));
+    }
+    where_clause
+}
+
+fn field_types(data: &syn::Data) -> Vec<&syn::Type> {
+    match data {
+        syn::Data::Struct(s) => s.fields.iter().map(|f| &f.ty).collect(),
+        syn::Data::Enum(e) => e
+            .variants
+            .iter()
+            .flat_map(|v| v.fields.iter().map(|f| &f.ty))
+            .collect(),
+        syn::Data::Union(_) => Vec::new(),
+    }
+}
+
+fn variant_name(variant: &syn::Variant) -> Result<String, Diagnostic> {
+    let opts = member_opts(&variant.attrs)?;
+    Ok(opts.rename.unwrap_or_else(|| variant.ident.to_string()))
+}
+
+/// Takes the input of `#[derive(IntoJsValue)]` and returns the generated
+/// conversion into `JsValue`.
+pub fn expand_into_js_value(input: TokenStream) -> Result<TokenStream, Diagnostic> {
+    let input: syn::DeriveInput = syn::parse2(input)?;
+    let wasm_bindgen = container_opts(&input.attrs)?.wasm_bindgen;
+    let name = &input.ident;
+
+    let body = match &input.data {
+        syn::Data::Struct(s) => {
+            let fields = fields(&s.fields)?;
+            let bindings = fields.iter().map(|f| &f.binding);
+            let members = fields.iter().map(|f| &f.member);
+            let value = fields_into_js(&wasm_bindgen, &s.fields, &fields);
+            quote! {
+                let #name { #(#members: #bindings,)* } = self;
+                #value
+            }
+        }
+        syn::Data::Enum(e) => {
+            let mut arms = Vec::new();
+            for variant in &e.variants {
+                let js_name = variant_name(variant)?;
+                let ident = &variant.ident;
+                let fields = fields(&variant.fields)?;
+                let bindings = fields.iter().map(|f| &f.binding);
+                let members = fields.iter().map(|f| &f.member);
+                let value = if let syn::Fields::Unit = variant.fields {
+                    quote! { #wasm_bindgen::JsValue::from_str(#js_name) }
+                } else {
+                    let inner = fields_into_js(&wasm_bindgen, &variant.fields, &fields);
+                    quote! {
+                        let __object = #wasm_bindgen::convert::__new_object();
+                        #wasm_bindgen::convert::__set(&__object, #js_name, { #inner });
+                        __object
+                    }
+                };
+                arms.push(quote! {
+                    #name::#ident { #(#members: #bindings,)* } => { #value }
+                });
+            }
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        syn::Data::Union(u) => {
+            bail_span!(u.union_token, "`IntoJsValue` cannot be derived for unions")
+        }
+    };
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = where_clause(
+        &input.generics,
+        field_types(&input.data).into_iter(),
+        quote! { #wasm_bindgen::convert::IntoJsField },
+    );
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #wasm_bindgen::convert::IntoJsField for #name #ty_generics
+            #where_clause
+        {
+            fn into_js_field(self) -> #wasm_bindgen::JsValue {
+                #body
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#name #ty_generics> for #wasm_bindgen::JsValue
+            #where_clause
+        {
+            #[inline]
+            fn from(value: #name #ty_generics) -> Self {
+                #wasm_bindgen::convert::IntoJsField::into_js_field(value)
+            }
+        }
+    })
+}
+
+/// Generates an expression converting the `fields`, bound to their
+/// `binding`s, into a `JsValue`.
+fn fields_into_js(wasm_bindgen: &syn::Path, kind: &syn::Fields, fields: &[Field]) -> TokenStream {
+    let into = quote! { #wasm_bindgen::convert::IntoJsField::into_js_field };
+    match kind {
+        syn::Fields::Named(_) => {
+            let sets = fields.iter().map(|f| {
+                let js_name = f.js_name.as_ref().unwrap();
+                let binding = &f.binding;
+                quote! {
+                    #wasm_bindgen::convert::__set(&__object, #js_name, #into(#binding));
+                }
+            });
+            quote! {
+                let __object = #wasm_bindgen::convert::__new_object();
+                #(#sets)*
+                __object
+            }
+        }
+        syn::Fields::Unnamed(_) if fields.len() == 1 => {
+            let binding = &fields[0].binding;
+            quote! { #into(#binding) }
+        }
+        syn::Fields::Unnamed(_) => {
+            let sets = fields.iter().enumerate().map(|(i, f)| {
+                let binding = &f.binding;
+                quote! {
+                    #wasm_bindgen::convert::__set_index(&__array, #i, #into(#binding));
+                }
+            });
+            quote! {
+                let __array = #wasm_bindgen::convert::__new_array();
+                #(#sets)*
+                __array
+            }
+        }
+        syn::Fields::Unit => quote! { #wasm_bindgen::JsValue::NULL },
+    }
+}
+
+/// Takes the input of `#[derive(TryFromJsValue)]` and returns the generated
+/// conversion from `JsValue`.
+pub fn expand_try_from_js_value(input: TokenStream) -> Result<TokenStream, Diagnostic> {
+    let input: syn::DeriveInput = syn::parse2(input)?;
+    let wasm_bindgen = container_opts(&input.attrs)?.wasm_bindgen;
+    let name = &input.ident;
+
+    let body = match &input.data {
+        syn::Data::Struct(s) => {
+            let fields = fields(&s.fields)?;
+            fields_from_js(&wasm_bindgen, &quote! { #name }, &s.fields, &fields)
+        }
+        syn::Data::Enum(e) => {
+            let mut unit = Vec::new();
+            let mut tagged = Vec::new();
+            let mut expected = Vec::new();
+            for variant in &e.variants {
+                let js_name = variant_name(variant)?;
+                let ident = &variant.ident;
+                if let syn::Fields::Unit = variant.fields {
+                    expected.push(format!("{js_name:?}"));
+                    unit.push(quote! { #js_name => return Ok(#name::#ident), });
+                    continue;
+                }
+                expected.push(format!("{{ {js_name} }}"));
+                let fields = fields(&variant.fields)?;
+                let inner = fields_from_js(
+                    &wasm_bindgen,
+                    &quote! { #name::#ident },
+                    &variant.fields,
+                    &fields,
+                );
+                tagged.push(quote! {
+                    if #wasm_bindgen::convert::__has(value, #js_name) {
+                        let value = &#wasm_bindgen::convert::__get(value, #js_name);
+                        let variant = (|| -> ::core::result::Result<Self, #wasm_bindgen::convert::JsFieldError> {
+                            #inner
+                        })();
+                        return variant.map_err(|e| e.field(#js_name));
+                    }
+                });
+            }
+            let expected = format!("one of {}", expected.join(", "));
+            quote! {
+                if let Some(tag) = value.as_string() {
+                    match tag.as_str() {
+                        #(#unit)*
+                        _ => {}
+                    }
+                } else if value.is_object() {
+                    #(#tagged)*
+                }
+                Err(#wasm_bindgen::convert::JsFieldError::expected(#expected))
+            }
+        }
+        syn::Data::Union(u) => {
+            bail_span!(
+                u.union_token,
+                "`TryFromJsValue` cannot be derived for unions"
+            )
+        }
+    };
+
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+    let where_clause = where_clause(
+        &input.generics,
+        field_types(&input.data).into_iter(),
+        quote! { #wasm_bindgen::convert::TryFromJsField },
+    );
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #wasm_bindgen::convert::TryFromJsField for #name #ty_generics
+            #where_clause
+        {
+            #[allow(clippy::redundant_closure_call)]
+            fn try_from_js_field(
+                value: &#wasm_bindgen::JsValue,
+            ) -> ::core::result::Result<Self, #wasm_bindgen::convert::JsFieldError> {
+                #body
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #wasm_bindgen::convert::TryFromJsValue for #name #ty_generics
+            #where_clause
+        {
+            fn try_from_js_value(
+                value: #wasm_bindgen::JsValue,
+            ) -> ::core::result::Result<Self, #wasm_bindgen::JsValue> {
+                <Self as #wasm_bindgen::convert::TryFromJsField>::try_from_js_field(&value)
+                    .map_err(::core::convert::Into::into)
+            }
+
+            fn try_from_js_value_ref(value: &#wasm_bindgen::JsValue) -> ::core::option::Option<Self> {
+                <Self as #wasm_bindgen::convert::TryFromJsField>::try_from_js_field(value).ok()
+            }
+        }
+    })
+}
+
+/// Generates statements returning the result of constructing `ctor` from the
+/// `&JsValue` bound to `value`.
+fn fields_from_js(
+    wasm_bindgen: &syn::Path,
+    ctor: &TokenStream,
+    kind: &syn::Fields,
+    fields: &[Field],
+) -> TokenStream {
+    let convert = |ty: &syn::Type, value: TokenStream| {
+        quote! {
+            <#ty as #wasm_bindgen::convert::TryFromJsField>::try_from_js_field(#value)
+        }
+    };
+    match kind {
+        syn::Fields::Named(_) => {
+            let inits = fields.iter().map(|f| {
+                let js_name = f.js_name.as_ref().unwrap();
+                let member = &f.member;
+                let convert = convert(
+                    f.ty,
+                    quote! { &#wasm_bindgen::convert::__get(value, #js_name) },
+                );
+                quote! {
+                    #member: #convert.map_err(|e| e.field(#js_name))?,
+                }
+            });
+            quote! {
+                #wasm_bindgen::convert::__expect_object(value, "object")?;
+                Ok(#ctor { #(#inits)* })
+            }
+        }
+        syn::Fields::Unnamed(_) if fields.len() == 1 => {
+            let convert = convert(fields[0].ty, quote! { value });
+            quote! { Ok(#ctor(#convert?)) }
+        }
+        syn::Fields::Unnamed(_) => {
+            let inits = fields.iter().enumerate().map(|(i, f)| {
+                let convert = convert(
+                    f.ty,
+                    quote! { &#wasm_bindgen::convert::__get_index(value, #i) },
+                );
+                quote! { #convert.map_err(|e| e.index(#i))?, }
+            });
+            quote! {
+                if !value.is_array() {
+                    return Err(#wasm_bindgen::convert::JsFieldError::expected("array"));
+                }
+                Ok(#ctor(#(#inits)*))
+            }
+        }
+        syn::Fields::Unit => quote! {
+            if value.is_null_or_undefined() {
+                Ok(#ctor)
+            } else {
+                Err(#wasm_bindgen::convert::JsFieldError::expected("null"))
+            }
+        },
+    }
+}
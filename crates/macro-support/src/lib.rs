@@ -7,12 +7,14 @@ mod error;
 
 mod ast;
 mod codegen;
+mod derive;
 mod encode;
 mod generics;
 mod hash;
 mod parser;
 
 use codegen::TryToTokens;
+pub use derive::{expand_into_js_value, expand_try_from_js_value};
 use error::Diagnostic;
 pub use parser::BindgenAttrs;
 use parser::{ConvertToAst, MacroParse};
@@ -94,6 +94,7 @@ macro_rules! attrgen {
             (js_class, false, JsClass(Span, String, Span)),
             (reexport, false, Reexport(Span, Option<String>)),
             (inspectable, false, Inspectable(Span)),
+            (reentrant, false, Reentrant(Span)),
             (is_type_of, false, IsTypeOf(Span, syn::Expr)),
             (extends, false, Extends(Span, syn::Path)),
             (no_deref, false, NoDeref(Span)),
@@ -120,6 +121,7 @@ macro_rules! attrgen {
             (return_description, true, ReturnDesc(Span, String, Span)),
             (unchecked_param_type, true, ParamType(Span, String, Span)),
             (param_description, true, ParamDesc(Span, String, Span)),
+            (throws, false, Throws(Span, syn::Path)),
 
             // For testing purposes only.
             (assert_no_shim, false, AssertNoShim(Span)),
@@ -493,6 +495,7 @@ impl ConvertToAst<&ast::Program> for &mut syn::ItemStruct {
         }
 
         let is_inspectable = attrs.inspectable().is_some();
+        let reentrant = attrs.reentrant().is_some();
         let getter_with_clone = attrs.getter_with_clone();
         for (i, field) in self.fields.iter_mut().enumerate() {
             match field.vis {
@@ -515,6 +518,15 @@ impl ConvertToAst<&ast::Program> for &mut syn::ItemStruct {
                 None => js_field_name,
             };
 
+            // Setters borrow the struct mutably, which `reentrant` structs
+            // don't support.
+            if reentrant && attrs.readonly().is_none() {
+                bail_span!(
+                    field,
+                    "public fields of `reentrant` structs must be `readonly`"
+                );
+            }
+
             let comments = extract_doc_comments(&field.attrs);
             let getter = wasm_bindgen_shared::struct_field_get(&js_name, &js_field_name);
             let setter = wasm_bindgen_shared::struct_field_set(&js_name, &js_field_name);
@@ -546,6 +558,7 @@ impl ConvertToAst<&ast::Program> for &mut syn::ItemStruct {
             fields,
             comments,
             is_inspectable,
+            reentrant,
             generate_typescript,
             private,
             js_namespace,
@@ -1349,6 +1362,7 @@ impl<'a> MacroParse<(Option<BindgenAttrs>, &'a mut TokenStream)> for syn::Item {
                 }
 
                 let js_namespace = opts.js_namespace().map(|(ns, _)| ns.0);
+                let throws = opts.throws().cloned();
                 program.exports.push(ast::Export {
                     comments,
                     function: f.convert((opts, args_attrs))?,
@@ -1359,6 +1373,7 @@ impl<'a> MacroParse<(Option<BindgenAttrs>, &'a mut TokenStream)> for syn::Item {
                     rust_class: None,
                     rust_name,
                     start,
+                    throws,
                     wasm_bindgen: program.wasm_bindgen.clone(),
                     wasm_bindgen_futures: program.wasm_bindgen_futures.clone(),
                 });
@@ -1582,6 +1597,7 @@ impl MacroParse<&ClassMarker> for &mut syn::ImplItemFn {
             rust_class: Some(class.clone()),
             rust_name: self.sig.ident.clone(),
             start: false,
+            throws: opts.throws().cloned(),
             wasm_bindgen: program.wasm_bindgen.clone(),
             wasm_bindgen_futures: program.wasm_bindgen_futures.clone(),
         });
@@ -73,3 +73,43 @@ pub fn __wasm_bindgen_struct_marker(item: TokenStream) -> TokenStream {
         Err(diagnostic) => (quote! { #diagnostic }).into(),
     }
 }
+
+/// Derives conversion of a Rust type into a plain JS value, by implementing
+/// `From<T> for JsValue`.
+///
+/// Structs with named fields become objects, newtype structs become their
+/// field, tuple structs become arrays and unit structs become `null`. Unit
+/// enum variants become strings of their name and other variants become an
+/// object with a single property named after the variant. Fields and variants
+/// can be renamed with `#[js(rename = "name")]`.
+#[proc_macro_derive(IntoJsValue, attributes(js))]
+pub fn into_js_value(item: TokenStream) -> TokenStream {
+    match wasm_bindgen_macro_support::expand_into_js_value(item.into()) {
+        Ok(tokens) => {
+            if cfg!(xxx_debug_only_print_generated_code) {
+                println!("{tokens}");
+            }
+            tokens.into()
+        }
+        Err(diagnostic) => (quote! { #diagnostic }).into(),
+    }
+}
+
+/// Derives conversion of a plain JS value into a Rust type, by implementing
+/// `TryFromJsValue`.
+///
+/// The expected shape of the JS value is the one produced by
+/// `#[derive(IntoJsValue)]`. On failure the error describes the path to the
+/// offending value, e.g. `items[3].name: expected string`.
+#[proc_macro_derive(TryFromJsValue, attributes(js))]
+pub fn try_from_js_value(item: TokenStream) -> TokenStream {
+    match wasm_bindgen_macro_support::expand_try_from_js_value(item.into()) {
+        Ok(tokens) => {
+            if cfg!(xxx_debug_only_print_generated_code) {
+                println!("{tokens}");
+            }
+            tokens.into()
+        }
+        Err(diagnostic) => (quote! { #diagnostic }).into(),
+    }
+}
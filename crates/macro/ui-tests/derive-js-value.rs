@@ -0,0 +1,23 @@
+use wasm_bindgen::{IntoJsValue, TryFromJsValue};
+
+#[derive(IntoJsValue)]
+union A {
+    a: u32,
+}
+
+#[derive(TryFromJsValue)]
+struct B(#[js(rename = "b")] u32);
+
+#[derive(IntoJsValue)]
+struct C {
+    #[js(skip)]
+    c: u32,
+}
+
+#[derive(TryFromJsValue)]
+enum D {
+    #[js(rename = "a", rename = "b")]
+    D,
+}
+
+fn main() {}
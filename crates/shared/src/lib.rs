@@ -226,6 +226,14 @@ pub fn struct_function_export_name(struct_: &str, f: &str) -> String {
     name
 }
 
+/// The name of the descriptor for what an export throws, given the export's
+/// name.
+pub fn throws_descriptor_name(export_name: &str) -> String {
+    let mut name = String::from("__wbg_throws_");
+    name.push_str(export_name);
+    name
+}
+
 pub fn struct_field_get(struct_: &str, f: &str) -> String {
     let mut name = String::from("__wbg_get_");
     name.extend(struct_.chars().flat_map(|s| s.to_lowercase()));
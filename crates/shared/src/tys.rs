@@ -41,4 +41,5 @@ tys! {
     UNIT
     CLAMPED
     NONNULL
+    JS_STRING
 }
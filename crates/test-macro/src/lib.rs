@@ -119,15 +119,43 @@ fn bindgen(
         ident.clone()
     };
 
-    let test_body = if attributes.r#async || is_bench {
-        quote! { cx.execute_async(test_name, #exec_ident, #should_panic_par, #ignore_par); }
+    let wasm_bindgen_path = attributes.wasm_bindgen_path;
+    let exec = if attributes.check_leaks {
+        if is_bench || attributes.property.is_some() {
+            return compile_error(
+                ident.span(),
+                "`check_leaks` is not supported on benchmarks or property tests",
+            );
+        }
+        if attributes.r#async {
+            quote! { #wasm_bindgen_path::__rt::leaks::check_async(#exec_ident) }
+        } else {
+            quote! { #wasm_bindgen_path::__rt::leaks::check_sync(#exec_ident) }
+        }
     } else {
-        quote! { cx.execute_sync(test_name, #exec_ident, #should_panic_par, #ignore_par); }
+        quote! { #exec_ident }
+    };
+
+    let test_body = if let Some(cases) = &attributes.property {
+        if attributes.r#async || is_bench {
+            return compile_error(
+                ident.span(),
+                "`property` is only supported on synchronous tests",
+            );
+        }
+        let cases = match cases {
+            Some(lit) => quote! { ::core::option::Option::Some(#lit) },
+            None => quote! { ::core::option::Option::None },
+        };
+        quote! { cx.execute_property(test_name, #exec_ident, #cases, #should_panic_par, #ignore_par); }
+    } else if attributes.r#async || is_bench {
+        quote! { cx.execute_async(test_name, #exec, #should_panic_par, #ignore_par); }
+    } else {
+        quote! { cx.execute_sync(test_name, #exec, #should_panic_par, #ignore_par); }
     };
 
     let ignore_name = if ignore.is_some() { "$" } else { "" };
 
-    let wasm_bindgen_path = attributes.wasm_bindgen_path;
     let prefix = if is_bench { "__wbgb_" } else { "__wbgt_" };
     tokens.extend(
         quote! {
@@ -340,6 +368,8 @@ struct Attributes {
     r#async: bool,
     wasm_bindgen_path: syn::Path,
     unsupported: Option<syn::Meta>,
+    property: Option<Option<syn::LitInt>>,
+    check_leaks: bool,
 }
 
 impl Default for Attributes {
@@ -348,6 +378,8 @@ impl Default for Attributes {
             r#async: false,
             wasm_bindgen_path: syn::parse_quote!(::wasm_bindgen_test),
             unsupported: None,
+            property: None,
+            check_leaks: false,
         }
     }
 }
@@ -360,6 +392,21 @@ impl Attributes {
             self.wasm_bindgen_path = meta.value()?.parse::<syn::Path>()?;
         } else if meta.path.is_ident("unsupported") {
             self.unsupported = Some(meta.value()?.parse::<syn::Meta>()?);
+        } else if meta.path.is_ident("property") {
+            let mut cases = None;
+            if meta.input.peek(syn::token::Paren) {
+                meta.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("cases") {
+                        cases = Some(meta.value()?.parse::<syn::LitInt>()?);
+                        Ok(())
+                    } else {
+                        Err(meta.error("unknown `property` attribute"))
+                    }
+                })?;
+            }
+            self.property = Some(cases);
+        } else if meta.path.is_ident("check_leaks") {
+            self.check_leaks = true;
         } else {
             return Err(meta.error("unknown attribute"));
         }
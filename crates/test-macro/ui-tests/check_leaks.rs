@@ -0,0 +1,19 @@
+#![no_implicit_prelude]
+
+extern crate wasm_bindgen_test_macro;
+
+use wasm_bindgen_test_macro::wasm_bindgen_test;
+
+#[wasm_bindgen_test(check_leaks)]
+fn success_1() {}
+
+#[wasm_bindgen_test(check_leaks)]
+async fn success_2() {}
+
+#[wasm_bindgen_test(check_leaks, property)]
+fn failure_1(_: u32) {}
+
+#[wasm_bindgen_test(check_leaks = true)]
+fn failure_2() {}
+
+fn main() {}
@@ -0,0 +1,25 @@
+#![no_implicit_prelude]
+
+extern crate wasm_bindgen_test_macro;
+
+use wasm_bindgen_test_macro::wasm_bindgen_test;
+
+#[wasm_bindgen_test(property)]
+fn success_1(_: u32) {}
+
+#[wasm_bindgen_test(property(cases = 10))]
+fn success_2(_: (u32, bool)) {}
+
+#[wasm_bindgen_test(property(cases))]
+fn failure_1(_: u32) {}
+
+#[wasm_bindgen_test(property(seed = 10))]
+fn failure_2(_: u32) {}
+
+#[wasm_bindgen_test(property)]
+async fn failure_3(_: u32) {}
+
+#[wasm_bindgen_test(property, async)]
+fn failure_4(_: u32) {}
+
+fn main() {}
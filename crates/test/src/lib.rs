@@ -126,5 +126,11 @@ mod coverage;
 // A modified `criterion.rs`, retaining only the basic benchmark capabilities.
 pub use __rt::criterion::Criterion;
 
+// Property testing support for `#[wasm_bindgen_test(property)]`.
+pub use __rt::property::{Arbitrary, Gen};
+
+// Allocation tracking for `#[wasm_bindgen_test(check_leaks)]`.
+pub use __rt::leaks::TrackingAllocator;
+
 // web_time Instant
 pub use __rt::web_time::Instant;
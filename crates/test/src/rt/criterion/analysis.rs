@@ -8,7 +8,7 @@ use super::estimate::{
     build_estimates, ConfidenceInterval, Distributions, Estimate, Estimates, PointEstimates,
 };
 use super::measurement::Measurement;
-use super::report::{BenchmarkId, Report};
+use super::report::{compare_to_threshold, BenchmarkId, ComparisonResult, Report};
 use super::routine::Routine;
 use super::{baseline, compare, Criterion, SavedSample};
 
@@ -76,6 +76,31 @@ pub(crate) async fn common<M: Measurement>(
         .report
         .measurement_complete(id, &measurement_data, criterion.measurement.formatter());
 
+    baseline::report(
+        id.desc(),
+        baseline::BenchmarkReport {
+            estimates: estimates.clone(),
+            change: measurement_data
+                .comparison
+                .as_ref()
+                .map(|comp| baseline::BenchmarkChange {
+                    mean: comp.relative_estimates.mean.clone(),
+                    median: comp.relative_estimates.median.clone(),
+                    p_value: comp.p_value,
+                    significance_threshold: comp.significance_threshold,
+                    noise_threshold: comp.noise_threshold,
+                    regressed: comp.p_value < comp.significance_threshold
+                        && matches!(
+                            compare_to_threshold(
+                                &comp.relative_estimates.mean,
+                                comp.noise_threshold
+                            ),
+                            ComparisonResult::Regressed
+                        ),
+                }),
+        },
+    );
+
     baseline::write(
         id.desc(),
         baseline::BenchmarkBaseline {
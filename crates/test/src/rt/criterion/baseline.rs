@@ -3,7 +3,8 @@
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::wasm_bindgen;
 
-use super::{estimate::Estimates, SavedSample};
+use super::estimate::{Estimate, Estimates};
+use super::SavedSample;
 use alloc::collections::BTreeMap;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -14,6 +15,10 @@ use wasm_bindgen::__rt::LazyCell;
 static BASELINE: LazyCell<RefCell<BTreeMap<String, BenchmarkBaseline>>> =
     LazyCell::new(|| RefCell::new(BTreeMap::new()));
 
+#[cfg_attr(target_feature = "atomics", thread_local)]
+static REPORT: LazyCell<RefCell<BTreeMap<String, BenchmarkReport>>> =
+    LazyCell::new(|| RefCell::new(BTreeMap::new()));
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub(crate) struct BenchmarkBaseline {
     pub(crate) file: Option<String>,
@@ -24,6 +29,27 @@ pub(crate) struct BenchmarkBaseline {
     pub(crate) estimates: Estimates,
 }
 
+/// Machine-readable results of a single benchmark in this run.
+#[derive(Debug, Serialize, Clone)]
+pub(crate) struct BenchmarkReport {
+    pub(crate) estimates: Estimates,
+    /// Comparison against the imported baseline, if there was one.
+    pub(crate) change: Option<BenchmarkChange>,
+}
+
+/// Relative change of a benchmark compared to the imported baseline.
+#[derive(Debug, Serialize, Clone)]
+pub(crate) struct BenchmarkChange {
+    pub(crate) mean: Estimate,
+    pub(crate) median: Estimate,
+    pub(crate) p_value: f64,
+    pub(crate) significance_threshold: f64,
+    pub(crate) noise_threshold: f64,
+    /// Whether the slowdown is statistically significant and outside of the
+    /// noise threshold.
+    pub(crate) regressed: bool,
+}
+
 /// Write the corresponding benchmark ID and corresponding data into the table.
 pub(crate) fn write(id: &str, baseline: BenchmarkBaseline) {
     BASELINE.borrow_mut().insert(id.into(), baseline);
@@ -34,6 +60,11 @@ pub(crate) fn read(id: &str) -> Option<BenchmarkBaseline> {
     BASELINE.borrow().get(id).cloned()
 }
 
+/// Record the results of the benchmark with the given ID for the report.
+pub(crate) fn report(id: &str, report: BenchmarkReport) {
+    REPORT.borrow_mut().insert(id.into(), report);
+}
+
 /// Used to write previous benchmark data before the benchmark, for later comparison.
 #[wasm_bindgen]
 pub fn __wbgbench_import(baseline: Vec<u8>) {
@@ -56,3 +87,13 @@ pub fn __wbgbench_dump() -> Option<Vec<u8>> {
     }
     serde_json::to_vec(&*baseline).ok()
 }
+
+/// Used to read the report of this run, which the runner stores on the local disk.
+#[wasm_bindgen]
+pub fn __wbgbench_report() -> Option<Vec<u8>> {
+    let report = REPORT.borrow();
+    if report.is_empty() {
+        return None;
+    }
+    serde_json::to_vec(&*report).ok()
+}
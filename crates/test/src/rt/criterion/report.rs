@@ -230,13 +230,13 @@ impl Report for WasmReport {
     }
 }
 
-enum ComparisonResult {
+pub(crate) enum ComparisonResult {
     Improved,
     Regressed,
     NonSignificant,
 }
 
-fn compare_to_threshold(estimate: &Estimate, noise: f64) -> ComparisonResult {
+pub(crate) fn compare_to_threshold(estimate: &Estimate, noise: f64) -> ComparisonResult {
     let ci = &estimate.confidence_interval;
     let lb = ci.lower_bound;
     let ub = ci.upper_bound;
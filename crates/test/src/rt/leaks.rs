@@ -0,0 +1,209 @@
+//! Leak detection for `#[wasm_bindgen_test(check_leaks)]`.
+//!
+//! A snapshot of the externref heap, the live `Closure`s and, if
+//! [`TrackingAllocator`] is installed as the global allocator, the live heap
+//! allocations is taken before and after the test. If any of them grew the
+//! test fails with a description of the difference.
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::alloc::{GlobalAlloc, Layout};
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use wasm_bindgen::prelude::*;
+
+use super::Termination;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static EXCLUDED_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static EXCLUDED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// A global allocator wrapper counting live allocations, which lets
+/// `#[wasm_bindgen_test(check_leaks)]` detect leaked Rust memory.
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOC: wasm_bindgen_test::TrackingAllocator<std::alloc::System> =
+///     wasm_bindgen_test::TrackingAllocator::new(std::alloc::System);
+/// ```
+pub struct TrackingAllocator<A> {
+    inner: A,
+}
+
+impl<A> TrackingAllocator<A> {
+    /// Wraps `inner`, which performs the actual allocations.
+    pub const fn new(inner: A) -> Self {
+        Self { inner }
+    }
+}
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for TrackingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        ALLOCATIONS.fetch_sub(1, Ordering::Relaxed);
+        BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+            BYTES.fetch_add(new_size, Ordering::Relaxed);
+        }
+        new_ptr
+    }
+}
+
+fn allocated(size: usize) {
+    INSTALLED.store(true, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size, Ordering::Relaxed);
+}
+
+/// Runs `f` without accounting the memory it leaves allocated to the test.
+///
+/// This is used for the test harness' own bookkeeping, like captured console
+/// output, which lives until the test finished.
+pub(super) fn exclude<R>(f: impl FnOnce() -> R) -> R {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let result = f();
+    let allocations = ALLOCATIONS
+        .load(Ordering::Relaxed)
+        .wrapping_sub(allocations);
+    let bytes = BYTES.load(Ordering::Relaxed).wrapping_sub(bytes);
+    EXCLUDED_ALLOCATIONS.fetch_add(allocations, Ordering::Relaxed);
+    EXCLUDED_BYTES.fetch_add(bytes, Ordering::Relaxed);
+    result
+}
+
+struct Snapshot {
+    /// Live allocations and bytes, if [`TrackingAllocator`] is installed.
+    heap: Option<(usize, usize)>,
+    externrefs: u32,
+    closures: u32,
+    /// Empty unless `wasm-bindgen`'s `closure-diagnostics` feature is enabled.
+    live_closures: Vec<wasm_bindgen::closure::LiveClosure>,
+}
+
+impl Snapshot {
+    fn take() -> Self {
+        let heap = INSTALLED.load(Ordering::Relaxed).then(|| {
+            (
+                ALLOCATIONS
+                    .load(Ordering::Relaxed)
+                    .wrapping_sub(EXCLUDED_ALLOCATIONS.load(Ordering::Relaxed)),
+                BYTES
+                    .load(Ordering::Relaxed)
+                    .wrapping_sub(EXCLUDED_BYTES.load(Ordering::Relaxed)),
+            )
+        });
+        Self {
+            heap,
+            externrefs: wasm_bindgen::externref_heap_live_count(),
+            closures: wasm_bindgen::closure_live_count(),
+            live_closures: wasm_bindgen::live_closures(),
+        }
+    }
+
+    /// Fails with a description of everything that grew since `self`.
+    fn compare(&self, after: &Snapshot) -> Result<(), JsValue> {
+        let mut diff = String::new();
+        let mut check = |what: &str, before: usize, after: usize| {
+            if after > before {
+                diff.push_str(&format!(
+                    "\n  {what}: {before} -> {after} (+{})",
+                    after - before
+                ));
+            }
+        };
+        if let (Some(before), Some(after)) = (self.heap, after.heap) {
+            check("heap allocations", before.0, after.0);
+            check("heap bytes", before.1, after.1);
+        }
+        check(
+            "externref heap slots",
+            self.externrefs as usize,
+            after.externrefs as usize,
+        );
+        check("closures", self.closures as usize, after.closures as usize);
+        let mut before = self.live_closures.clone();
+        for closure in &after.live_closures {
+            match before.iter().position(|c| c == closure) {
+                Some(i) => {
+                    before.swap_remove(i);
+                }
+                None => diff.push_str(&format!("\n    {closure}")),
+            }
+        }
+
+        if diff.is_empty() {
+            Ok(())
+        } else {
+            Err(JsError::new(&format!("test leaked resources:{diff}")).into())
+        }
+    }
+}
+
+/// The result of a test run by [`check_sync`] or [`check_async`].
+pub struct Checked(Result<(), JsValue>);
+
+impl Termination for Checked {
+    fn into_js_result(self) -> Result<(), JsValue> {
+        self.0
+    }
+}
+
+/// Wraps a synchronous test so that it fails if it leaked.
+pub fn check_sync<T: Termination>(
+    f: impl FnOnce() -> T + 'static,
+) -> impl FnOnce() -> Checked + 'static {
+    move || {
+        let before = Snapshot::take();
+        Checked(
+            f().into_js_result()
+                .and_then(|()| before.compare(&Snapshot::take())),
+        )
+    }
+}
+
+/// Wraps an asynchronous test so that it fails if it leaked.
+pub fn check_async<F>(
+    f: impl FnOnce() -> F + 'static,
+) -> impl FnOnce() -> Pin<Box<dyn Future<Output = Checked>>> + 'static
+where
+    F: Future + 'static,
+    F::Output: Termination,
+{
+    move || {
+        Box::pin(async move {
+            let before = Snapshot::take();
+            Checked(
+                f().await
+                    .into_js_result()
+                    .and_then(|()| before.compare(&Snapshot::take())),
+            )
+        })
+    }
+}
@@ -119,7 +119,9 @@ pub mod browser;
 #[cfg_attr(wasm_bindgen_unstable_test_coverage, coverage(off))]
 pub mod criterion;
 pub mod detect;
+pub mod leaks;
 pub mod node;
+pub mod property;
 mod scoped_tls;
 /// Directly depending on wasm-bindgen-test-based libraries should be avoided,
 /// as it creates a circular dependency that breaks their usage within `wasm-bindgen-test`.
@@ -145,6 +147,9 @@ struct State {
     /// Include ignored tests.
     include_ignored: Cell<bool>,
 
+    /// Seed for property tests, passed with `--seed`.
+    property_seed: Cell<Option<u64>>,
+
     /// Counter of the number of tests that have succeeded.
     succeeded_count: Cell<usize>,
 
@@ -335,7 +340,10 @@ impl Context {
         static SET_HOOK: std::sync::Once = std::sync::Once::new();
         #[cfg(feature = "std")]
         SET_HOOK.call_once(|| {
+            // Don't chain to the previous hook, which would print the panic a
+            // second time, but record panic locations for `PanicError`s.
             std::panic::set_hook(Box::new(|panic_info| {
+                wasm_bindgen::record_panic_location(panic_info.location());
                 panic_handling(panic_info.to_string());
             }));
         });
@@ -358,6 +366,7 @@ impl Context {
             state: Rc::new(State {
                 is_bench,
                 include_ignored: Default::default(),
+                property_seed: Default::default(),
                 failures: Default::default(),
                 succeeded_count: Default::default(),
                 filtered_count: Default::default(),
@@ -375,6 +384,11 @@ impl Context {
         self.state.include_ignored.set(include_ignored);
     }
 
+    /// Handle `--seed` flag.
+    pub fn property_seed(&mut self, seed: u64) {
+        self.state.property_seed.set(Some(seed));
+    }
+
     /// Handle filter argument.
     pub fn filtered_count(&mut self, filtered: usize) {
         self.state.filtered_count.set(filtered);
@@ -476,16 +490,20 @@ fn record(args: &Array, dst: impl FnOnce(&mut Output) -> &mut String) {
         return;
     }
 
-    CURRENT_OUTPUT.with(|output| {
-        let mut out = output.borrow_mut();
-        let dst = dst(&mut out);
-        args.for_each(&mut |val, idx, _array| {
-            if idx != 0 {
-                dst.push(' ');
-            }
-            dst.push_str(&stringify(&val));
+    // Captured output lives until the test finished, don't report it as
+    // leaked by `check_leaks`.
+    leaks::exclude(|| {
+        CURRENT_OUTPUT.with(|output| {
+            let mut out = output.borrow_mut();
+            let dst = dst(&mut out);
+            args.for_each(&mut |val, idx, _array| {
+                if idx != 0 {
+                    dst.push(' ');
+                }
+                dst.push_str(&stringify(&val));
+            });
+            dst.push('\n');
         });
-        dst.push('\n');
     });
 }
 
@@ -541,6 +559,37 @@ impl Context {
         )
     }
 
+    /// Entry point for a property test in wasm. The
+    /// `#[wasm_bindgen_test(property)]` macro generates invocations of this
+    /// method.
+    ///
+    /// `f` is executed for `cases` randomly generated inputs, and the first
+    /// failing input is shrunk before being reported along with the seed.
+    pub fn execute_property<T, R>(
+        &self,
+        name: &str,
+        f: impl Fn(T) -> R + 'static,
+        cases: Option<u32>,
+        should_panic: Option<Option<&'static str>>,
+        ignore: Option<Option<&'static str>>,
+    ) where
+        T: property::Arbitrary,
+        R: Termination,
+    {
+        let seed = self
+            .state
+            .property_seed
+            .get()
+            .unwrap_or_else(property::random_seed);
+        let cases = cases.unwrap_or(property::DEFAULT_CASES);
+        self.execute(
+            name,
+            async move { property::check(seed, cases, f) },
+            should_panic,
+            ignore,
+        )
+    }
+
     fn execute(
         &self,
         name: &str,
@@ -647,9 +696,13 @@ impl State {
         // Save off the test for later processing when we print the final
         // results.
         if let Some(should_panic) = test.should_panic {
-            if let TestResult::Err(_e) = result {
+            if let TestResult::Err(e) = &result {
                 if let Some(expected) = should_panic {
-                    if !test.output.borrow().panic.contains(expected) {
+                    // Tests can also fail with an error instead of a panic,
+                    // like the ones of `check_leaks`.
+                    if !test.output.borrow().panic.contains(expected)
+                        && !self.formatter.stringify_error(e).contains(expected)
+                    {
                         self.formatter.log_test(
                             self.is_bench,
                             &test.name,
@@ -0,0 +1,392 @@
+//! Minimal property testing support for `#[wasm_bindgen_test(property)]`.
+//!
+//! A property test is a function taking a single argument implementing
+//! [`Arbitrary`]. The runtime generates a number of random inputs from a seed,
+//! executes the test for each of them and, on failure, repeatedly shrinks the
+//! failing input to a minimal counterexample. The seed is reported so that the
+//! failure can be reproduced with `wasm-bindgen-test-runner --seed`.
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use wasm_bindgen::prelude::*;
+
+use super::{Termination, CURRENT_OUTPUT};
+
+/// Number of cases to run when no `cases` are configured.
+pub(super) const DEFAULT_CASES: u32 = 100;
+
+/// Maximum number of successful shrinking steps.
+///
+/// With `panic=abort` every failed case leaks some of the shadow stack, so
+/// keep this bounded.
+const MAX_SHRINK_STEPS: u32 = 256;
+
+/// Source of randomness handed to [`Arbitrary::arbitrary`].
+///
+/// This is a small SplitMix64 generator, so the same seed always produces the
+/// same sequence of inputs.
+pub struct Gen {
+    state: u64,
+    size: usize,
+}
+
+impl Gen {
+    /// Creates a generator from `seed` producing collections of at most
+    /// `size` elements.
+    pub fn new(seed: u64, size: usize) -> Self {
+        Self { state: seed, size }
+    }
+
+    /// The maximum length of generated collections.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the next random `u64`.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a random number in `0..bound`, or `0` if `bound` is `0`.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            0
+        } else {
+            self.next_u64() % bound
+        }
+    }
+}
+
+/// Types that can be randomly generated and shrunk by property tests.
+pub trait Arbitrary: Clone + Debug + 'static {
+    /// Generates a random value.
+    fn arbitrary(g: &mut Gen) -> Self;
+
+    /// Returns "smaller" candidates for this value, tried in order when
+    /// minimizing a failing input.
+    fn shrink(&self) -> Vec<Self> {
+        Vec::new()
+    }
+}
+
+impl Arbitrary for () {
+    fn arbitrary(_: &mut Gen) -> Self {}
+}
+
+impl Arbitrary for bool {
+    fn arbitrary(g: &mut Gen) -> Self {
+        g.next_u64() & 1 == 1
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        if *self {
+            alloc::vec![false]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+macro_rules! arbitrary_int {
+    ($($ty:ty => $small:expr,)*) => ($(
+        impl Arbitrary for $ty {
+            fn arbitrary(g: &mut Gen) -> Self {
+                // Bias towards small values, they are the most likely to find
+                // edge cases.
+                if g.below(4) == 0 {
+                    g.next_u64() as $ty
+                } else {
+                    let small: fn(&mut Gen) -> $ty = $small;
+                    small(g)
+                }
+            }
+
+            fn shrink(&self) -> Vec<Self> {
+                let x = *self;
+                if x == 0 {
+                    return Vec::new();
+                }
+                let mut candidates = alloc::vec![0];
+                let half = x / 2;
+                if half != 0 {
+                    candidates.push(half);
+                }
+                // Step one closer to zero.
+                let closer = if x > 0 { x - 1 } else { x + 1 };
+                if closer != 0 && closer != half {
+                    candidates.push(closer);
+                }
+                candidates
+            }
+        }
+    )*)
+}
+
+macro_rules! arbitrary_ints {
+    (unsigned: $($uty:ty)*; signed: $($ity:ty)*;) => {
+        arbitrary_int! {
+            $($uty => |g| g.below(g.size() as u64 + 1).min(<$uty>::MAX as u64) as $uty,)*
+            $($ity => |g| {
+                // Clamp so that long runs can't overflow narrow types.
+                let magnitude = g.below(g.size() as u64 + 1).min(<$ity>::MAX as u64) as $ity;
+                if g.next_u64() & 1 == 1 { -magnitude } else { magnitude }
+            },)*
+        }
+    };
+}
+
+arbitrary_ints! {
+    unsigned: u8 u16 u32 u64 usize;
+    signed: i8 i16 i32 i64 isize;
+}
+
+macro_rules! arbitrary_float {
+    ($($ty:ty)*) => ($(
+        impl Arbitrary for $ty {
+            fn arbitrary(g: &mut Gen) -> Self {
+                let int = i64::arbitrary(g) as $ty;
+                let fraction = (g.next_u64() >> 11) as $ty / (1u64 << 53) as $ty;
+                int + fraction
+            }
+
+            fn shrink(&self) -> Vec<Self> {
+                let x = *self;
+                if x == 0.0 || x.is_nan() {
+                    return Vec::new();
+                }
+                let mut candidates = alloc::vec![0.0];
+                let truncated = x as i64 as $ty;
+                if truncated != x {
+                    candidates.push(truncated);
+                }
+                candidates
+            }
+        }
+    )*)
+}
+
+arbitrary_float!(f32 f64);
+
+impl Arbitrary for char {
+    fn arbitrary(g: &mut Gen) -> Self {
+        // Mostly printable ASCII, with the occasional arbitrary code point.
+        if g.below(4) == 0 {
+            loop {
+                if let Some(c) = char::from_u32(g.below(0x11_0000) as u32) {
+                    return c;
+                }
+            }
+        }
+        (b' ' + g.below(95) as u8) as char
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        if *self == 'a' {
+            Vec::new()
+        } else {
+            alloc::vec!['a']
+        }
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Option<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        if g.below(4) == 0 {
+            None
+        } else {
+            Some(T::arbitrary(g))
+        }
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        match self {
+            None => Vec::new(),
+            Some(value) => core::iter::once(None)
+                .chain(value.shrink().into_iter().map(Some))
+                .collect(),
+        }
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Box<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Box::new(T::arbitrary(g))
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        (**self).shrink().into_iter().map(Box::new).collect()
+    }
+}
+
+impl<T: Arbitrary> Arbitrary for Vec<T> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let len = g.below(g.size as u64 + 1) as usize;
+        (0..len).map(|_| T::arbitrary(g)).collect()
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        let mut candidates = Vec::new();
+        if self.is_empty() {
+            return candidates;
+        }
+        candidates.push(Vec::new());
+        if self.len() > 1 {
+            candidates.push(self[..self.len() / 2].to_vec());
+            candidates.push(self[self.len() / 2..].to_vec());
+        }
+        for i in 0..self.len() {
+            let mut smaller = self.clone();
+            smaller.remove(i);
+            candidates.push(smaller);
+        }
+        for (i, item) in self.iter().enumerate() {
+            for shrunk in item.shrink() {
+                let mut smaller = self.clone();
+                smaller[i] = shrunk;
+                candidates.push(smaller);
+            }
+        }
+        candidates
+    }
+}
+
+impl Arbitrary for String {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Vec::<char>::arbitrary(g).into_iter().collect()
+    }
+
+    fn shrink(&self) -> Vec<Self> {
+        self.chars()
+            .collect::<Vec<_>>()
+            .shrink()
+            .into_iter()
+            .map(|chars| chars.into_iter().collect())
+            .collect()
+    }
+}
+
+macro_rules! arbitrary_tuple {
+    ($(($($name:ident $idx:tt)*))*) => ($(
+        impl<$($name: Arbitrary),*> Arbitrary for ($($name,)*) {
+            fn arbitrary(g: &mut Gen) -> Self {
+                ($($name::arbitrary(g),)*)
+            }
+
+            fn shrink(&self) -> Vec<Self> {
+                let mut candidates = Vec::new();
+                $(
+                    for shrunk in self.$idx.shrink() {
+                        let mut smaller = self.clone();
+                        smaller.$idx = shrunk;
+                        candidates.push(smaller);
+                    }
+                )*
+                candidates
+            }
+        }
+    )*)
+}
+
+arbitrary_tuple! {
+    (A 0)
+    (A 0 B 1)
+    (A 0 B 1 C 2)
+    (A 0 B 1 C 2 D 3)
+    (A 0 B 1 C 2 D 3 E 4)
+    (A 0 B 1 C 2 D 3 E 4 F 5)
+}
+
+/// Returns a fresh seed when none was passed with `--seed`.
+pub(super) fn random_seed() -> u64 {
+    let high = (js_sys::Math::random() * 4294967296.0) as u64;
+    let low = (js_sys::Math::random() * 4294967296.0) as u64;
+    (high << 32) | low
+}
+
+/// Runs a single case, returning the error (and the panic message, if any)
+/// on failure.
+fn run_case<T: Arbitrary, R: Termination>(
+    f: &impl Fn(T) -> R,
+    input: &T,
+) -> Result<(), (JsValue, String)> {
+    let mut result = None;
+    let mut func = || result = Some(f(input.clone()).into_js_result());
+    let closure = ScopedClosure::borrow_mut_assert_unwind_safe(&mut func);
+    let thrown = super::__wbg_test_invoke(&closure);
+    drop(closure);
+
+    let error = match (thrown, result) {
+        (Err(e), _) | (_, Some(Err(e))) => e,
+        (Ok(()), Some(Ok(()))) => return Ok(()),
+        (Ok(()), None) => wasm_bindgen::throw_str("invalid property case state"),
+    };
+    let panic = CURRENT_OUTPUT.with(|output| core::mem::take(&mut output.borrow_mut().panic));
+    Err((error, panic))
+}
+
+/// Runs `cases` randomly generated inputs through `f`, shrinking the first
+/// failing input to a minimal counterexample.
+pub(super) fn check<T: Arbitrary, R: Termination>(
+    seed: u64,
+    cases: u32,
+    f: impl Fn(T) -> R,
+) -> Result<(), JsValue> {
+    // Don't print every panic hit while searching and shrinking; only the
+    // final counterexample gets reported.
+    let should_panic = CURRENT_OUTPUT
+        .with(|output| core::mem::replace(&mut output.borrow_mut().should_panic, true));
+
+    let mut g = Gen::new(seed, 0);
+    let mut failure = None;
+    for case in 0..cases {
+        // Grow inputs over the course of the run, like QuickCheck does.
+        g.size = case as usize;
+        let input = T::arbitrary(&mut g);
+        if let Err(error) = run_case(&f, &input) {
+            failure = Some((case, input, error));
+            break;
+        }
+    }
+
+    let result = match failure {
+        None => Ok(()),
+        Some((case, mut input, mut error)) => {
+            let mut steps = 0;
+            'shrink: while steps < MAX_SHRINK_STEPS {
+                for candidate in input.shrink() {
+                    if let Err(candidate_error) = run_case(&f, &candidate) {
+                        input = candidate;
+                        error = candidate_error;
+                        steps += 1;
+                        continue 'shrink;
+                    }
+                }
+                break;
+            }
+
+            let (error, panic) = error;
+            let mut message = format!(
+                "property failed after {case} passing case(s)\n\
+                 seed: {seed}\n\
+                 minimal input ({steps} shrink step(s)): {input:?}\n"
+            );
+            if !panic.is_empty() {
+                message.push_str(&format!("panic: {panic}\n"));
+            } else {
+                message.push_str(&format!("error: {}\n", super::stringify(&error)));
+            }
+            message.push_str(&format!("rerun with `--seed {seed}` to reproduce"));
+            Err(JsError::new(&message).into())
+        }
+    };
+
+    CURRENT_OUTPUT.with(|output| output.borrow_mut().should_panic = should_panic);
+    result
+}
@@ -1,7 +1,6 @@
-mod instant;
 mod js;
 mod system_time;
 
-pub use instant::Instant;
 pub use system_time::SystemTime;
+pub use wasm_bindgen_futures::time::Instant;
 pub const UNIX_EPOCH: SystemTime = SystemTime::UNIX_EPOCH;
@@ -115,6 +115,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Animation`*"]
     pub fn pending(this: &Animation) -> bool;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (structural , catch , method , getter , js_class = "Animation" , js_name = ready)]
     #[doc = "Getter for the `ready` field of this object."]
     #[doc = ""]
@@ -122,6 +123,18 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Animation`*"]
     pub fn ready(this: &Animation) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (structural , catch , method , getter , js_class = "Animation" , js_name = ready)]
+    #[doc = "Getter for the `ready` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Animation/ready)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Animation`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn ready(this: &Animation) -> Result<::js_sys::Promise<Animation>, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (structural , catch , method , getter , js_class = "Animation" , js_name = finished)]
     #[doc = "Getter for the `finished` field of this object."]
     #[doc = ""]
@@ -129,6 +142,17 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Animation`*"]
     pub fn finished(this: &Animation) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (structural , catch , method , getter , js_class = "Animation" , js_name = finished)]
+    #[doc = "Getter for the `finished` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Animation/finished)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Animation`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn finished(this: &Animation) -> Result<::js_sys::Promise<Animation>, JsValue>;
     # [wasm_bindgen (structural , method , getter , js_class = "Animation" , js_name = onfinish)]
     #[doc = "Getter for the `onfinish` field of this object."]
     #[doc = ""]
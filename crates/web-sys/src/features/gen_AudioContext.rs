@@ -122,6 +122,7 @@ extern "C" {
     pub fn new_with_context_options(
         context_options: &AudioContextOptions,
     ) -> Result<AudioContext, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = close)]
     #[doc = "The `close()` method."]
     #[doc = ""]
@@ -190,6 +191,7 @@ extern "C" {
         this: &AudioContext,
         sink_id: &AudioSinkOptions,
     ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = suspend)]
     #[doc = "The `suspend()` method."]
     #[doc = ""]
@@ -513,6 +515,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `AudioContext`, `WaveShaperNode`*"]
     pub fn create_wave_shaper(this: &AudioContext) -> Result<WaveShaperNode, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = decodeAudioData)]
     #[doc = "The `decodeAudioData()` method."]
     #[doc = ""]
@@ -523,6 +526,7 @@ extern "C" {
         this: &AudioContext,
         audio_data: &::js_sys::ArrayBuffer,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = decodeAudioData)]
     #[doc = "The `decodeAudioData()` method."]
     #[doc = ""]
@@ -534,6 +538,7 @@ extern "C" {
         audio_data: &::js_sys::ArrayBuffer,
         success_callback: &::js_sys::Function,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = decodeAudioData)]
     #[doc = "The `decodeAudioData()` method."]
     #[doc = ""]
@@ -546,6 +551,7 @@ extern "C" {
         success_callback: &::js_sys::Function,
         error_callback: &::js_sys::Function,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = resume)]
     #[doc = "The `resume()` method."]
     #[doc = ""]
@@ -553,4 +559,85 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `AudioContext`*"]
     pub fn resume(this: &AudioContext) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = close)]
+    #[doc = "The `close()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/AudioContext/close)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `AudioContext`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn close(this: &AudioContext) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = suspend)]
+    #[doc = "The `suspend()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/AudioContext/suspend)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `AudioContext`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn suspend(this: &AudioContext) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "AudioBuffer")]
+    # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = decodeAudioData)]
+    #[doc = "The `decodeAudioData()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/AudioContext/decodeAudioData)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `AudioBuffer`, `AudioContext`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn decode_audio_data(
+        this: &AudioContext,
+        audio_data: &::js_sys::ArrayBuffer,
+    ) -> Result<::js_sys::Promise<AudioBuffer>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "AudioBuffer")]
+    # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = decodeAudioData)]
+    #[doc = "The `decodeAudioData()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/AudioContext/decodeAudioData)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `AudioBuffer`, `AudioContext`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn decode_audio_data_with_success_callback(
+        this: &AudioContext,
+        audio_data: &::js_sys::ArrayBuffer,
+        success_callback: &::js_sys::Function,
+    ) -> Result<::js_sys::Promise<AudioBuffer>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "AudioBuffer")]
+    # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = decodeAudioData)]
+    #[doc = "The `decodeAudioData()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/AudioContext/decodeAudioData)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `AudioBuffer`, `AudioContext`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn decode_audio_data_with_success_callback_and_error_callback(
+        this: &AudioContext,
+        audio_data: &::js_sys::ArrayBuffer,
+        success_callback: &::js_sys::Function,
+        error_callback: &::js_sys::Function,
+    ) -> Result<::js_sys::Promise<AudioBuffer>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "AudioContext" , js_name = resume)]
+    #[doc = "The `resume()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/AudioContext/resume)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `AudioContext`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn resume(this: &AudioContext) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
 }
@@ -129,6 +129,7 @@ extern "C" {
         blob_parts: &::wasm_bindgen::JsValue,
         options: &BlobPropertyBag,
     ) -> Result<Blob, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Blob" , js_name = arrayBuffer)]
     #[doc = "The `arrayBuffer()` method."]
     #[doc = ""]
@@ -136,6 +137,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Blob`*"]
     pub fn array_buffer(this: &Blob) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Blob" , js_name = bytes)]
     #[doc = "The `bytes()` method."]
     #[doc = ""]
@@ -248,6 +250,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Blob`, `ReadableStream`*"]
     pub fn stream(this: &Blob) -> ReadableStream;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Blob" , js_name = text)]
     #[doc = "The `text()` method."]
     #[doc = ""]
@@ -255,4 +258,37 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Blob`*"]
     pub fn text(this: &Blob) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Blob" , js_name = arrayBuffer)]
+    #[doc = "The `arrayBuffer()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Blob/arrayBuffer)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Blob`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn array_buffer(this: &Blob) -> ::js_sys::Promise<::js_sys::ArrayBuffer>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Blob" , js_name = bytes)]
+    #[doc = "The `bytes()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Blob/bytes)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Blob`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn bytes(this: &Blob) -> ::js_sys::Promise<::alloc::vec::Vec<u8>>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Blob" , js_name = text)]
+    #[doc = "The `text()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Blob/text)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Blob`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn text(this: &Blob) -> ::js_sys::Promise<::js_sys::JsString>;
 }
@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
     pub type Cache;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "Request")]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = add)]
     #[doc = "The `add()` method."]
@@ -20,6 +21,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`*"]
     pub fn add_with_request(this: &Cache, request: &Request) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = add)]
     #[doc = "The `add()` method."]
     #[doc = ""]
@@ -27,6 +29,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
     pub fn add_with_str(this: &Cache, request: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = addAll)]
     #[doc = "The `addAll()` method."]
     #[doc = ""]
@@ -37,6 +40,7 @@ extern "C" {
         this: &Cache,
         requests: &::wasm_bindgen::JsValue,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = addAll)]
     #[doc = "The `addAll()` method."]
     #[doc = ""]
@@ -47,6 +51,7 @@ extern "C" {
         this: &Cache,
         requests: &::wasm_bindgen::JsValue,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "Request")]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = delete)]
     #[doc = "The `delete()` method."]
@@ -55,6 +60,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`*"]
     pub fn delete_with_request(this: &Cache, request: &Request) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = delete)]
     #[doc = "The `delete()` method."]
     #[doc = ""]
@@ -62,6 +68,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
     pub fn delete_with_str(this: &Cache, request: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(all(feature = "CacheQueryOptions", feature = "Request",))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = delete)]
     #[doc = "The `delete()` method."]
@@ -74,6 +81,7 @@ extern "C" {
         request: &Request,
         options: &CacheQueryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "CacheQueryOptions")]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = delete)]
     #[doc = "The `delete()` method."]
@@ -86,6 +94,7 @@ extern "C" {
         request: &str,
         options: &CacheQueryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = keys)]
     #[doc = "The `keys()` method."]
     #[doc = ""]
@@ -93,6 +102,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
     pub fn keys(this: &Cache) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "Request")]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = keys)]
     #[doc = "The `keys()` method."]
@@ -101,6 +111,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`*"]
     pub fn keys_with_request(this: &Cache, request: &Request) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = keys)]
     #[doc = "The `keys()` method."]
     #[doc = ""]
@@ -108,6 +119,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
     pub fn keys_with_str(this: &Cache, request: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(all(feature = "CacheQueryOptions", feature = "Request",))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = keys)]
     #[doc = "The `keys()` method."]
@@ -120,6 +132,7 @@ extern "C" {
         request: &Request,
         options: &CacheQueryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "CacheQueryOptions")]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = keys)]
     #[doc = "The `keys()` method."]
@@ -132,6 +145,7 @@ extern "C" {
         request: &str,
         options: &CacheQueryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "Request")]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = match)]
     #[doc = "The `match()` method."]
@@ -140,6 +154,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`*"]
     pub fn match_with_request(this: &Cache, request: &Request) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = match)]
     #[doc = "The `match()` method."]
     #[doc = ""]
@@ -147,6 +162,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
     pub fn match_with_str(this: &Cache, request: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(all(feature = "CacheQueryOptions", feature = "Request",))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = match)]
     #[doc = "The `match()` method."]
@@ -159,6 +175,7 @@ extern "C" {
         request: &Request,
         options: &CacheQueryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "CacheQueryOptions")]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = match)]
     #[doc = "The `match()` method."]
@@ -171,6 +188,7 @@ extern "C" {
         request: &str,
         options: &CacheQueryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = matchAll)]
     #[doc = "The `matchAll()` method."]
     #[doc = ""]
@@ -178,6 +196,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
     pub fn match_all(this: &Cache) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "Request")]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = matchAll)]
     #[doc = "The `matchAll()` method."]
@@ -186,6 +205,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`*"]
     pub fn match_all_with_request(this: &Cache, request: &Request) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = matchAll)]
     #[doc = "The `matchAll()` method."]
     #[doc = ""]
@@ -193,6 +213,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
     pub fn match_all_with_str(this: &Cache, request: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(all(feature = "CacheQueryOptions", feature = "Request",))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = matchAll)]
     #[doc = "The `matchAll()` method."]
@@ -205,6 +226,7 @@ extern "C" {
         request: &Request,
         options: &CacheQueryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "CacheQueryOptions")]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = matchAll)]
     #[doc = "The `matchAll()` method."]
@@ -217,6 +239,7 @@ extern "C" {
         request: &str,
         options: &CacheQueryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(all(feature = "Request", feature = "Response",))]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = put)]
     #[doc = "The `put()` method."]
@@ -229,6 +252,7 @@ extern "C" {
         request: &Request,
         response: &Response,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "Response")]
     # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = put)]
     #[doc = "The `put()` method."]
@@ -237,4 +261,360 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Cache`, `Response`*"]
     pub fn put_with_str(this: &Cache, request: &str, response: &Response) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Request")]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = add)]
+    #[doc = "The `add()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/add)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn add_with_request(
+        this: &Cache,
+        request: &Request,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = add)]
+    #[doc = "The `add()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/add)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn add_with_str(this: &Cache, request: &str) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = addAll)]
+    #[doc = "The `addAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/addAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn add_all_with_request_sequence(
+        this: &Cache,
+        requests: &::wasm_bindgen::JsValue,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = addAll)]
+    #[doc = "The `addAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/addAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn add_all_with_str_sequence(
+        this: &Cache,
+        requests: &::wasm_bindgen::JsValue,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Request")]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = delete)]
+    #[doc = "The `delete()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/delete)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn delete_with_request(
+        this: &Cache,
+        request: &Request,
+    ) -> ::js_sys::Promise<::js_sys::Boolean>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = delete)]
+    #[doc = "The `delete()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/delete)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn delete_with_str(this: &Cache, request: &str) -> ::js_sys::Promise<::js_sys::Boolean>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "CacheQueryOptions", feature = "Request",))]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = delete)]
+    #[doc = "The `delete()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/delete)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `CacheQueryOptions`, `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn delete_with_request_and_options(
+        this: &Cache,
+        request: &Request,
+        options: &CacheQueryOptions,
+    ) -> ::js_sys::Promise<::js_sys::Boolean>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "CacheQueryOptions")]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = delete)]
+    #[doc = "The `delete()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/delete)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `CacheQueryOptions`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn delete_with_str_and_options(
+        this: &Cache,
+        request: &str,
+        options: &CacheQueryOptions,
+    ) -> ::js_sys::Promise<::js_sys::Boolean>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Request")]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = keys)]
+    #[doc = "The `keys()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/keys)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn keys(this: &Cache) -> ::js_sys::Promise<::js_sys::Array<Request>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Request")]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = keys)]
+    #[doc = "The `keys()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/keys)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn keys_with_request(
+        this: &Cache,
+        request: &Request,
+    ) -> ::js_sys::Promise<::js_sys::Array<Request>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Request")]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = keys)]
+    #[doc = "The `keys()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/keys)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn keys_with_str(
+        this: &Cache,
+        request: &str,
+    ) -> ::js_sys::Promise<::js_sys::Array<Request>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "CacheQueryOptions", feature = "Request",))]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = keys)]
+    #[doc = "The `keys()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/keys)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `CacheQueryOptions`, `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn keys_with_request_and_options(
+        this: &Cache,
+        request: &Request,
+        options: &CacheQueryOptions,
+    ) -> ::js_sys::Promise<::js_sys::Array<Request>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "CacheQueryOptions", feature = "Request",))]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = keys)]
+    #[doc = "The `keys()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/keys)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `CacheQueryOptions`, `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn keys_with_str_and_options(
+        this: &Cache,
+        request: &str,
+        options: &CacheQueryOptions,
+    ) -> ::js_sys::Promise<::js_sys::Array<Request>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "Request", feature = "Response",))]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = match)]
+    #[doc = "The `match()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/match)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_with_request(this: &Cache, request: &Request) -> ::js_sys::Promise<Response>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Response")]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = match)]
+    #[doc = "The `match()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/match)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_with_str(this: &Cache, request: &str) -> ::js_sys::Promise<Response>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(
+        feature = "CacheQueryOptions",
+        feature = "Request",
+        feature = "Response",
+    ))]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = match)]
+    #[doc = "The `match()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/match)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `CacheQueryOptions`, `Request`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_with_request_and_options(
+        this: &Cache,
+        request: &Request,
+        options: &CacheQueryOptions,
+    ) -> ::js_sys::Promise<Response>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "CacheQueryOptions", feature = "Response",))]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = match)]
+    #[doc = "The `match()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/match)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `CacheQueryOptions`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_with_str_and_options(
+        this: &Cache,
+        request: &str,
+        options: &CacheQueryOptions,
+    ) -> ::js_sys::Promise<Response>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Response")]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = matchAll)]
+    #[doc = "The `matchAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/matchAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_all(this: &Cache) -> ::js_sys::Promise<::js_sys::Array<Response>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "Request", feature = "Response",))]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = matchAll)]
+    #[doc = "The `matchAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/matchAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_all_with_request(
+        this: &Cache,
+        request: &Request,
+    ) -> ::js_sys::Promise<::js_sys::Array<Response>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Response")]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = matchAll)]
+    #[doc = "The `matchAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/matchAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_all_with_str(
+        this: &Cache,
+        request: &str,
+    ) -> ::js_sys::Promise<::js_sys::Array<Response>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(
+        feature = "CacheQueryOptions",
+        feature = "Request",
+        feature = "Response",
+    ))]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = matchAll)]
+    #[doc = "The `matchAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/matchAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `CacheQueryOptions`, `Request`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_all_with_request_and_options(
+        this: &Cache,
+        request: &Request,
+        options: &CacheQueryOptions,
+    ) -> ::js_sys::Promise<::js_sys::Array<Response>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "CacheQueryOptions", feature = "Response",))]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = matchAll)]
+    #[doc = "The `matchAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/matchAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `CacheQueryOptions`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_all_with_str_and_options(
+        this: &Cache,
+        request: &str,
+        options: &CacheQueryOptions,
+    ) -> ::js_sys::Promise<::js_sys::Array<Response>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "Request", feature = "Response",))]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = put)]
+    #[doc = "The `put()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/put)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Request`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn put_with_request(
+        this: &Cache,
+        request: &Request,
+        response: &Response,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Response")]
+    # [wasm_bindgen (method , structural , js_class = "Cache" , js_name = put)]
+    #[doc = "The `put()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Cache/put)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn put_with_str(
+        this: &Cache,
+        request: &str,
+        response: &Response,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CacheStorage`*"]
     pub type CacheStorage;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = delete)]
     #[doc = "The `delete()` method."]
     #[doc = ""]
@@ -19,6 +20,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CacheStorage`*"]
     pub fn delete(this: &CacheStorage, cache_name: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = has)]
     #[doc = "The `has()` method."]
     #[doc = ""]
@@ -26,6 +28,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CacheStorage`*"]
     pub fn has(this: &CacheStorage, cache_name: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = keys)]
     #[doc = "The `keys()` method."]
     #[doc = ""]
@@ -33,6 +36,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CacheStorage`*"]
     pub fn keys(this: &CacheStorage) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "Request")]
     # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = match)]
     #[doc = "The `match()` method."]
@@ -41,6 +45,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CacheStorage`, `Request`*"]
     pub fn match_with_request(this: &CacheStorage, request: &Request) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = match)]
     #[doc = "The `match()` method."]
     #[doc = ""]
@@ -48,6 +53,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CacheStorage`*"]
     pub fn match_with_str(this: &CacheStorage, request: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(all(feature = "CacheQueryOptions", feature = "Request",))]
     # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = match)]
     #[doc = "The `match()` method."]
@@ -60,6 +66,7 @@ extern "C" {
         request: &Request,
         options: &CacheQueryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "CacheQueryOptions")]
     # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = match)]
     #[doc = "The `match()` method."]
@@ -72,6 +79,7 @@ extern "C" {
         request: &str,
         options: &CacheQueryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = open)]
     #[doc = "The `open()` method."]
     #[doc = ""]
@@ -79,4 +87,112 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CacheStorage`*"]
     pub fn open(this: &CacheStorage, cache_name: &str) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = delete)]
+    #[doc = "The `delete()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/delete)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CacheStorage`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn delete(this: &CacheStorage, cache_name: &str) -> ::js_sys::Promise<::js_sys::Boolean>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = has)]
+    #[doc = "The `has()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/has)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CacheStorage`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn has(this: &CacheStorage, cache_name: &str) -> ::js_sys::Promise<::js_sys::Boolean>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = keys)]
+    #[doc = "The `keys()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/keys)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CacheStorage`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn keys(this: &CacheStorage) -> ::js_sys::Promise<::js_sys::Array<::js_sys::JsString>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "Request", feature = "Response",))]
+    # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = match)]
+    #[doc = "The `match()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/match)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CacheStorage`, `Request`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_with_request(
+        this: &CacheStorage,
+        request: &Request,
+    ) -> ::js_sys::Promise<Response>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Response")]
+    # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = match)]
+    #[doc = "The `match()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/match)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CacheStorage`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_with_str(this: &CacheStorage, request: &str) -> ::js_sys::Promise<Response>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(
+        feature = "CacheQueryOptions",
+        feature = "Request",
+        feature = "Response",
+    ))]
+    # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = match)]
+    #[doc = "The `match()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/match)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CacheQueryOptions`, `CacheStorage`, `Request`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_with_request_and_options(
+        this: &CacheStorage,
+        request: &Request,
+        options: &CacheQueryOptions,
+    ) -> ::js_sys::Promise<Response>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "CacheQueryOptions", feature = "Response",))]
+    # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = match)]
+    #[doc = "The `match()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/match)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CacheQueryOptions`, `CacheStorage`, `Response`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_with_str_and_options(
+        this: &CacheStorage,
+        request: &str,
+        options: &CacheQueryOptions,
+    ) -> ::js_sys::Promise<Response>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Cache")]
+    # [wasm_bindgen (method , structural , js_class = "CacheStorage" , js_name = open)]
+    #[doc = "The `open()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CacheStorage/open)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Cache`, `CacheStorage`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn open(this: &CacheStorage, cache_name: &str) -> ::js_sys::Promise<Cache>;
 }
@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Clients`*"]
     pub type Clients;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Clients" , js_name = claim)]
     #[doc = "The `claim()` method."]
     #[doc = ""]
@@ -26,6 +27,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Clients`*"]
     pub fn get(this: &Clients, id: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Clients" , js_name = matchAll)]
     #[doc = "The `matchAll()` method."]
     #[doc = ""]
@@ -33,6 +35,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Clients`*"]
     pub fn match_all(this: &Clients) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "ClientQueryOptions")]
     # [wasm_bindgen (method , structural , js_class = "Clients" , js_name = matchAll)]
     #[doc = "The `matchAll()` method."]
@@ -44,6 +47,7 @@ extern "C" {
         this: &Clients,
         options: &ClientQueryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Clients" , js_name = openWindow)]
     #[doc = "The `openWindow()` method."]
     #[doc = ""]
@@ -51,4 +55,57 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Clients`*"]
     pub fn open_window(this: &Clients, url: &str) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Clients" , js_name = claim)]
+    #[doc = "The `claim()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Clients/claim)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Clients`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn claim(this: &Clients) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Client")]
+    # [wasm_bindgen (method , structural , js_class = "Clients" , js_name = matchAll)]
+    #[doc = "The `matchAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Clients/matchAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Client`, `Clients`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_all(this: &Clients) -> ::js_sys::Promise<::js_sys::Array<Client>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "Client", feature = "ClientQueryOptions",))]
+    # [wasm_bindgen (method , structural , js_class = "Clients" , js_name = matchAll)]
+    #[doc = "The `matchAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Clients/matchAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Client`, `ClientQueryOptions`, `Clients`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn match_all_with_options(
+        this: &Clients,
+        options: &ClientQueryOptions,
+    ) -> ::js_sys::Promise<::js_sys::Array<Client>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "WindowClient")]
+    # [wasm_bindgen (method , structural , js_class = "Clients" , js_name = openWindow)]
+    #[doc = "The `openWindow()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Clients/openWindow)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Clients`, `WindowClient`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn open_window(
+        this: &Clients,
+        url: &str,
+    ) -> ::js_sys::Promise<::js_sys::JsOption<WindowClient>>;
 }
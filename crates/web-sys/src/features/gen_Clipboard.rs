@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Clipboard`*"]
     pub type Clipboard;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Clipboard" , js_name = read)]
     #[doc = "The `read()` method."]
     #[doc = ""]
@@ -34,6 +35,7 @@ extern "C" {
         this: &Clipboard,
         formats: &ClipboardUnsanitizedFormats,
     ) -> ::js_sys::Promise<::js_sys::Array<ClipboardItem>>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Clipboard" , js_name = readText)]
     #[doc = "The `readText()` method."]
     #[doc = ""]
@@ -41,6 +43,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Clipboard`*"]
     pub fn read_text(this: &Clipboard) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Clipboard" , js_name = write)]
     #[doc = "The `write()` method."]
     #[doc = ""]
@@ -48,6 +51,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Clipboard`*"]
     pub fn write(this: &Clipboard, data: &::wasm_bindgen::JsValue) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Clipboard" , js_name = writeText)]
     #[doc = "The `writeText()` method."]
     #[doc = ""]
@@ -55,4 +59,52 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Clipboard`*"]
     pub fn write_text(this: &Clipboard, data: &str) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "ClipboardItem")]
+    # [wasm_bindgen (method , structural , js_class = "Clipboard" , js_name = read)]
+    #[doc = "The `read()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/read)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Clipboard`, `ClipboardItem`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn read(this: &Clipboard) -> ::js_sys::Promise<::js_sys::Array<ClipboardItem>>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Clipboard" , js_name = readText)]
+    #[doc = "The `readText()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/readText)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Clipboard`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn read_text(this: &Clipboard) -> ::js_sys::Promise<::js_sys::JsString>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Clipboard" , js_name = write)]
+    #[doc = "The `write()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/write)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Clipboard`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn write(
+        this: &Clipboard,
+        data: &::wasm_bindgen::JsValue,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Clipboard" , js_name = writeText)]
+    #[doc = "The `writeText()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Clipboard/writeText)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Clipboard`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn write_text(this: &Clipboard, data: &str) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
@@ -67,6 +67,7 @@ extern "C" {
         items: &::js_sys::Object,
         options: &ClipboardItemOptions,
     ) -> Result<ClipboardItem, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "ClipboardItem" , js_name = getType)]
     #[doc = "The `getType()` method."]
     #[doc = ""]
@@ -81,4 +82,16 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ClipboardItem`*"]
     pub fn supports(type_: &str) -> bool;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Blob")]
+    # [wasm_bindgen (method , structural , js_class = "ClipboardItem" , js_name = getType)]
+    #[doc = "The `getType()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ClipboardItem/getType)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Blob`, `ClipboardItem`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_type(this: &ClipboardItem, type_: &str) -> ::js_sys::Promise<Blob>;
 }
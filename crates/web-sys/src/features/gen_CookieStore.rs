@@ -26,6 +26,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CookieStore`*"]
     pub fn set_onchange(this: &CookieStore, value: Option<&::js_sys::Function>);
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = delete)]
     #[doc = "The `delete()` method."]
     #[doc = ""]
@@ -33,6 +34,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CookieStore`*"]
     pub fn delete_with_name(this: &CookieStore, name: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "CookieStoreDeleteOptions")]
     # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = delete)]
     #[doc = "The `delete()` method."]
@@ -44,6 +46,7 @@ extern "C" {
         this: &CookieStore,
         options: &CookieStoreDeleteOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = get)]
     #[doc = "The `get()` method."]
     #[doc = ""]
@@ -51,6 +54,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CookieStore`*"]
     pub fn get_with_name(this: &CookieStore, name: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = get)]
     #[doc = "The `get()` method."]
     #[doc = ""]
@@ -58,6 +62,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CookieStore`*"]
     pub fn get(this: &CookieStore) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "CookieStoreGetOptions")]
     # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = get)]
     #[doc = "The `get()` method."]
@@ -69,6 +74,7 @@ extern "C" {
         this: &CookieStore,
         options: &CookieStoreGetOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = getAll)]
     #[doc = "The `getAll()` method."]
     #[doc = ""]
@@ -76,6 +82,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CookieStore`*"]
     pub fn get_all_with_name(this: &CookieStore, name: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = getAll)]
     #[doc = "The `getAll()` method."]
     #[doc = ""]
@@ -83,6 +90,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CookieStore`*"]
     pub fn get_all(this: &CookieStore) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "CookieStoreGetOptions")]
     # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = getAll)]
     #[doc = "The `getAll()` method."]
@@ -94,6 +102,7 @@ extern "C" {
         this: &CookieStore,
         options: &CookieStoreGetOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = set)]
     #[doc = "The `set()` method."]
     #[doc = ""]
@@ -105,6 +114,7 @@ extern "C" {
         name: &str,
         value: &str,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "CookieInit")]
     # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = set)]
     #[doc = "The `set()` method."]
@@ -113,4 +123,147 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CookieInit`, `CookieStore`*"]
     pub fn set_with_options(this: &CookieStore, options: &CookieInit) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = delete)]
+    #[doc = "The `delete()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStore/delete)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieStore`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn delete_with_name(
+        this: &CookieStore,
+        name: &str,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "CookieStoreDeleteOptions")]
+    # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = delete)]
+    #[doc = "The `delete()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStore/delete)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieStore`, `CookieStoreDeleteOptions`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn delete_with_options(
+        this: &CookieStore,
+        options: &CookieStoreDeleteOptions,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "CookieListItem")]
+    # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = get)]
+    #[doc = "The `get()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStore/get)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieListItem`, `CookieStore`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_with_name(
+        this: &CookieStore,
+        name: &str,
+    ) -> ::js_sys::Promise<::js_sys::JsOption<CookieListItem>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "CookieListItem")]
+    # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = get)]
+    #[doc = "The `get()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStore/get)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieListItem`, `CookieStore`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get(this: &CookieStore) -> ::js_sys::Promise<::js_sys::JsOption<CookieListItem>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "CookieListItem", feature = "CookieStoreGetOptions",))]
+    # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = get)]
+    #[doc = "The `get()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStore/get)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieListItem`, `CookieStore`, `CookieStoreGetOptions`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_with_cookie_store_get_options(
+        this: &CookieStore,
+        options: &CookieStoreGetOptions,
+    ) -> ::js_sys::Promise<::js_sys::JsOption<CookieListItem>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "CookieListItem")]
+    # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = getAll)]
+    #[doc = "The `getAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStore/getAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieListItem`, `CookieStore`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_all_with_name(
+        this: &CookieStore,
+        name: &str,
+    ) -> ::js_sys::Promise<::js_sys::Array<CookieListItem>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "CookieListItem")]
+    # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = getAll)]
+    #[doc = "The `getAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStore/getAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieListItem`, `CookieStore`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_all(this: &CookieStore) -> ::js_sys::Promise<::js_sys::Array<CookieListItem>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "CookieListItem", feature = "CookieStoreGetOptions",))]
+    # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = getAll)]
+    #[doc = "The `getAll()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStore/getAll)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieListItem`, `CookieStore`, `CookieStoreGetOptions`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_all_with_cookie_store_get_options(
+        this: &CookieStore,
+        options: &CookieStoreGetOptions,
+    ) -> ::js_sys::Promise<::js_sys::Array<CookieListItem>>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = set)]
+    #[doc = "The `set()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStore/set)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieStore`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_with_name_and_value(
+        this: &CookieStore,
+        name: &str,
+        value: &str,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "CookieInit")]
+    # [wasm_bindgen (method , structural , js_class = "CookieStore" , js_name = set)]
+    #[doc = "The `set()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStore/set)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieInit`, `CookieStore`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_with_options(
+        this: &CookieStore,
+        options: &CookieInit,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CookieStoreManager`*"]
     pub type CookieStoreManager;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CookieStoreManager" , js_name = getSubscriptions)]
     #[doc = "The `getSubscriptions()` method."]
     #[doc = ""]
@@ -19,6 +20,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CookieStoreManager`*"]
     pub fn get_subscriptions(this: &CookieStoreManager) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CookieStoreManager" , js_name = subscribe)]
     #[doc = "The `subscribe()` method."]
     #[doc = ""]
@@ -29,6 +31,7 @@ extern "C" {
         this: &CookieStoreManager,
         subscriptions: &::wasm_bindgen::JsValue,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CookieStoreManager" , js_name = unsubscribe)]
     #[doc = "The `unsubscribe()` method."]
     #[doc = ""]
@@ -39,4 +42,46 @@ extern "C" {
         this: &CookieStoreManager,
         subscriptions: &::wasm_bindgen::JsValue,
     ) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "CookieStoreGetOptions")]
+    # [wasm_bindgen (method , structural , js_class = "CookieStoreManager" , js_name = getSubscriptions)]
+    #[doc = "The `getSubscriptions()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStoreManager/getSubscriptions)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieStoreGetOptions`, `CookieStoreManager`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_subscriptions(
+        this: &CookieStoreManager,
+    ) -> ::js_sys::Promise<::js_sys::Array<CookieStoreGetOptions>>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "CookieStoreManager" , js_name = subscribe)]
+    #[doc = "The `subscribe()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStoreManager/subscribe)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieStoreManager`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn subscribe(
+        this: &CookieStoreManager,
+        subscriptions: &::wasm_bindgen::JsValue,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "CookieStoreManager" , js_name = unsubscribe)]
+    #[doc = "The `unsubscribe()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CookieStoreManager/unsubscribe)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CookieStoreManager`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn unsubscribe(
+        this: &CookieStoreManager,
+        subscriptions: &::wasm_bindgen::JsValue,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CredentialsContainer`*"]
     pub type CredentialsContainer;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = create)]
     #[doc = "The `create()` method."]
     #[doc = ""]
@@ -19,6 +20,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CredentialsContainer`*"]
     pub fn create(this: &CredentialsContainer) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "CredentialCreationOptions")]
     # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = create)]
     #[doc = "The `create()` method."]
@@ -30,6 +32,7 @@ extern "C" {
         this: &CredentialsContainer,
         options: &CredentialCreationOptions,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = get)]
     #[doc = "The `get()` method."]
     #[doc = ""]
@@ -37,6 +40,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CredentialsContainer`*"]
     pub fn get(this: &CredentialsContainer) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "CredentialRequestOptions")]
     # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = get)]
     #[doc = "The `get()` method."]
@@ -48,6 +52,7 @@ extern "C" {
         this: &CredentialsContainer,
         options: &CredentialRequestOptions,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = preventSilentAccess)]
     #[doc = "The `preventSilentAccess()` method."]
     #[doc = ""]
@@ -56,6 +61,7 @@ extern "C" {
     #[doc = "*This API requires the following crate features to be activated: `CredentialsContainer`*"]
     pub fn prevent_silent_access(this: &CredentialsContainer)
         -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "Credential")]
     # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = store)]
     #[doc = "The `store()` method."]
@@ -67,4 +73,90 @@ extern "C" {
         this: &CredentialsContainer,
         credential: &Credential,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Credential")]
+    # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = create)]
+    #[doc = "The `create()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CredentialsContainer/create)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Credential`, `CredentialsContainer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create(
+        this: &CredentialsContainer,
+    ) -> Result<::js_sys::Promise<::js_sys::JsOption<Credential>>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "Credential", feature = "CredentialCreationOptions",))]
+    # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = create)]
+    #[doc = "The `create()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CredentialsContainer/create)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Credential`, `CredentialCreationOptions`, `CredentialsContainer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_with_options(
+        this: &CredentialsContainer,
+        options: &CredentialCreationOptions,
+    ) -> Result<::js_sys::Promise<::js_sys::JsOption<Credential>>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Credential")]
+    # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = get)]
+    #[doc = "The `get()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CredentialsContainer/get)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Credential`, `CredentialsContainer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get(
+        this: &CredentialsContainer,
+    ) -> Result<::js_sys::Promise<::js_sys::JsOption<Credential>>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "Credential", feature = "CredentialRequestOptions",))]
+    # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = get)]
+    #[doc = "The `get()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CredentialsContainer/get)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Credential`, `CredentialRequestOptions`, `CredentialsContainer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_with_options(
+        this: &CredentialsContainer,
+        options: &CredentialRequestOptions,
+    ) -> Result<::js_sys::Promise<::js_sys::JsOption<Credential>>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = preventSilentAccess)]
+    #[doc = "The `preventSilentAccess()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CredentialsContainer/preventSilentAccess)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CredentialsContainer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn prevent_silent_access(
+        this: &CredentialsContainer,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Credential")]
+    # [wasm_bindgen (catch , method , structural , js_class = "CredentialsContainer" , js_name = store)]
+    #[doc = "The `store()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CredentialsContainer/store)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Credential`, `CredentialsContainer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn store(
+        this: &CredentialsContainer,
+        credential: &Credential,
+    ) -> Result<::js_sys::Promise<Credential>, JsValue>;
 }
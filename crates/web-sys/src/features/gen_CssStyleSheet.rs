@@ -60,6 +60,7 @@ extern "C" {
         rule: &str,
         index: u32,
     ) -> Result<u32, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "CSSStyleSheet" , js_name = replace)]
     #[doc = "The `replace()` method."]
     #[doc = ""]
@@ -74,4 +75,15 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CssStyleSheet`*"]
     pub fn replace_sync(this: &CssStyleSheet, text: &str) -> Result<(), JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "CSSStyleSheet" , js_name = replace)]
+    #[doc = "The `replace()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CSSStyleSheet/replace)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CssStyleSheet`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn replace(this: &CssStyleSheet, text: &str) -> ::js_sys::Promise<CssStyleSheet>;
 }
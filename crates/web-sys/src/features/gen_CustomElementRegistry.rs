@@ -51,6 +51,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `CustomElementRegistry`, `Node`*"]
     pub fn upgrade(this: &CustomElementRegistry, root: &Node);
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "CustomElementRegistry" , js_name = whenDefined)]
     #[doc = "The `whenDefined()` method."]
     #[doc = ""]
@@ -61,4 +62,18 @@ extern "C" {
         this: &CustomElementRegistry,
         name: &str,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "CustomElementRegistry" , js_name = whenDefined)]
+    #[doc = "The `whenDefined()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/CustomElementRegistry/whenDefined)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `CustomElementRegistry`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn when_defined(
+        this: &CustomElementRegistry,
+        name: &str,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
 }
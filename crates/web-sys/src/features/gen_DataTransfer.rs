@@ -91,6 +91,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `DataTransfer`*"]
     pub fn get_data(this: &DataTransfer, format: &str) -> Result<::alloc::string::String, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "DataTransfer" , js_name = getFiles)]
     #[doc = "The `getFiles()` method."]
     #[doc = ""]
@@ -98,6 +99,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `DataTransfer`*"]
     pub fn get_files(this: &DataTransfer) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "DataTransfer" , js_name = getFiles)]
     #[doc = "The `getFiles()` method."]
     #[doc = ""]
@@ -108,6 +110,7 @@ extern "C" {
         this: &DataTransfer,
         recursive_flag: bool,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "DataTransfer" , js_name = getFilesAndDirectories)]
     #[doc = "The `getFilesAndDirectories()` method."]
     #[doc = ""]
@@ -130,4 +133,46 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `DataTransfer`, `Element`*"]
     pub fn set_drag_image(this: &DataTransfer, image: &Element, x: i32, y: i32);
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "File")]
+    # [wasm_bindgen (catch , method , structural , js_class = "DataTransfer" , js_name = getFiles)]
+    #[doc = "The `getFiles()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/DataTransfer/getFiles)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `DataTransfer`, `File`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_files(
+        this: &DataTransfer,
+    ) -> Result<::js_sys::Promise<::js_sys::Array<File>>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "File")]
+    # [wasm_bindgen (catch , method , structural , js_class = "DataTransfer" , js_name = getFiles)]
+    #[doc = "The `getFiles()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/DataTransfer/getFiles)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `DataTransfer`, `File`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_files_with_recursive_flag(
+        this: &DataTransfer,
+        recursive_flag: bool,
+    ) -> Result<::js_sys::Promise<::js_sys::Array<File>>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "DataTransfer" , js_name = getFilesAndDirectories)]
+    #[doc = "The `getFilesAndDirectories()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/DataTransfer/getFilesAndDirectories)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `DataTransfer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_files_and_directories(
+        this: &DataTransfer,
+    ) -> Result<::js_sys::Promise<::js_sys::Array<::js_sys::Object>>, JsValue>;
 }
@@ -35,13 +35,13 @@ extern "C" {
     #[doc = "*This API requires the following crate features to be activated: `DataTransferItem`, `File`*"]
     pub fn get_as_file(this: &DataTransferItem) -> Result<Option<File>, JsValue>;
     #[cfg(web_sys_unstable_apis)]
-    #[cfg(all(feature = "FileSystemHandle", feature = "JsOption",))]
+    #[cfg(feature = "FileSystemHandle")]
     # [wasm_bindgen (method , structural , js_class = "DataTransferItem" , js_name = getAsFileSystemHandle)]
     #[doc = "The `getAsFileSystemHandle()` method."]
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/DataTransferItem/getAsFileSystemHandle)"]
     #[doc = ""]
-    #[doc = "*This API requires the following crate features to be activated: `DataTransferItem`, `FileSystemHandle`, `JsOption`*"]
+    #[doc = "*This API requires the following crate features to be activated: `DataTransferItem`, `FileSystemHandle`*"]
     #[doc = ""]
     #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
     #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
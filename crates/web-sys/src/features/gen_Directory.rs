@@ -26,6 +26,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Directory`*"]
     pub fn path(this: &Directory) -> Result<::alloc::string::String, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Directory" , js_name = getFiles)]
     #[doc = "The `getFiles()` method."]
     #[doc = ""]
@@ -33,6 +34,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Directory`*"]
     pub fn get_files(this: &Directory) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Directory" , js_name = getFiles)]
     #[doc = "The `getFiles()` method."]
     #[doc = ""]
@@ -43,6 +45,7 @@ extern "C" {
         this: &Directory,
         recursive_flag: bool,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Directory" , js_name = getFilesAndDirectories)]
     #[doc = "The `getFilesAndDirectories()` method."]
     #[doc = ""]
@@ -50,4 +53,45 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Directory`*"]
     pub fn get_files_and_directories(this: &Directory) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "File")]
+    # [wasm_bindgen (catch , method , structural , js_class = "Directory" , js_name = getFiles)]
+    #[doc = "The `getFiles()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Directory/getFiles)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Directory`, `File`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_files(this: &Directory)
+        -> Result<::js_sys::Promise<::js_sys::Array<File>>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "File")]
+    # [wasm_bindgen (catch , method , structural , js_class = "Directory" , js_name = getFiles)]
+    #[doc = "The `getFiles()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Directory/getFiles)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Directory`, `File`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_files_with_recursive_flag(
+        this: &Directory,
+        recursive_flag: bool,
+    ) -> Result<::js_sys::Promise<::js_sys::Array<File>>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "Directory" , js_name = getFilesAndDirectories)]
+    #[doc = "The `getFilesAndDirectories()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Directory/getFilesAndDirectories)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Directory`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_files_and_directories(
+        this: &Directory,
+    ) -> Result<::js_sys::Promise<::js_sys::Array<::js_sys::Object>>, JsValue>;
 }
@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`*"]
     pub type FileSystemDirectoryHandle;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = getDirectoryHandle)]
     #[doc = "The `getDirectoryHandle()` method."]
     #[doc = ""]
@@ -19,6 +20,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`*"]
     pub fn get_directory_handle(this: &FileSystemDirectoryHandle, name: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "FileSystemGetDirectoryOptions")]
     # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = getDirectoryHandle)]
     #[doc = "The `getDirectoryHandle()` method."]
@@ -31,6 +33,7 @@ extern "C" {
         name: &str,
         options: &FileSystemGetDirectoryOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = getFileHandle)]
     #[doc = "The `getFileHandle()` method."]
     #[doc = ""]
@@ -38,6 +41,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`*"]
     pub fn get_file_handle(this: &FileSystemDirectoryHandle, name: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "FileSystemGetFileOptions")]
     # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = getFileHandle)]
     #[doc = "The `getFileHandle()` method."]
@@ -50,6 +54,7 @@ extern "C" {
         name: &str,
         options: &FileSystemGetFileOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = removeEntry)]
     #[doc = "The `removeEntry()` method."]
     #[doc = ""]
@@ -57,6 +62,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`*"]
     pub fn remove_entry(this: &FileSystemDirectoryHandle, name: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "FileSystemRemoveOptions")]
     # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = removeEntry)]
     #[doc = "The `removeEntry()` method."]
@@ -69,6 +75,7 @@ extern "C" {
         name: &str,
         options: &FileSystemRemoveOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = resolve)]
     #[doc = "The `resolve()` method."]
     #[doc = ""]
@@ -79,6 +86,111 @@ extern "C" {
         this: &FileSystemDirectoryHandle,
         possible_descendant: &FileSystemHandle,
     ) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = getDirectoryHandle)]
+    #[doc = "The `getDirectoryHandle()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemDirectoryHandle/getDirectoryHandle)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_directory_handle(
+        this: &FileSystemDirectoryHandle,
+        name: &str,
+    ) -> ::js_sys::Promise<FileSystemDirectoryHandle>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "FileSystemGetDirectoryOptions")]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = getDirectoryHandle)]
+    #[doc = "The `getDirectoryHandle()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemDirectoryHandle/getDirectoryHandle)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`, `FileSystemGetDirectoryOptions`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_directory_handle_with_options(
+        this: &FileSystemDirectoryHandle,
+        name: &str,
+        options: &FileSystemGetDirectoryOptions,
+    ) -> ::js_sys::Promise<FileSystemDirectoryHandle>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "FileSystemFileHandle")]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = getFileHandle)]
+    #[doc = "The `getFileHandle()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemDirectoryHandle/getFileHandle)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`, `FileSystemFileHandle`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_file_handle(
+        this: &FileSystemDirectoryHandle,
+        name: &str,
+    ) -> ::js_sys::Promise<FileSystemFileHandle>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "FileSystemFileHandle", feature = "FileSystemGetFileOptions",))]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = getFileHandle)]
+    #[doc = "The `getFileHandle()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemDirectoryHandle/getFileHandle)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`, `FileSystemFileHandle`, `FileSystemGetFileOptions`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_file_handle_with_options(
+        this: &FileSystemDirectoryHandle,
+        name: &str,
+        options: &FileSystemGetFileOptions,
+    ) -> ::js_sys::Promise<FileSystemFileHandle>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = removeEntry)]
+    #[doc = "The `removeEntry()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemDirectoryHandle/removeEntry)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn remove_entry(
+        this: &FileSystemDirectoryHandle,
+        name: &str,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "FileSystemRemoveOptions")]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = removeEntry)]
+    #[doc = "The `removeEntry()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemDirectoryHandle/removeEntry)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`, `FileSystemRemoveOptions`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn remove_entry_with_options(
+        this: &FileSystemDirectoryHandle,
+        name: &str,
+        options: &FileSystemRemoveOptions,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = resolve)]
+    #[doc = "The `resolve()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemDirectoryHandle/resolve)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn resolve(
+        this: &FileSystemDirectoryHandle,
+        possible_descendant: &FileSystemHandle,
+    ) -> ::js_sys::Promise<::js_sys::JsOption<::js_sys::Array<::js_sys::JsString>>>;
     # [wasm_bindgen (method , structural , js_class = "FileSystemDirectoryHandle" , js_name = entries)]
     #[doc = "The `entries()` method."]
     #[doc = ""]
@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FileSystemFileHandle`*"]
     pub type FileSystemFileHandle;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "FileSystemFileHandle" , js_name = createSyncAccessHandle)]
     #[doc = "The `createSyncAccessHandle()` method."]
     #[doc = ""]
@@ -37,6 +38,7 @@ extern "C" {
         this: &FileSystemFileHandle,
         options: &FileSystemSyncAccessHandleOptions,
     ) -> ::js_sys::Promise<FileSystemSyncAccessHandle>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "FileSystemFileHandle" , js_name = createWritable)]
     #[doc = "The `createWritable()` method."]
     #[doc = ""]
@@ -44,6 +46,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FileSystemFileHandle`*"]
     pub fn create_writable(this: &FileSystemFileHandle) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "FileSystemCreateWritableOptions")]
     # [wasm_bindgen (method , structural , js_class = "FileSystemFileHandle" , js_name = createWritable)]
     #[doc = "The `createWritable()` method."]
@@ -55,6 +58,7 @@ extern "C" {
         this: &FileSystemFileHandle,
         options: &FileSystemCreateWritableOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "FileSystemFileHandle" , js_name = getFile)]
     #[doc = "The `getFile()` method."]
     #[doc = ""]
@@ -62,4 +66,62 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FileSystemFileHandle`*"]
     pub fn get_file(this: &FileSystemFileHandle) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "FileSystemSyncAccessHandle")]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemFileHandle" , js_name = createSyncAccessHandle)]
+    #[doc = "The `createSyncAccessHandle()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemFileHandle/createSyncAccessHandle)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemFileHandle`, `FileSystemSyncAccessHandle`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_sync_access_handle(
+        this: &FileSystemFileHandle,
+    ) -> ::js_sys::Promise<FileSystemSyncAccessHandle>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "FileSystemWritableFileStream")]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemFileHandle" , js_name = createWritable)]
+    #[doc = "The `createWritable()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemFileHandle/createWritable)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemFileHandle`, `FileSystemWritableFileStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_writable(
+        this: &FileSystemFileHandle,
+    ) -> ::js_sys::Promise<FileSystemWritableFileStream>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(
+        feature = "FileSystemCreateWritableOptions",
+        feature = "FileSystemWritableFileStream",
+    ))]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemFileHandle" , js_name = createWritable)]
+    #[doc = "The `createWritable()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemFileHandle/createWritable)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemCreateWritableOptions`, `FileSystemFileHandle`, `FileSystemWritableFileStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_writable_with_options(
+        this: &FileSystemFileHandle,
+        options: &FileSystemCreateWritableOptions,
+    ) -> ::js_sys::Promise<FileSystemWritableFileStream>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "File")]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemFileHandle" , js_name = getFile)]
+    #[doc = "The `getFile()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemFileHandle/getFile)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `File`, `FileSystemFileHandle`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_file(this: &FileSystemFileHandle) -> ::js_sys::Promise<File>;
 }
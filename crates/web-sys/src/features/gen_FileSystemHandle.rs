@@ -27,6 +27,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FileSystemHandle`*"]
     pub fn name(this: &FileSystemHandle) -> ::alloc::string::String;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "FileSystemHandle" , js_name = isSameEntry)]
     #[doc = "The `isSameEntry()` method."]
     #[doc = ""]
@@ -94,4 +95,18 @@ extern "C" {
         this: &FileSystemHandle,
         descriptor: &FileSystemHandlePermissionDescriptor,
     ) -> ::js_sys::Promise<PermissionState>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "FileSystemHandle" , js_name = isSameEntry)]
+    #[doc = "The `isSameEntry()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemHandle/isSameEntry)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemHandle`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn is_same_entry(
+        this: &FileSystemHandle,
+        other: &FileSystemHandle,
+    ) -> ::js_sys::Promise<::js_sys::Boolean>;
 }
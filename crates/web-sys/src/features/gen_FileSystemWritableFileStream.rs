@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FileSystemWritableFileStream`*"]
     pub type FileSystemWritableFileStream;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = seek)]
     #[doc = "The `seek()` method."]
     #[doc = ""]
@@ -22,6 +23,7 @@ extern "C" {
         this: &FileSystemWritableFileStream,
         position: u32,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = seek)]
     #[doc = "The `seek()` method."]
     #[doc = ""]
@@ -32,6 +34,7 @@ extern "C" {
         this: &FileSystemWritableFileStream,
         position: f64,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = truncate)]
     #[doc = "The `truncate()` method."]
     #[doc = ""]
@@ -42,6 +45,7 @@ extern "C" {
         this: &FileSystemWritableFileStream,
         size: u32,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = truncate)]
     #[doc = "The `truncate()` method."]
     #[doc = ""]
@@ -52,6 +56,7 @@ extern "C" {
         this: &FileSystemWritableFileStream,
         size: f64,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
     #[doc = "The `write()` method."]
     #[doc = ""]
@@ -62,6 +67,7 @@ extern "C" {
         this: &FileSystemWritableFileStream,
         data: &::js_sys::Object,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
     #[doc = "The `write()` method."]
     #[doc = ""]
@@ -72,6 +78,7 @@ extern "C" {
         this: &FileSystemWritableFileStream,
         data: &[u8],
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
     #[doc = "The `write()` method."]
     #[doc = ""]
@@ -82,6 +89,7 @@ extern "C" {
         this: &FileSystemWritableFileStream,
         data: &::js_sys::Uint8Array,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "Blob")]
     # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
     #[doc = "The `write()` method."]
@@ -93,6 +101,7 @@ extern "C" {
         this: &FileSystemWritableFileStream,
         data: &Blob,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
     #[doc = "The `write()` method."]
     #[doc = ""]
@@ -103,6 +112,7 @@ extern "C" {
         this: &FileSystemWritableFileStream,
         data: &str,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "WriteParams")]
     # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
     #[doc = "The `write()` method."]
@@ -114,4 +124,146 @@ extern "C" {
         this: &FileSystemWritableFileStream,
         data: &WriteParams,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = seek)]
+    #[doc = "The `seek()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemWritableFileStream/seek)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemWritableFileStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn seek_with_u32(
+        this: &FileSystemWritableFileStream,
+        position: u32,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = seek)]
+    #[doc = "The `seek()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemWritableFileStream/seek)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemWritableFileStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn seek_with_f64(
+        this: &FileSystemWritableFileStream,
+        position: f64,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = truncate)]
+    #[doc = "The `truncate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemWritableFileStream/truncate)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemWritableFileStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn truncate_with_u32(
+        this: &FileSystemWritableFileStream,
+        size: u32,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = truncate)]
+    #[doc = "The `truncate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemWritableFileStream/truncate)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemWritableFileStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn truncate_with_f64(
+        this: &FileSystemWritableFileStream,
+        size: f64,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
+    #[doc = "The `write()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemWritableFileStream/write)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemWritableFileStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn write_with_buffer_source(
+        this: &FileSystemWritableFileStream,
+        data: &::js_sys::Object,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
+    #[doc = "The `write()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemWritableFileStream/write)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemWritableFileStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn write_with_u8_array(
+        this: &FileSystemWritableFileStream,
+        data: &[u8],
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
+    #[doc = "The `write()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemWritableFileStream/write)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemWritableFileStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn write_with_js_u8_array(
+        this: &FileSystemWritableFileStream,
+        data: &::js_sys::Uint8Array,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Blob")]
+    # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
+    #[doc = "The `write()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemWritableFileStream/write)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Blob`, `FileSystemWritableFileStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn write_with_blob(
+        this: &FileSystemWritableFileStream,
+        data: &Blob,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
+    #[doc = "The `write()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemWritableFileStream/write)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemWritableFileStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn write_with_str(
+        this: &FileSystemWritableFileStream,
+        data: &str,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "WriteParams")]
+    # [wasm_bindgen (catch , method , structural , js_class = "FileSystemWritableFileStream" , js_name = write)]
+    #[doc = "The `write()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FileSystemWritableFileStream/write)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemWritableFileStream`, `WriteParams`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn write_with_write_params(
+        this: &FileSystemWritableFileStream,
+        data: &WriteParams,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
 }
@@ -146,6 +146,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FontFace`, `FontFaceLoadStatus`*"]
     pub fn status(this: &FontFace) -> FontFaceLoadStatus;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (structural , catch , method , getter , js_class = "FontFace" , js_name = loaded)]
     #[doc = "Getter for the `loaded` field of this object."]
     #[doc = ""]
@@ -153,6 +154,17 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FontFace`*"]
     pub fn loaded(this: &FontFace) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (structural , catch , method , getter , js_class = "FontFace" , js_name = loaded)]
+    #[doc = "Getter for the `loaded` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FontFace/loaded)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FontFace`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn loaded(this: &FontFace) -> Result<::js_sys::Promise<FontFace>, JsValue>;
     #[wasm_bindgen(catch, constructor, js_class = "FontFace")]
     #[doc = "The `new FontFace(..)` constructor, creating a new instance of `FontFace`."]
     #[doc = ""]
@@ -257,6 +269,7 @@ extern "C" {
         source: &::js_sys::Uint8Array,
         descriptors: &FontFaceDescriptors,
     ) -> Result<FontFace, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "FontFace" , js_name = load)]
     #[doc = "The `load()` method."]
     #[doc = ""]
@@ -264,4 +277,15 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FontFace`*"]
     pub fn load(this: &FontFace) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "FontFace" , js_name = load)]
+    #[doc = "The `load()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FontFace/load)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FontFace`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn load(this: &FontFace) -> Result<::js_sys::Promise<FontFace>, JsValue>;
 }
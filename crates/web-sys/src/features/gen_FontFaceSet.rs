@@ -61,6 +61,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FontFaceSet`*"]
     pub fn set_onloadingerror(this: &FontFaceSet, value: Option<&::js_sys::Function>);
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (structural , catch , method , getter , js_class = "FontFaceSet" , js_name = ready)]
     #[doc = "Getter for the `ready` field of this object."]
     #[doc = ""]
@@ -68,6 +69,17 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FontFaceSet`*"]
     pub fn ready(this: &FontFaceSet) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (structural , catch , method , getter , js_class = "FontFaceSet" , js_name = ready)]
+    #[doc = "Getter for the `ready` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/ready)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FontFaceSet`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn ready(this: &FontFaceSet) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
     #[cfg(feature = "FontFaceSetLoadStatus")]
     # [wasm_bindgen (structural , method , getter , js_class = "FontFaceSet" , js_name = status)]
     #[doc = "Getter for the `status` field of this object."]
@@ -147,6 +159,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FontFace`, `FontFaceSet`*"]
     pub fn has(this: &FontFaceSet, font: &FontFace) -> bool;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "FontFaceSet" , js_name = load)]
     #[doc = "The `load()` method."]
     #[doc = ""]
@@ -154,6 +167,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FontFaceSet`*"]
     pub fn load(this: &FontFaceSet, font: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "FontFaceSet" , js_name = load)]
     #[doc = "The `load()` method."]
     #[doc = ""]
@@ -169,4 +183,32 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `FontFaceSet`, `FontFaceSetIterator`*"]
     pub fn values(this: &FontFaceSet) -> FontFaceSetIterator;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "FontFace")]
+    # [wasm_bindgen (method , structural , js_class = "FontFaceSet" , js_name = load)]
+    #[doc = "The `load()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/load)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FontFace`, `FontFaceSet`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn load(this: &FontFaceSet, font: &str) -> ::js_sys::Promise<::js_sys::Array<FontFace>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "FontFace")]
+    # [wasm_bindgen (method , structural , js_class = "FontFaceSet" , js_name = load)]
+    #[doc = "The `load()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/FontFaceSet/load)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FontFace`, `FontFaceSet`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn load_with_text(
+        this: &FontFaceSet,
+        font: &str,
+        text: &str,
+    ) -> ::js_sys::Promise<::js_sys::Array<FontFace>>;
 }
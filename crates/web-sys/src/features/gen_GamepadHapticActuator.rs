@@ -68,6 +68,7 @@ extern "C" {
         type_: GamepadHapticEffectType,
         params: &GamepadEffectParameters,
     ) -> ::js_sys::Promise<GamepadHapticsResult>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "GamepadHapticActuator" , js_name = pulse)]
     #[doc = "The `pulse()` method."]
     #[doc = ""]
@@ -91,4 +92,19 @@ extern "C" {
     #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
     #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
     pub fn reset(this: &GamepadHapticActuator) -> ::js_sys::Promise<GamepadHapticsResult>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "GamepadHapticActuator" , js_name = pulse)]
+    #[doc = "The `pulse()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GamepadHapticActuator/pulse)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `GamepadHapticActuator`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn pulse(
+        this: &GamepadHapticActuator,
+        value: f64,
+        duration: f64,
+    ) -> Result<::js_sys::Promise<::js_sys::Boolean>, JsValue>;
 }
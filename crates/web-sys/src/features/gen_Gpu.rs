@@ -41,29 +41,25 @@ extern "C" {
     #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
     pub fn get_preferred_canvas_format(this: &Gpu) -> GpuTextureFormat;
     #[cfg(web_sys_unstable_apis)]
-    #[cfg(all(feature = "GpuAdapter", feature = "JsOption",))]
+    #[cfg(feature = "GpuAdapter")]
     # [wasm_bindgen (method , structural , js_class = "GPU" , js_name = requestAdapter)]
     #[doc = "The `requestAdapter()` method."]
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPU/requestAdapter)"]
     #[doc = ""]
-    #[doc = "*This API requires the following crate features to be activated: `Gpu`, `GpuAdapter`, `JsOption`*"]
+    #[doc = "*This API requires the following crate features to be activated: `Gpu`, `GpuAdapter`*"]
     #[doc = ""]
     #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
     #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
     pub fn request_adapter(this: &Gpu) -> ::js_sys::Promise<::js_sys::JsOption<GpuAdapter>>;
     #[cfg(web_sys_unstable_apis)]
-    #[cfg(all(
-        feature = "GpuAdapter",
-        feature = "GpuRequestAdapterOptions",
-        feature = "JsOption",
-    ))]
+    #[cfg(all(feature = "GpuAdapter", feature = "GpuRequestAdapterOptions",))]
     # [wasm_bindgen (method , structural , js_class = "GPU" , js_name = requestAdapter)]
     #[doc = "The `requestAdapter()` method."]
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/GPU/requestAdapter)"]
     #[doc = ""]
-    #[doc = "*This API requires the following crate features to be activated: `Gpu`, `GpuAdapter`, `GpuRequestAdapterOptions`, `JsOption`*"]
+    #[doc = "*This API requires the following crate features to be activated: `Gpu`, `GpuAdapter`, `GpuRequestAdapterOptions`*"]
     #[doc = ""]
     #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
     #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
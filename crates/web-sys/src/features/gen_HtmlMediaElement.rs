@@ -405,6 +405,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `HtmlMediaElement`*"]
     pub fn pause(this: &HtmlMediaElement) -> Result<(), JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "HTMLMediaElement" , js_name = play)]
     #[doc = "The `play()` method."]
     #[doc = ""]
@@ -412,6 +413,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `HtmlMediaElement`*"]
     pub fn play(this: &HtmlMediaElement) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "HTMLMediaElement" , js_name = seekToNextFrame)]
     #[doc = "The `seekToNextFrame()` method."]
     #[doc = ""]
@@ -419,6 +421,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `HtmlMediaElement`*"]
     pub fn seek_to_next_frame(this: &HtmlMediaElement) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "MediaKeys")]
     # [wasm_bindgen (method , structural , js_class = "HTMLMediaElement" , js_name = setMediaKeys)]
     #[doc = "The `setMediaKeys()` method."]
@@ -437,6 +440,46 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `HtmlMediaElement`*"]
     pub fn set_visible(this: &HtmlMediaElement, a_visible: bool);
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "HTMLMediaElement" , js_name = play)]
+    #[doc = "The `play()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/play)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `HtmlMediaElement`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn play(this: &HtmlMediaElement)
+        -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "HTMLMediaElement" , js_name = seekToNextFrame)]
+    #[doc = "The `seekToNextFrame()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/seekToNextFrame)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `HtmlMediaElement`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn seek_to_next_frame(
+        this: &HtmlMediaElement,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "MediaKeys")]
+    # [wasm_bindgen (method , structural , js_class = "HTMLMediaElement" , js_name = setMediaKeys)]
+    #[doc = "The `setMediaKeys()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/HTMLMediaElement/setMediaKeys)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `HtmlMediaElement`, `MediaKeys`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_media_keys(
+        this: &HtmlMediaElement,
+        media_keys: Option<&MediaKeys>,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
 impl HtmlMediaElement {
     #[doc = "The `HTMLMediaElement.NETWORK_EMPTY` const."]
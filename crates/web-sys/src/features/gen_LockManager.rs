@@ -29,13 +29,13 @@ extern "C" {
     #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
     pub fn query(this: &LockManager) -> ::js_sys::Promise<LockManagerSnapshot>;
     #[cfg(web_sys_unstable_apis)]
-    #[cfg(all(feature = "JsOption", feature = "Lock",))]
+    #[cfg(feature = "Lock")]
     # [wasm_bindgen (method , structural , js_class = "LockManager" , js_name = request)]
     #[doc = "The `request()` method."]
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/LockManager/request)"]
     #[doc = ""]
-    #[doc = "*This API requires the following crate features to be activated: `JsOption`, `Lock`, `LockManager`*"]
+    #[doc = "*This API requires the following crate features to be activated: `Lock`, `LockManager`*"]
     #[doc = ""]
     #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
     #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
@@ -45,13 +45,13 @@ extern "C" {
         callback: &::js_sys::Function<fn(::js_sys::JsOption<Lock>) -> ::js_sys::Promise>,
     ) -> ::js_sys::Promise;
     #[cfg(web_sys_unstable_apis)]
-    #[cfg(all(feature = "JsOption", feature = "Lock", feature = "LockOptions",))]
+    #[cfg(all(feature = "Lock", feature = "LockOptions",))]
     # [wasm_bindgen (method , structural , js_class = "LockManager" , js_name = request)]
     #[doc = "The `request()` method."]
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/LockManager/request)"]
     #[doc = ""]
-    #[doc = "*This API requires the following crate features to be activated: `JsOption`, `Lock`, `LockManager`, `LockOptions`*"]
+    #[doc = "*This API requires the following crate features to be activated: `Lock`, `LockManager`, `LockOptions`*"]
     #[doc = ""]
     #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
     #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
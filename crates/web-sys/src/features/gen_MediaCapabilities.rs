@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaCapabilities`*"]
     pub type MediaCapabilities;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "MediaDecodingConfiguration")]
     # [wasm_bindgen (method , structural , js_class = "MediaCapabilities" , js_name = decodingInfo)]
     #[doc = "The `decodingInfo()` method."]
@@ -23,6 +24,7 @@ extern "C" {
         this: &MediaCapabilities,
         configuration: &MediaDecodingConfiguration,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "MediaEncodingConfiguration")]
     # [wasm_bindgen (method , structural , js_class = "MediaCapabilities" , js_name = encodingInfo)]
     #[doc = "The `encodingInfo()` method."]
@@ -34,4 +36,40 @@ extern "C" {
         this: &MediaCapabilities,
         configuration: &MediaEncodingConfiguration,
     ) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(
+        feature = "MediaCapabilitiesInfo",
+        feature = "MediaDecodingConfiguration",
+    ))]
+    # [wasm_bindgen (method , structural , js_class = "MediaCapabilities" , js_name = decodingInfo)]
+    #[doc = "The `decodingInfo()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaCapabilities/decodingInfo)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaCapabilities`, `MediaCapabilitiesInfo`, `MediaDecodingConfiguration`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn decoding_info(
+        this: &MediaCapabilities,
+        configuration: &MediaDecodingConfiguration,
+    ) -> ::js_sys::Promise<MediaCapabilitiesInfo>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(
+        feature = "MediaCapabilitiesInfo",
+        feature = "MediaEncodingConfiguration",
+    ))]
+    # [wasm_bindgen (method , structural , js_class = "MediaCapabilities" , js_name = encodingInfo)]
+    #[doc = "The `encodingInfo()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaCapabilities/encodingInfo)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaCapabilities`, `MediaCapabilitiesInfo`, `MediaEncodingConfiguration`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn encoding_info(
+        this: &MediaCapabilities,
+        configuration: &MediaEncodingConfiguration,
+    ) -> ::js_sys::Promise<MediaCapabilitiesInfo>;
 }
@@ -26,6 +26,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaDevices`*"]
     pub fn set_ondevicechange(this: &MediaDevices, value: Option<&::js_sys::Function>);
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "MediaDevices" , js_name = enumerateDevices)]
     #[doc = "The `enumerateDevices()` method."]
     #[doc = ""]
@@ -33,6 +34,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaDevices`*"]
     pub fn enumerate_devices(this: &MediaDevices) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "MediaDevices" , js_name = getDisplayMedia)]
     #[doc = "The `getDisplayMedia()` method."]
     #[doc = ""]
@@ -40,6 +42,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaDevices`*"]
     pub fn get_display_media(this: &MediaDevices) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "DisplayMediaStreamConstraints")]
     # [wasm_bindgen (catch , method , structural , js_class = "MediaDevices" , js_name = getDisplayMedia)]
     #[doc = "The `getDisplayMedia()` method."]
@@ -59,6 +62,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaDevices`, `MediaTrackSupportedConstraints`*"]
     pub fn get_supported_constraints(this: &MediaDevices) -> MediaTrackSupportedConstraints;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "MediaDevices" , js_name = getUserMedia)]
     #[doc = "The `getUserMedia()` method."]
     #[doc = ""]
@@ -66,6 +70,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaDevices`*"]
     pub fn get_user_media(this: &MediaDevices) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "MediaStreamConstraints")]
     # [wasm_bindgen (catch , method , structural , js_class = "MediaDevices" , js_name = getUserMedia)]
     #[doc = "The `getUserMedia()` method."]
@@ -77,4 +82,74 @@ extern "C" {
         this: &MediaDevices,
         constraints: &MediaStreamConstraints,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "MediaDeviceInfo")]
+    # [wasm_bindgen (catch , method , structural , js_class = "MediaDevices" , js_name = enumerateDevices)]
+    #[doc = "The `enumerateDevices()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/enumerateDevices)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaDeviceInfo`, `MediaDevices`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn enumerate_devices(
+        this: &MediaDevices,
+    ) -> Result<::js_sys::Promise<::js_sys::Array<MediaDeviceInfo>>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "MediaStream")]
+    # [wasm_bindgen (catch , method , structural , js_class = "MediaDevices" , js_name = getDisplayMedia)]
+    #[doc = "The `getDisplayMedia()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/getDisplayMedia)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaDevices`, `MediaStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_display_media(
+        this: &MediaDevices,
+    ) -> Result<::js_sys::Promise<MediaStream>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "DisplayMediaStreamConstraints", feature = "MediaStream",))]
+    # [wasm_bindgen (catch , method , structural , js_class = "MediaDevices" , js_name = getDisplayMedia)]
+    #[doc = "The `getDisplayMedia()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/getDisplayMedia)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `DisplayMediaStreamConstraints`, `MediaDevices`, `MediaStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_display_media_with_constraints(
+        this: &MediaDevices,
+        constraints: &DisplayMediaStreamConstraints,
+    ) -> Result<::js_sys::Promise<MediaStream>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "MediaStream")]
+    # [wasm_bindgen (catch , method , structural , js_class = "MediaDevices" , js_name = getUserMedia)]
+    #[doc = "The `getUserMedia()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/getUserMedia)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaDevices`, `MediaStream`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_user_media(this: &MediaDevices) -> Result<::js_sys::Promise<MediaStream>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "MediaStream", feature = "MediaStreamConstraints",))]
+    # [wasm_bindgen (catch , method , structural , js_class = "MediaDevices" , js_name = getUserMedia)]
+    #[doc = "The `getUserMedia()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaDevices/getUserMedia)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaDevices`, `MediaStream`, `MediaStreamConstraints`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_user_media_with_constraints(
+        this: &MediaDevices,
+        constraints: &MediaStreamConstraints,
+    ) -> Result<::js_sys::Promise<MediaStream>, JsValue>;
 }
@@ -34,6 +34,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
     pub fn expiration(this: &MediaKeySession) -> f64;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (structural , method , getter , js_class = "MediaKeySession" , js_name = closed)]
     #[doc = "Getter for the `closed` field of this object."]
     #[doc = ""]
@@ -41,6 +42,17 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
     pub fn closed(this: &MediaKeySession) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (structural , method , getter , js_class = "MediaKeySession" , js_name = closed)]
+    #[doc = "Getter for the `closed` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeySession/closed)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn closed(this: &MediaKeySession) -> ::js_sys::Promise<::js_sys::Undefined>;
     #[cfg(feature = "MediaKeyStatusMap")]
     # [wasm_bindgen (structural , method , getter , js_class = "MediaKeySession" , js_name = keyStatuses)]
     #[doc = "Getter for the `keyStatuses` field of this object."]
@@ -77,6 +89,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
     pub fn set_onmessage(this: &MediaKeySession, value: Option<&::js_sys::Function>);
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = close)]
     #[doc = "The `close()` method."]
     #[doc = ""]
@@ -84,6 +97,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
     pub fn close(this: &MediaKeySession) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = generateRequest)]
     #[doc = "The `generateRequest()` method."]
     #[doc = ""]
@@ -95,6 +109,7 @@ extern "C" {
         init_data_type: &str,
         init_data: &::js_sys::Object,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = generateRequest)]
     #[doc = "The `generateRequest()` method."]
     #[doc = ""]
@@ -106,6 +121,7 @@ extern "C" {
         init_data_type: &str,
         init_data: &mut [u8],
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = generateRequest)]
     #[doc = "The `generateRequest()` method."]
     #[doc = ""]
@@ -117,6 +133,7 @@ extern "C" {
         init_data_type: &str,
         init_data: &::js_sys::Uint8Array,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = load)]
     #[doc = "The `load()` method."]
     #[doc = ""]
@@ -124,6 +141,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
     pub fn load(this: &MediaKeySession, session_id: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = remove)]
     #[doc = "The `remove()` method."]
     #[doc = ""]
@@ -131,6 +149,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
     pub fn remove(this: &MediaKeySession) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = update)]
     #[doc = "The `update()` method."]
     #[doc = ""]
@@ -141,6 +160,7 @@ extern "C" {
         this: &MediaKeySession,
         response: &::js_sys::Object,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = update)]
     #[doc = "The `update()` method."]
     #[doc = ""]
@@ -148,6 +168,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
     pub fn update_with_u8_array(this: &MediaKeySession, response: &mut [u8]) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = update)]
     #[doc = "The `update()` method."]
     #[doc = ""]
@@ -158,4 +179,124 @@ extern "C" {
         this: &MediaKeySession,
         response: &::js_sys::Uint8Array,
     ) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = close)]
+    #[doc = "The `close()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeySession/close)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn close(this: &MediaKeySession) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = generateRequest)]
+    #[doc = "The `generateRequest()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeySession/generateRequest)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn generate_request_with_buffer_source(
+        this: &MediaKeySession,
+        init_data_type: &str,
+        init_data: &::js_sys::Object,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = generateRequest)]
+    #[doc = "The `generateRequest()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeySession/generateRequest)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn generate_request_with_u8_array(
+        this: &MediaKeySession,
+        init_data_type: &str,
+        init_data: &mut [u8],
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = generateRequest)]
+    #[doc = "The `generateRequest()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeySession/generateRequest)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn generate_request_with_js_u8_array(
+        this: &MediaKeySession,
+        init_data_type: &str,
+        init_data: &::js_sys::Uint8Array,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = load)]
+    #[doc = "The `load()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeySession/load)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn load(this: &MediaKeySession, session_id: &str) -> ::js_sys::Promise<::js_sys::Boolean>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = remove)]
+    #[doc = "The `remove()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeySession/remove)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn remove(this: &MediaKeySession) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = update)]
+    #[doc = "The `update()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeySession/update)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn update_with_buffer_source(
+        this: &MediaKeySession,
+        response: &::js_sys::Object,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = update)]
+    #[doc = "The `update()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeySession/update)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn update_with_u8_array(
+        this: &MediaKeySession,
+        response: &mut [u8],
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeySession" , js_name = update)]
+    #[doc = "The `update()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeySession/update)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySession`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn update_with_js_u8_array(
+        this: &MediaKeySession,
+        response: &::js_sys::Uint8Array,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
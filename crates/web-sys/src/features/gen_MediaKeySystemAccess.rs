@@ -19,6 +19,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaKeySystemAccess`*"]
     pub fn key_system(this: &MediaKeySystemAccess) -> ::alloc::string::String;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeySystemAccess" , js_name = createMediaKeys)]
     #[doc = "The `createMediaKeys()` method."]
     #[doc = ""]
@@ -34,4 +35,16 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaKeySystemAccess`, `MediaKeySystemConfiguration`*"]
     pub fn get_configuration(this: &MediaKeySystemAccess) -> MediaKeySystemConfiguration;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "MediaKeys")]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeySystemAccess" , js_name = createMediaKeys)]
+    #[doc = "The `createMediaKeys()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeySystemAccess/createMediaKeys)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySystemAccess`, `MediaKeys`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_media_keys(this: &MediaKeySystemAccess) -> ::js_sys::Promise<MediaKeys>;
 }
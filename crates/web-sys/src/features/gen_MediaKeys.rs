@@ -38,6 +38,7 @@ extern "C" {
         this: &MediaKeys,
         session_type: MediaKeySessionType,
     ) -> Result<MediaKeySession, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeys" , js_name = getStatusForPolicy)]
     #[doc = "The `getStatusForPolicy()` method."]
     #[doc = ""]
@@ -45,6 +46,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MediaKeys`*"]
     pub fn get_status_for_policy(this: &MediaKeys) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "MediaKeysPolicy")]
     # [wasm_bindgen (method , structural , js_class = "MediaKeys" , js_name = getStatusForPolicy)]
     #[doc = "The `getStatusForPolicy()` method."]
@@ -56,6 +58,7 @@ extern "C" {
         this: &MediaKeys,
         policy: &MediaKeysPolicy,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeys" , js_name = setServerCertificate)]
     #[doc = "The `setServerCertificate()` method."]
     #[doc = ""]
@@ -66,6 +69,7 @@ extern "C" {
         this: &MediaKeys,
         server_certificate: &::js_sys::Object,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeys" , js_name = setServerCertificate)]
     #[doc = "The `setServerCertificate()` method."]
     #[doc = ""]
@@ -76,6 +80,7 @@ extern "C" {
         this: &MediaKeys,
         server_certificate: &mut [u8],
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MediaKeys" , js_name = setServerCertificate)]
     #[doc = "The `setServerCertificate()` method."]
     #[doc = ""]
@@ -86,4 +91,73 @@ extern "C" {
         this: &MediaKeys,
         server_certificate: &::js_sys::Uint8Array,
     ) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "MediaKeyStatus")]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeys" , js_name = getStatusForPolicy)]
+    #[doc = "The `getStatusForPolicy()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeys/getStatusForPolicy)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeyStatus`, `MediaKeys`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_status_for_policy(this: &MediaKeys) -> ::js_sys::Promise<MediaKeyStatus>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "MediaKeyStatus", feature = "MediaKeysPolicy",))]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeys" , js_name = getStatusForPolicy)]
+    #[doc = "The `getStatusForPolicy()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeys/getStatusForPolicy)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeyStatus`, `MediaKeys`, `MediaKeysPolicy`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_status_for_policy_with_policy(
+        this: &MediaKeys,
+        policy: &MediaKeysPolicy,
+    ) -> ::js_sys::Promise<MediaKeyStatus>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeys" , js_name = setServerCertificate)]
+    #[doc = "The `setServerCertificate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeys/setServerCertificate)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeys`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_server_certificate_with_buffer_source(
+        this: &MediaKeys,
+        server_certificate: &::js_sys::Object,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeys" , js_name = setServerCertificate)]
+    #[doc = "The `setServerCertificate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeys/setServerCertificate)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeys`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_server_certificate_with_u8_array(
+        this: &MediaKeys,
+        server_certificate: &mut [u8],
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MediaKeys" , js_name = setServerCertificate)]
+    #[doc = "The `setServerCertificate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MediaKeys/setServerCertificate)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeys`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_server_certificate_with_js_u8_array(
+        this: &MediaKeys,
+        server_certificate: &::js_sys::Uint8Array,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
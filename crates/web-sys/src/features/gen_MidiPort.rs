@@ -78,6 +78,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MidiPort`*"]
     pub fn set_onstatechange(this: &MidiPort, value: Option<&::js_sys::Function>);
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MIDIPort" , js_name = close)]
     #[doc = "The `close()` method."]
     #[doc = ""]
@@ -85,6 +86,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MidiPort`*"]
     pub fn close(this: &MidiPort) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "MIDIPort" , js_name = open)]
     #[doc = "The `open()` method."]
     #[doc = ""]
@@ -92,4 +94,26 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `MidiPort`*"]
     pub fn open(this: &MidiPort) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MIDIPort" , js_name = close)]
+    #[doc = "The `close()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MIDIPort/close)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MidiPort`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn close(this: &MidiPort) -> ::js_sys::Promise<MidiPort>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "MIDIPort" , js_name = open)]
+    #[doc = "The `open()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/MIDIPort/open)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MidiPort`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn open(this: &MidiPort) -> ::js_sys::Promise<MidiPort>;
 }
@@ -372,6 +372,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Navigator`*"]
     pub fn get_gamepads(this: &Navigator) -> Result<::js_sys::Array, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Navigator" , js_name = getVRDisplays)]
     #[doc = "The `getVRDisplays()` method."]
     #[doc = ""]
@@ -379,6 +380,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Navigator`*"]
     pub fn get_vr_displays(this: &Navigator) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Navigator" , js_name = requestMIDIAccess)]
     #[doc = "The `requestMIDIAccess()` method."]
     #[doc = ""]
@@ -386,6 +388,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Navigator`*"]
     pub fn request_midi_access(this: &Navigator) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "MidiOptions")]
     # [wasm_bindgen (catch , method , structural , js_class = "Navigator" , js_name = requestMIDIAccess)]
     #[doc = "The `requestMIDIAccess()` method."]
@@ -397,6 +400,7 @@ extern "C" {
         this: &Navigator,
         options: &MidiOptions,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Navigator" , js_name = requestMediaKeySystemAccess)]
     #[doc = "The `requestMediaKeySystemAccess()` method."]
     #[doc = ""]
@@ -515,6 +519,7 @@ extern "C" {
         url: &str,
         data: Option<&ReadableStream>,
     ) -> Result<bool, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "Navigator" , js_name = share)]
     #[doc = "The `share()` method."]
     #[doc = ""]
@@ -522,6 +527,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Navigator`*"]
     pub fn share(this: &Navigator) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "ShareData")]
     # [wasm_bindgen (method , structural , js_class = "Navigator" , js_name = share)]
     #[doc = "The `share()` method."]
@@ -575,4 +581,87 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Navigator`*"]
     pub fn taint_enabled(this: &Navigator) -> bool;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "VrDisplay")]
+    # [wasm_bindgen (catch , method , structural , js_class = "Navigator" , js_name = getVRDisplays)]
+    #[doc = "The `getVRDisplays()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/getVRDisplays)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Navigator`, `VrDisplay`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_vr_displays(
+        this: &Navigator,
+    ) -> Result<::js_sys::Promise<::js_sys::Array<VrDisplay>>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "MidiAccess")]
+    # [wasm_bindgen (catch , method , structural , js_class = "Navigator" , js_name = requestMIDIAccess)]
+    #[doc = "The `requestMIDIAccess()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/requestMIDIAccess)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MidiAccess`, `Navigator`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn request_midi_access(this: &Navigator) -> Result<::js_sys::Promise<MidiAccess>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "MidiAccess", feature = "MidiOptions",))]
+    # [wasm_bindgen (catch , method , structural , js_class = "Navigator" , js_name = requestMIDIAccess)]
+    #[doc = "The `requestMIDIAccess()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/requestMIDIAccess)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MidiAccess`, `MidiOptions`, `Navigator`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn request_midi_access_with_options(
+        this: &Navigator,
+        options: &MidiOptions,
+    ) -> Result<::js_sys::Promise<MidiAccess>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "MediaKeySystemAccess")]
+    # [wasm_bindgen (method , structural , js_class = "Navigator" , js_name = requestMediaKeySystemAccess)]
+    #[doc = "The `requestMediaKeySystemAccess()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/requestMediaKeySystemAccess)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaKeySystemAccess`, `Navigator`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn request_media_key_system_access(
+        this: &Navigator,
+        key_system: &str,
+        supported_configurations: &::wasm_bindgen::JsValue,
+    ) -> ::js_sys::Promise<MediaKeySystemAccess>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "Navigator" , js_name = share)]
+    #[doc = "The `share()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/share)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Navigator`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn share(this: &Navigator) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "ShareData")]
+    # [wasm_bindgen (method , structural , js_class = "Navigator" , js_name = share)]
+    #[doc = "The `share()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Navigator/share)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Navigator`, `ShareData`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn share_with_data(
+        this: &Navigator,
+        data: &ShareData,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
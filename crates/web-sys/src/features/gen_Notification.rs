@@ -214,6 +214,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Notification`*"]
     pub fn close(this: &Notification);
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , static_method_of = Notification , js_class = "Notification" , js_name = requestPermission)]
     #[doc = "The `requestPermission()` method."]
     #[doc = ""]
@@ -221,6 +222,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Notification`*"]
     pub fn request_permission() -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , static_method_of = Notification , js_class = "Notification" , js_name = requestPermission)]
     #[doc = "The `requestPermission()` method."]
     #[doc = ""]
@@ -230,4 +232,30 @@ extern "C" {
     pub fn request_permission_with_permission_callback(
         permission_callback: &::js_sys::Function,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "NotificationPermission")]
+    # [wasm_bindgen (catch , static_method_of = Notification , js_class = "Notification" , js_name = requestPermission)]
+    #[doc = "The `requestPermission()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Notification/requestPermission_static)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Notification`, `NotificationPermission`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn request_permission() -> Result<::js_sys::Promise<NotificationPermission>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "NotificationPermission")]
+    # [wasm_bindgen (catch , static_method_of = Notification , js_class = "Notification" , js_name = requestPermission)]
+    #[doc = "The `requestPermission()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Notification/requestPermission_static)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Notification`, `NotificationPermission`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn request_permission_with_permission_callback(
+        permission_callback: &::js_sys::Function,
+    ) -> Result<::js_sys::Promise<NotificationPermission>, JsValue>;
 }
@@ -114,6 +114,7 @@ extern "C" {
         length: u32,
         sample_rate: f32,
     ) -> Result<OfflineAudioContext, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "OfflineAudioContext" , js_name = startRendering)]
     #[doc = "The `startRendering()` method."]
     #[doc = ""]
@@ -445,6 +446,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `OfflineAudioContext`, `WaveShaperNode`*"]
     pub fn create_wave_shaper(this: &OfflineAudioContext) -> Result<WaveShaperNode, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "OfflineAudioContext" , js_name = decodeAudioData)]
     #[doc = "The `decodeAudioData()` method."]
     #[doc = ""]
@@ -455,6 +457,7 @@ extern "C" {
         this: &OfflineAudioContext,
         audio_data: &::js_sys::ArrayBuffer,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "OfflineAudioContext" , js_name = decodeAudioData)]
     #[doc = "The `decodeAudioData()` method."]
     #[doc = ""]
@@ -466,6 +469,7 @@ extern "C" {
         audio_data: &::js_sys::ArrayBuffer,
         success_callback: &::js_sys::Function,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "OfflineAudioContext" , js_name = decodeAudioData)]
     #[doc = "The `decodeAudioData()` method."]
     #[doc = ""]
@@ -478,6 +482,7 @@ extern "C" {
         success_callback: &::js_sys::Function,
         error_callback: &::js_sys::Function,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "OfflineAudioContext" , js_name = resume)]
     #[doc = "The `resume()` method."]
     #[doc = ""]
@@ -485,4 +490,79 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `OfflineAudioContext`*"]
     pub fn resume(this: &OfflineAudioContext) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "AudioBuffer")]
+    # [wasm_bindgen (catch , method , structural , js_class = "OfflineAudioContext" , js_name = startRendering)]
+    #[doc = "The `startRendering()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OfflineAudioContext/startRendering)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `AudioBuffer`, `OfflineAudioContext`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn start_rendering(
+        this: &OfflineAudioContext,
+    ) -> Result<::js_sys::Promise<AudioBuffer>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "AudioBuffer")]
+    # [wasm_bindgen (catch , method , structural , js_class = "OfflineAudioContext" , js_name = decodeAudioData)]
+    #[doc = "The `decodeAudioData()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OfflineAudioContext/decodeAudioData)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `AudioBuffer`, `OfflineAudioContext`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn decode_audio_data(
+        this: &OfflineAudioContext,
+        audio_data: &::js_sys::ArrayBuffer,
+    ) -> Result<::js_sys::Promise<AudioBuffer>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "AudioBuffer")]
+    # [wasm_bindgen (catch , method , structural , js_class = "OfflineAudioContext" , js_name = decodeAudioData)]
+    #[doc = "The `decodeAudioData()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OfflineAudioContext/decodeAudioData)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `AudioBuffer`, `OfflineAudioContext`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn decode_audio_data_with_success_callback(
+        this: &OfflineAudioContext,
+        audio_data: &::js_sys::ArrayBuffer,
+        success_callback: &::js_sys::Function,
+    ) -> Result<::js_sys::Promise<AudioBuffer>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "AudioBuffer")]
+    # [wasm_bindgen (catch , method , structural , js_class = "OfflineAudioContext" , js_name = decodeAudioData)]
+    #[doc = "The `decodeAudioData()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OfflineAudioContext/decodeAudioData)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `AudioBuffer`, `OfflineAudioContext`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn decode_audio_data_with_success_callback_and_error_callback(
+        this: &OfflineAudioContext,
+        audio_data: &::js_sys::ArrayBuffer,
+        success_callback: &::js_sys::Function,
+        error_callback: &::js_sys::Function,
+    ) -> Result<::js_sys::Promise<AudioBuffer>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "OfflineAudioContext" , js_name = resume)]
+    #[doc = "The `resume()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/OfflineAudioContext/resume)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `OfflineAudioContext`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn resume(
+        this: &OfflineAudioContext,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
 }
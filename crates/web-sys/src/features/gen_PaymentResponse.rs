@@ -69,6 +69,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PaymentResponse`*"]
     pub fn payer_phone(this: &PaymentResponse) -> Option<::alloc::string::String>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "PaymentResponse" , js_name = complete)]
     #[doc = "The `complete()` method."]
     #[doc = ""]
@@ -76,6 +77,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PaymentResponse`*"]
     pub fn complete(this: &PaymentResponse) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "PaymentComplete")]
     # [wasm_bindgen (method , structural , js_class = "PaymentResponse" , js_name = complete)]
     #[doc = "The `complete()` method."]
@@ -94,4 +96,30 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PaymentResponse`*"]
     pub fn to_json(this: &PaymentResponse) -> ::js_sys::Object;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "PaymentResponse" , js_name = complete)]
+    #[doc = "The `complete()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PaymentResponse/complete)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PaymentResponse`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn complete(this: &PaymentResponse) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "PaymentComplete")]
+    # [wasm_bindgen (method , structural , js_class = "PaymentResponse" , js_name = complete)]
+    #[doc = "The `complete()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PaymentResponse/complete)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PaymentComplete`, `PaymentResponse`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn complete_with_result(
+        this: &PaymentResponse,
+        result: PaymentComplete,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Permissions`*"]
     pub type Permissions;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Permissions" , js_name = query)]
     #[doc = "The `query()` method."]
     #[doc = ""]
@@ -22,6 +23,7 @@ extern "C" {
         this: &Permissions,
         permission: &::js_sys::Object,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Permissions" , js_name = revoke)]
     #[doc = "The `revoke()` method."]
     #[doc = ""]
@@ -32,4 +34,34 @@ extern "C" {
         this: &Permissions,
         permission: &::js_sys::Object,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "PermissionStatus")]
+    # [wasm_bindgen (catch , method , structural , js_class = "Permissions" , js_name = query)]
+    #[doc = "The `query()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Permissions/query)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PermissionStatus`, `Permissions`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn query(
+        this: &Permissions,
+        permission: &::js_sys::Object,
+    ) -> Result<::js_sys::Promise<PermissionStatus>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "PermissionStatus")]
+    # [wasm_bindgen (catch , method , structural , js_class = "Permissions" , js_name = revoke)]
+    #[doc = "The `revoke()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Permissions/revoke)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PermissionStatus`, `Permissions`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn revoke(
+        this: &Permissions,
+        permission: &::js_sys::Object,
+    ) -> Result<::js_sys::Promise<PermissionStatus>, JsValue>;
 }
@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PresentationReceiver`*"]
     pub type PresentationReceiver;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (structural , catch , method , getter , js_class = "PresentationReceiver" , js_name = connectionList)]
     #[doc = "Getter for the `connectionList` field of this object."]
     #[doc = ""]
@@ -19,4 +20,18 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PresentationReceiver`*"]
     pub fn connection_list(this: &PresentationReceiver) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "PresentationConnectionList")]
+    # [wasm_bindgen (structural , catch , method , getter , js_class = "PresentationReceiver" , js_name = connectionList)]
+    #[doc = "Getter for the `connectionList` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PresentationReceiver/connectionList)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PresentationConnectionList`, `PresentationReceiver`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn connection_list(
+        this: &PresentationReceiver,
+    ) -> Result<::js_sys::Promise<PresentationConnectionList>, JsValue>;
 }
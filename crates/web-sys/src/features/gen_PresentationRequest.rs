@@ -43,6 +43,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PresentationRequest`*"]
     pub fn new_with_urls(urls: &::wasm_bindgen::JsValue) -> Result<PresentationRequest, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "PresentationRequest" , js_name = getAvailability)]
     #[doc = "The `getAvailability()` method."]
     #[doc = ""]
@@ -50,6 +51,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PresentationRequest`*"]
     pub fn get_availability(this: &PresentationRequest) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "PresentationRequest" , js_name = reconnect)]
     #[doc = "The `reconnect()` method."]
     #[doc = ""]
@@ -60,6 +62,7 @@ extern "C" {
         this: &PresentationRequest,
         presentation_id: &str,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "PresentationRequest" , js_name = start)]
     #[doc = "The `start()` method."]
     #[doc = ""]
@@ -67,4 +70,47 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PresentationRequest`*"]
     pub fn start(this: &PresentationRequest) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "PresentationAvailability")]
+    # [wasm_bindgen (catch , method , structural , js_class = "PresentationRequest" , js_name = getAvailability)]
+    #[doc = "The `getAvailability()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PresentationRequest/getAvailability)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PresentationAvailability`, `PresentationRequest`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_availability(
+        this: &PresentationRequest,
+    ) -> Result<::js_sys::Promise<PresentationAvailability>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "PresentationConnection")]
+    # [wasm_bindgen (catch , method , structural , js_class = "PresentationRequest" , js_name = reconnect)]
+    #[doc = "The `reconnect()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PresentationRequest/reconnect)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PresentationConnection`, `PresentationRequest`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn reconnect(
+        this: &PresentationRequest,
+        presentation_id: &str,
+    ) -> Result<::js_sys::Promise<PresentationConnection>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "PresentationConnection")]
+    # [wasm_bindgen (catch , method , structural , js_class = "PresentationRequest" , js_name = start)]
+    #[doc = "The `start()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PresentationRequest/start)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PresentationConnection`, `PresentationRequest`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn start(
+        this: &PresentationRequest,
+    ) -> Result<::js_sys::Promise<PresentationConnection>, JsValue>;
 }
@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PushManager`*"]
     pub type PushManager;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "PushManager" , js_name = getSubscription)]
     #[doc = "The `getSubscription()` method."]
     #[doc = ""]
@@ -19,6 +20,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PushManager`*"]
     pub fn get_subscription(this: &PushManager) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "PushManager" , js_name = permissionState)]
     #[doc = "The `permissionState()` method."]
     #[doc = ""]
@@ -26,6 +28,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PushManager`*"]
     pub fn permission_state(this: &PushManager) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "PushSubscriptionOptionsInit")]
     # [wasm_bindgen (catch , method , structural , js_class = "PushManager" , js_name = permissionState)]
     #[doc = "The `permissionState()` method."]
@@ -37,6 +40,7 @@ extern "C" {
         this: &PushManager,
         options: &PushSubscriptionOptionsInit,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "PushManager" , js_name = subscribe)]
     #[doc = "The `subscribe()` method."]
     #[doc = ""]
@@ -44,6 +48,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PushManager`*"]
     pub fn subscribe(this: &PushManager) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "PushSubscriptionOptionsInit")]
     # [wasm_bindgen (catch , method , structural , js_class = "PushManager" , js_name = subscribe)]
     #[doc = "The `subscribe()` method."]
@@ -55,4 +60,77 @@ extern "C" {
         this: &PushManager,
         options: &PushSubscriptionOptionsInit,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "PushSubscription")]
+    # [wasm_bindgen (catch , method , structural , js_class = "PushManager" , js_name = getSubscription)]
+    #[doc = "The `getSubscription()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PushManager/getSubscription)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PushManager`, `PushSubscription`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_subscription(
+        this: &PushManager,
+    ) -> Result<::js_sys::Promise<::js_sys::JsOption<PushSubscription>>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "PushPermissionState")]
+    # [wasm_bindgen (catch , method , structural , js_class = "PushManager" , js_name = permissionState)]
+    #[doc = "The `permissionState()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PushManager/permissionState)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PushManager`, `PushPermissionState`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn permission_state(
+        this: &PushManager,
+    ) -> Result<::js_sys::Promise<PushPermissionState>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(
+        feature = "PushPermissionState",
+        feature = "PushSubscriptionOptionsInit",
+    ))]
+    # [wasm_bindgen (catch , method , structural , js_class = "PushManager" , js_name = permissionState)]
+    #[doc = "The `permissionState()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PushManager/permissionState)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PushManager`, `PushPermissionState`, `PushSubscriptionOptionsInit`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn permission_state_with_options(
+        this: &PushManager,
+        options: &PushSubscriptionOptionsInit,
+    ) -> Result<::js_sys::Promise<PushPermissionState>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "PushSubscription")]
+    # [wasm_bindgen (catch , method , structural , js_class = "PushManager" , js_name = subscribe)]
+    #[doc = "The `subscribe()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PushManager/subscribe)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PushManager`, `PushSubscription`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn subscribe(this: &PushManager) -> Result<::js_sys::Promise<PushSubscription>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "PushSubscription", feature = "PushSubscriptionOptionsInit",))]
+    # [wasm_bindgen (catch , method , structural , js_class = "PushManager" , js_name = subscribe)]
+    #[doc = "The `subscribe()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PushManager/subscribe)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PushManager`, `PushSubscription`, `PushSubscriptionOptionsInit`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn subscribe_with_options(
+        this: &PushManager,
+        options: &PushSubscriptionOptionsInit,
+    ) -> Result<::js_sys::Promise<PushSubscription>, JsValue>;
 }
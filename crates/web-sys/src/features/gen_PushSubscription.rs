@@ -46,6 +46,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PushSubscription`, `PushSubscriptionJson`*"]
     pub fn to_json(this: &PushSubscription) -> Result<PushSubscriptionJson, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "PushSubscription" , js_name = unsubscribe)]
     #[doc = "The `unsubscribe()` method."]
     #[doc = ""]
@@ -53,4 +54,17 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `PushSubscription`*"]
     pub fn unsubscribe(this: &PushSubscription) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "PushSubscription" , js_name = unsubscribe)]
+    #[doc = "The `unsubscribe()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/PushSubscription/unsubscribe)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `PushSubscription`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn unsubscribe(
+        this: &PushSubscription,
+    ) -> Result<::js_sys::Promise<::js_sys::Boolean>, JsValue>;
 }
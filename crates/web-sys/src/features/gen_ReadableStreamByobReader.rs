@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ReadableStreamByobReader`*"]
     pub type ReadableStreamByobReader;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (structural , method , getter , js_class = "ReadableStreamBYOBReader" , js_name = closed)]
     #[doc = "Getter for the `closed` field of this object."]
     #[doc = ""]
@@ -19,6 +20,17 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ReadableStreamByobReader`*"]
     pub fn closed(this: &ReadableStreamByobReader) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (structural , method , getter , js_class = "ReadableStreamBYOBReader" , js_name = closed)]
+    #[doc = "Getter for the `closed` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamBYOBReader/closed)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `ReadableStreamByobReader`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn closed(this: &ReadableStreamByobReader) -> ::js_sys::Promise<::js_sys::Undefined>;
     #[cfg(feature = "ReadableStream")]
     #[wasm_bindgen(catch, constructor, js_class = "ReadableStreamBYOBReader")]
     #[doc = "The `new ReadableStreamByobReader(..)` constructor, creating a new instance of `ReadableStreamByobReader`."]
@@ -27,6 +39,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ReadableStream`, `ReadableStreamByobReader`*"]
     pub fn new(stream: &ReadableStream) -> Result<ReadableStreamByobReader, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "ReadableStreamBYOBReader" , js_name = read)]
     #[doc = "The `read()` method."]
     #[doc = ""]
@@ -44,6 +57,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ReadableStreamByobReader`*"]
     pub fn release_lock(this: &ReadableStreamByobReader);
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "ReadableStreamBYOBReader" , js_name = cancel)]
     #[doc = "The `cancel()` method."]
     #[doc = ""]
@@ -51,6 +65,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ReadableStreamByobReader`*"]
     pub fn cancel(this: &ReadableStreamByobReader) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "ReadableStreamBYOBReader" , js_name = cancel)]
     #[doc = "The `cancel()` method."]
     #[doc = ""]
@@ -61,4 +76,44 @@ extern "C" {
         this: &ReadableStreamByobReader,
         reason: &::wasm_bindgen::JsValue,
     ) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "ReadableStreamReadResult")]
+    # [wasm_bindgen (method , structural , js_class = "ReadableStreamBYOBReader" , js_name = read)]
+    #[doc = "The `read()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamBYOBReader/read)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `ReadableStreamByobReader`, `ReadableStreamReadResult`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn read_with_array_buffer_view(
+        this: &ReadableStreamByobReader,
+        view: &::js_sys::Object,
+    ) -> ::js_sys::Promise<ReadableStreamReadResult>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "ReadableStreamBYOBReader" , js_name = cancel)]
+    #[doc = "The `cancel()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamBYOBReader/cancel)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `ReadableStreamByobReader`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn cancel(this: &ReadableStreamByobReader) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "ReadableStreamBYOBReader" , js_name = cancel)]
+    #[doc = "The `cancel()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamBYOBReader/cancel)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `ReadableStreamByobReader`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn cancel_with_reason(
+        this: &ReadableStreamByobReader,
+        reason: &::wasm_bindgen::JsValue,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
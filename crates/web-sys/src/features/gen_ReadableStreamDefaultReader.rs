@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ReadableStreamDefaultReader`*"]
     pub type ReadableStreamDefaultReader;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (structural , method , getter , js_class = "ReadableStreamDefaultReader" , js_name = closed)]
     #[doc = "Getter for the `closed` field of this object."]
     #[doc = ""]
@@ -19,6 +20,17 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ReadableStreamDefaultReader`*"]
     pub fn closed(this: &ReadableStreamDefaultReader) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (structural , method , getter , js_class = "ReadableStreamDefaultReader" , js_name = closed)]
+    #[doc = "Getter for the `closed` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader/closed)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `ReadableStreamDefaultReader`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn closed(this: &ReadableStreamDefaultReader) -> ::js_sys::Promise<::js_sys::Undefined>;
     #[cfg(feature = "ReadableStream")]
     #[wasm_bindgen(catch, constructor, js_class = "ReadableStreamDefaultReader")]
     #[doc = "The `new ReadableStreamDefaultReader(..)` constructor, creating a new instance of `ReadableStreamDefaultReader`."]
@@ -27,6 +39,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ReadableStream`, `ReadableStreamDefaultReader`*"]
     pub fn new(stream: &ReadableStream) -> Result<ReadableStreamDefaultReader, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "ReadableStreamDefaultReader" , js_name = read)]
     #[doc = "The `read()` method."]
     #[doc = ""]
@@ -41,6 +54,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ReadableStreamDefaultReader`*"]
     pub fn release_lock(this: &ReadableStreamDefaultReader);
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "ReadableStreamDefaultReader" , js_name = cancel)]
     #[doc = "The `cancel()` method."]
     #[doc = ""]
@@ -48,6 +62,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ReadableStreamDefaultReader`*"]
     pub fn cancel(this: &ReadableStreamDefaultReader) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "ReadableStreamDefaultReader" , js_name = cancel)]
     #[doc = "The `cancel()` method."]
     #[doc = ""]
@@ -58,4 +73,41 @@ extern "C" {
         this: &ReadableStreamDefaultReader,
         reason: &::wasm_bindgen::JsValue,
     ) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "ReadableStreamReadResult")]
+    # [wasm_bindgen (method , structural , js_class = "ReadableStreamDefaultReader" , js_name = read)]
+    #[doc = "The `read()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader/read)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `ReadableStreamDefaultReader`, `ReadableStreamReadResult`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn read(this: &ReadableStreamDefaultReader) -> ::js_sys::Promise<ReadableStreamReadResult>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "ReadableStreamDefaultReader" , js_name = cancel)]
+    #[doc = "The `cancel()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader/cancel)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `ReadableStreamDefaultReader`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn cancel(this: &ReadableStreamDefaultReader) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "ReadableStreamDefaultReader" , js_name = cancel)]
+    #[doc = "The `cancel()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ReadableStreamDefaultReader/cancel)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `ReadableStreamDefaultReader`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn cancel_with_reason(
+        this: &ReadableStreamDefaultReader,
+        reason: &::wasm_bindgen::JsValue,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
@@ -159,6 +159,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Request`*"]
     pub fn clone(this: &Request) -> Result<Request, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Request" , js_name = arrayBuffer)]
     #[doc = "The `arrayBuffer()` method."]
     #[doc = ""]
@@ -166,6 +167,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Request`*"]
     pub fn array_buffer(this: &Request) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Request" , js_name = blob)]
     #[doc = "The `blob()` method."]
     #[doc = ""]
@@ -173,6 +175,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Request`*"]
     pub fn blob(this: &Request) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Request" , js_name = formData)]
     #[doc = "The `formData()` method."]
     #[doc = ""]
@@ -180,6 +183,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Request`*"]
     pub fn form_data(this: &Request) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Request" , js_name = json)]
     #[doc = "The `json()` method."]
     #[doc = ""]
@@ -187,6 +191,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Request`*"]
     pub fn json(this: &Request) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Request" , js_name = text)]
     #[doc = "The `text()` method."]
     #[doc = ""]
@@ -194,4 +199,63 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Request`*"]
     pub fn text(this: &Request) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "Request" , js_name = arrayBuffer)]
+    #[doc = "The `arrayBuffer()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Request/arrayBuffer)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn array_buffer(
+        this: &Request,
+    ) -> Result<::js_sys::Promise<::js_sys::ArrayBuffer>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "Blob")]
+    # [wasm_bindgen (catch , method , structural , js_class = "Request" , js_name = blob)]
+    #[doc = "The `blob()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Request/blob)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Blob`, `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn blob(this: &Request) -> Result<::js_sys::Promise<Blob>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "FormData")]
+    # [wasm_bindgen (catch , method , structural , js_class = "Request" , js_name = formData)]
+    #[doc = "The `formData()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Request/formData)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FormData`, `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn form_data(this: &Request) -> Result<::js_sys::Promise<FormData>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "Request" , js_name = json)]
+    #[doc = "The `json()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Request/json)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn json(this: &Request) -> Result<::js_sys::Promise<::js_sys::Object>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "Request" , js_name = text)]
+    #[doc = "The `text()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Request/text)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Request`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn text(this: &Request) -> Result<::js_sys::Promise<::js_sys::JsString>, JsValue>;
 }
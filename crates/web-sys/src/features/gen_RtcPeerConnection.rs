@@ -99,6 +99,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`, `RtcPeerConnectionState`*"]
     pub fn connection_state(this: &RtcPeerConnection) -> RtcPeerConnectionState;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (structural , method , getter , js_class = "RTCPeerConnection" , js_name = peerIdentity)]
     #[doc = "Getter for the `peerIdentity` field of this object."]
     #[doc = ""]
@@ -106,6 +107,18 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`*"]
     pub fn peer_identity(this: &RtcPeerConnection) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcIdentityAssertion")]
+    # [wasm_bindgen (structural , method , getter , js_class = "RTCPeerConnection" , js_name = peerIdentity)]
+    #[doc = "Getter for the `peerIdentity` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/peerIdentity)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcIdentityAssertion`, `RtcPeerConnection`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn peer_identity(this: &RtcPeerConnection) -> ::js_sys::Promise<RtcIdentityAssertion>;
     # [wasm_bindgen (structural , method , getter , js_class = "RTCPeerConnection" , js_name = idpLoginUrl)]
     #[doc = "Getter for the `idpLoginUrl` field of this object."]
     #[doc = ""]
@@ -304,6 +317,7 @@ extern "C" {
         configuration: &RtcConfiguration,
         constraints: Option<&::js_sys::Object>,
     ) -> Result<RtcPeerConnection, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RtcIceCandidateInit")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = addIceCandidate)]
     #[doc = "The `addIceCandidate()` method."]
@@ -315,6 +329,7 @@ extern "C" {
         this: &RtcPeerConnection,
         candidate: Option<&RtcIceCandidateInit>,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RtcIceCandidate")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = addIceCandidate)]
     #[doc = "The `addIceCandidate()` method."]
@@ -326,6 +341,7 @@ extern "C" {
         this: &RtcPeerConnection,
         candidate: Option<&RtcIceCandidate>,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RtcIceCandidate")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = addIceCandidate)]
     #[doc = "The `addIceCandidate()` method."]
@@ -577,6 +593,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`*"]
     pub fn close(this: &RtcPeerConnection);
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createAnswer)]
     #[doc = "The `createAnswer()` method."]
     #[doc = ""]
@@ -584,6 +601,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`*"]
     pub fn create_answer(this: &RtcPeerConnection) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RtcAnswerOptions")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createAnswer)]
     #[doc = "The `createAnswer()` method."]
@@ -595,6 +613,7 @@ extern "C" {
         this: &RtcPeerConnection,
         options: &RtcAnswerOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createAnswer)]
     #[doc = "The `createAnswer()` method."]
     #[doc = ""]
@@ -626,6 +645,7 @@ extern "C" {
         label: &str,
         data_channel_dict: &RtcDataChannelInit,
     ) -> RtcDataChannel;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createOffer)]
     #[doc = "The `createOffer()` method."]
     #[doc = ""]
@@ -633,6 +653,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`*"]
     pub fn create_offer(this: &RtcPeerConnection) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RtcOfferOptions")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createOffer)]
     #[doc = "The `createOffer()` method."]
@@ -644,6 +665,7 @@ extern "C" {
         this: &RtcPeerConnection,
         options: &RtcOfferOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createOffer)]
     #[doc = "The `createOffer()` method."]
     #[doc = ""]
@@ -655,6 +677,7 @@ extern "C" {
         success_callback: &::js_sys::Function,
         failure_callback: &::js_sys::Function,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RtcOfferOptions")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createOffer)]
     #[doc = "The `createOffer()` method."]
@@ -668,6 +691,7 @@ extern "C" {
         failure_callback: &::js_sys::Function,
         options: &RtcOfferOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , static_method_of = RtcPeerConnection , js_class = "RTCPeerConnection" , js_name = generateCertificate)]
     #[doc = "The `generateCertificate()` method."]
     #[doc = ""]
@@ -677,6 +701,7 @@ extern "C" {
     pub fn generate_certificate_with_object(
         keygen_algorithm: &::js_sys::Object,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , static_method_of = RtcPeerConnection , js_class = "RTCPeerConnection" , js_name = generateCertificate)]
     #[doc = "The `generateCertificate()` method."]
     #[doc = ""]
@@ -694,6 +719,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcConfiguration`, `RtcPeerConnection`*"]
     pub fn get_configuration(this: &RtcPeerConnection) -> RtcConfiguration;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = getIdentityAssertion)]
     #[doc = "The `getIdentityAssertion()` method."]
     #[doc = ""]
@@ -729,6 +755,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`*"]
     pub fn get_senders(this: &RtcPeerConnection) -> ::js_sys::Array;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = getStats)]
     #[doc = "The `getStats()` method."]
     #[doc = ""]
@@ -736,6 +763,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`*"]
     pub fn get_stats(this: &RtcPeerConnection) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "MediaStreamTrack")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = getStats)]
     #[doc = "The `getStats()` method."]
@@ -747,6 +775,7 @@ extern "C" {
         this: &RtcPeerConnection,
         selector: Option<&MediaStreamTrack>,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "MediaStreamTrack")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = getStats)]
     #[doc = "The `getStats()` method."]
@@ -812,6 +841,7 @@ extern "C" {
         provider: &str,
         options: &RtcIdentityProviderOptions,
     );
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RtcSessionDescriptionInit")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = setLocalDescription)]
     #[doc = "The `setLocalDescription()` method."]
@@ -823,6 +853,7 @@ extern "C" {
         this: &RtcPeerConnection,
         description: &RtcSessionDescriptionInit,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RtcSessionDescriptionInit")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = setLocalDescription)]
     #[doc = "The `setLocalDescription()` method."]
@@ -836,6 +867,7 @@ extern "C" {
         success_callback: &::js_sys::Function,
         failure_callback: &::js_sys::Function,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RtcSessionDescriptionInit")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = setRemoteDescription)]
     #[doc = "The `setRemoteDescription()` method."]
@@ -847,6 +879,7 @@ extern "C" {
         this: &RtcPeerConnection,
         description: &RtcSessionDescriptionInit,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RtcSessionDescriptionInit")]
     # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = setRemoteDescription)]
     #[doc = "The `setRemoteDescription()` method."]
@@ -860,4 +893,301 @@ extern "C" {
         success_callback: &::js_sys::Function,
         failure_callback: &::js_sys::Function,
     ) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcIceCandidateInit")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = addIceCandidate)]
+    #[doc = "The `addIceCandidate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/addIceCandidate)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcIceCandidateInit`, `RtcPeerConnection`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn add_ice_candidate_with_opt_rtc_ice_candidate_init(
+        this: &RtcPeerConnection,
+        candidate: Option<&RtcIceCandidateInit>,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcIceCandidate")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = addIceCandidate)]
+    #[doc = "The `addIceCandidate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/addIceCandidate)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcIceCandidate`, `RtcPeerConnection`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn add_ice_candidate_with_opt_rtc_ice_candidate(
+        this: &RtcPeerConnection,
+        candidate: Option<&RtcIceCandidate>,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcIceCandidate")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = addIceCandidate)]
+    #[doc = "The `addIceCandidate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/addIceCandidate)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcIceCandidate`, `RtcPeerConnection`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn add_ice_candidate_with_rtc_ice_candidate_and_success_callback_and_failure_callback(
+        this: &RtcPeerConnection,
+        candidate: &RtcIceCandidate,
+        success_callback: &::js_sys::Function,
+        failure_callback: &::js_sys::Function,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcSessionDescriptionInit")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createAnswer)]
+    #[doc = "The `createAnswer()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/createAnswer)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`, `RtcSessionDescriptionInit`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_answer(this: &RtcPeerConnection) -> ::js_sys::Promise<RtcSessionDescriptionInit>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "RtcAnswerOptions", feature = "RtcSessionDescriptionInit",))]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createAnswer)]
+    #[doc = "The `createAnswer()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/createAnswer)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcAnswerOptions`, `RtcPeerConnection`, `RtcSessionDescriptionInit`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_answer_with_rtc_answer_options(
+        this: &RtcPeerConnection,
+        options: &RtcAnswerOptions,
+    ) -> ::js_sys::Promise<RtcSessionDescriptionInit>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createAnswer)]
+    #[doc = "The `createAnswer()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/createAnswer)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_answer_with_success_callback_and_failure_callback(
+        this: &RtcPeerConnection,
+        success_callback: &::js_sys::Function,
+        failure_callback: &::js_sys::Function,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcSessionDescriptionInit")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createOffer)]
+    #[doc = "The `createOffer()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/createOffer)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`, `RtcSessionDescriptionInit`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_offer(this: &RtcPeerConnection) -> ::js_sys::Promise<RtcSessionDescriptionInit>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "RtcOfferOptions", feature = "RtcSessionDescriptionInit",))]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createOffer)]
+    #[doc = "The `createOffer()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/createOffer)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcOfferOptions`, `RtcPeerConnection`, `RtcSessionDescriptionInit`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_offer_with_rtc_offer_options(
+        this: &RtcPeerConnection,
+        options: &RtcOfferOptions,
+    ) -> ::js_sys::Promise<RtcSessionDescriptionInit>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createOffer)]
+    #[doc = "The `createOffer()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/createOffer)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_offer_with_callback_and_failure_callback(
+        this: &RtcPeerConnection,
+        success_callback: &::js_sys::Function,
+        failure_callback: &::js_sys::Function,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcOfferOptions")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = createOffer)]
+    #[doc = "The `createOffer()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/createOffer)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcOfferOptions`, `RtcPeerConnection`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn create_offer_with_callback_and_failure_callback_and_options(
+        this: &RtcPeerConnection,
+        success_callback: &::js_sys::Function,
+        failure_callback: &::js_sys::Function,
+        options: &RtcOfferOptions,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcCertificate")]
+    # [wasm_bindgen (catch , static_method_of = RtcPeerConnection , js_class = "RTCPeerConnection" , js_name = generateCertificate)]
+    #[doc = "The `generateCertificate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/generateCertificate_static)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcCertificate`, `RtcPeerConnection`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn generate_certificate_with_object(
+        keygen_algorithm: &::js_sys::Object,
+    ) -> Result<::js_sys::Promise<RtcCertificate>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcCertificate")]
+    # [wasm_bindgen (catch , static_method_of = RtcPeerConnection , js_class = "RTCPeerConnection" , js_name = generateCertificate)]
+    #[doc = "The `generateCertificate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/generateCertificate_static)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcCertificate`, `RtcPeerConnection`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn generate_certificate_with_str(
+        keygen_algorithm: &str,
+    ) -> Result<::js_sys::Promise<RtcCertificate>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = getIdentityAssertion)]
+    #[doc = "The `getIdentityAssertion()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/getIdentityAssertion)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_identity_assertion(
+        this: &RtcPeerConnection,
+    ) -> ::js_sys::Promise<::js_sys::JsString>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcStatsReport")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = getStats)]
+    #[doc = "The `getStats()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/getStats)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`, `RtcStatsReport`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_stats(this: &RtcPeerConnection) -> ::js_sys::Promise<RtcStatsReport>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "MediaStreamTrack", feature = "RtcStatsReport",))]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = getStats)]
+    #[doc = "The `getStats()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/getStats)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaStreamTrack`, `RtcPeerConnection`, `RtcStatsReport`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_stats_with_selector(
+        this: &RtcPeerConnection,
+        selector: Option<&MediaStreamTrack>,
+    ) -> ::js_sys::Promise<RtcStatsReport>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "MediaStreamTrack")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = getStats)]
+    #[doc = "The `getStats()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/getStats)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaStreamTrack`, `RtcPeerConnection`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_stats_with_selector_and_success_callback_and_failure_callback(
+        this: &RtcPeerConnection,
+        selector: Option<&MediaStreamTrack>,
+        success_callback: &::js_sys::Function,
+        failure_callback: &::js_sys::Function,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcSessionDescriptionInit")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = setLocalDescription)]
+    #[doc = "The `setLocalDescription()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/setLocalDescription)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`, `RtcSessionDescriptionInit`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_local_description(
+        this: &RtcPeerConnection,
+        description: &RtcSessionDescriptionInit,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcSessionDescriptionInit")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = setLocalDescription)]
+    #[doc = "The `setLocalDescription()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/setLocalDescription)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`, `RtcSessionDescriptionInit`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_local_description_with_success_callback_and_failure_callback(
+        this: &RtcPeerConnection,
+        description: &RtcSessionDescriptionInit,
+        success_callback: &::js_sys::Function,
+        failure_callback: &::js_sys::Function,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcSessionDescriptionInit")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = setRemoteDescription)]
+    #[doc = "The `setRemoteDescription()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/setRemoteDescription)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`, `RtcSessionDescriptionInit`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_remote_description(
+        this: &RtcPeerConnection,
+        description: &RtcSessionDescriptionInit,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcSessionDescriptionInit")]
+    # [wasm_bindgen (method , structural , js_class = "RTCPeerConnection" , js_name = setRemoteDescription)]
+    #[doc = "The `setRemoteDescription()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCPeerConnection/setRemoteDescription)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcPeerConnection`, `RtcSessionDescriptionInit`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_remote_description_with_success_callback_and_failure_callback(
+        this: &RtcPeerConnection,
+        description: &RtcSessionDescriptionInit,
+        success_callback: &::js_sys::Function,
+        failure_callback: &::js_sys::Function,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
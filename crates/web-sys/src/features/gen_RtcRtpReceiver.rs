@@ -101,6 +101,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcRtpReceiver`*"]
     pub fn get_contributing_sources(this: &RtcRtpReceiver) -> ::js_sys::Array;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "RTCRtpReceiver" , js_name = getStats)]
     #[doc = "The `getStats()` method."]
     #[doc = ""]
@@ -115,4 +116,16 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcRtpReceiver`*"]
     pub fn get_synchronization_sources(this: &RtcRtpReceiver) -> ::js_sys::Array;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcStatsReport")]
+    # [wasm_bindgen (method , structural , js_class = "RTCRtpReceiver" , js_name = getStats)]
+    #[doc = "The `getStats()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCRtpReceiver/getStats)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcRtpReceiver`, `RtcStatsReport`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_stats(this: &RtcRtpReceiver) -> ::js_sys::Promise<RtcStatsReport>;
 }
@@ -110,6 +110,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcRtpParameters`, `RtcRtpSender`*"]
     pub fn get_parameters(this: &RtcRtpSender) -> RtcRtpParameters;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "RTCRtpSender" , js_name = getStats)]
     #[doc = "The `getStats()` method."]
     #[doc = ""]
@@ -117,6 +118,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcRtpSender`*"]
     pub fn get_stats(this: &RtcRtpSender) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "MediaStreamTrack")]
     # [wasm_bindgen (method , structural , js_class = "RTCRtpSender" , js_name = replaceTrack)]
     #[doc = "The `replaceTrack()` method."]
@@ -128,6 +130,7 @@ extern "C" {
         this: &RtcRtpSender,
         with_track: Option<&MediaStreamTrack>,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "RTCRtpSender" , js_name = setParameters)]
     #[doc = "The `setParameters()` method."]
     #[doc = ""]
@@ -135,6 +138,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `RtcRtpSender`*"]
     pub fn set_parameters(this: &RtcRtpSender) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RtcRtpParameters")]
     # [wasm_bindgen (method , structural , js_class = "RTCRtpSender" , js_name = setParameters)]
     #[doc = "The `setParameters()` method."]
@@ -146,4 +150,57 @@ extern "C" {
         this: &RtcRtpSender,
         parameters: &RtcRtpParameters,
     ) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcStatsReport")]
+    # [wasm_bindgen (method , structural , js_class = "RTCRtpSender" , js_name = getStats)]
+    #[doc = "The `getStats()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCRtpSender/getStats)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcRtpSender`, `RtcStatsReport`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_stats(this: &RtcRtpSender) -> ::js_sys::Promise<RtcStatsReport>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "MediaStreamTrack")]
+    # [wasm_bindgen (method , structural , js_class = "RTCRtpSender" , js_name = replaceTrack)]
+    #[doc = "The `replaceTrack()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCRtpSender/replaceTrack)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `MediaStreamTrack`, `RtcRtpSender`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn replace_track(
+        this: &RtcRtpSender,
+        with_track: Option<&MediaStreamTrack>,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (method , structural , js_class = "RTCRtpSender" , js_name = setParameters)]
+    #[doc = "The `setParameters()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCRtpSender/setParameters)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcRtpSender`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_parameters(this: &RtcRtpSender) -> ::js_sys::Promise<::js_sys::Undefined>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "RtcRtpParameters")]
+    # [wasm_bindgen (method , structural , js_class = "RTCRtpSender" , js_name = setParameters)]
+    #[doc = "The `setParameters()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/RTCRtpSender/setParameters)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RtcRtpParameters`, `RtcRtpSender`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn set_parameters_with_parameters(
+        this: &RtcRtpSender,
+        parameters: &RtcRtpParameters,
+    ) -> ::js_sys::Promise<::js_sys::Undefined>;
 }
@@ -133,13 +133,13 @@ extern "C" {
         key_id: f64,
     ) -> ::js_sys::Promise<::js_sys::Undefined>;
     #[cfg(web_sys_unstable_apis)]
-    #[cfg(all(feature = "BigInt", feature = "CryptoKey",))]
+    #[cfg(feature = "CryptoKey")]
     # [wasm_bindgen (method , structural , js_class = "SFrameTransform" , js_name = setEncryptionKey)]
     #[doc = "The `setEncryptionKey()` method."]
     #[doc = ""]
     #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/SFrameTransform/setEncryptionKey)"]
     #[doc = ""]
-    #[doc = "*This API requires the following crate features to be activated: `BigInt`, `CryptoKey`, `SFrameTransform`*"]
+    #[doc = "*This API requires the following crate features to be activated: `CryptoKey`, `SFrameTransform`*"]
     #[doc = ""]
     #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
     #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
@@ -41,6 +41,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ScreenOrientation`*"]
     pub fn set_onchange(this: &ScreenOrientation, value: Option<&::js_sys::Function>);
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "OrientationLockType")]
     # [wasm_bindgen (catch , method , structural , js_class = "ScreenOrientation" , js_name = lock)]
     #[doc = "The `lock()` method."]
@@ -59,4 +60,19 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ScreenOrientation`*"]
     pub fn unlock(this: &ScreenOrientation) -> Result<(), JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "OrientationLockType")]
+    # [wasm_bindgen (catch , method , structural , js_class = "ScreenOrientation" , js_name = lock)]
+    #[doc = "The `lock()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ScreenOrientation/lock)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `OrientationLockType`, `ScreenOrientation`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn lock(
+        this: &ScreenOrientation,
+        orientation: OrientationLockType,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
 }
@@ -20,6 +20,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ServiceWorker`, `ServiceWorkerContainer`*"]
     pub fn controller(this: &ServiceWorkerContainer) -> Option<ServiceWorker>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (structural , catch , method , getter , js_class = "ServiceWorkerContainer" , js_name = ready)]
     #[doc = "Getter for the `ready` field of this object."]
     #[doc = ""]
@@ -27,6 +28,20 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ServiceWorkerContainer`*"]
     pub fn ready(this: &ServiceWorkerContainer) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "ServiceWorkerRegistration")]
+    # [wasm_bindgen (structural , catch , method , getter , js_class = "ServiceWorkerContainer" , js_name = ready)]
+    #[doc = "Getter for the `ready` field of this object."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/ready)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `ServiceWorkerContainer`, `ServiceWorkerRegistration`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn ready(
+        this: &ServiceWorkerContainer,
+    ) -> Result<::js_sys::Promise<ServiceWorkerRegistration>, JsValue>;
     # [wasm_bindgen (structural , method , getter , js_class = "ServiceWorkerContainer" , js_name = oncontrollerchange)]
     #[doc = "Getter for the `oncontrollerchange` field of this object."]
     #[doc = ""]
@@ -89,6 +104,7 @@ extern "C" {
         this: &ServiceWorkerContainer,
         document_url: &str,
     ) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "ServiceWorkerContainer" , js_name = getRegistrations)]
     #[doc = "The `getRegistrations()` method."]
     #[doc = ""]
@@ -106,6 +122,7 @@ extern "C" {
         this: &ServiceWorkerContainer,
         url: &str,
     ) -> Result<::alloc::string::String, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "ServiceWorkerContainer" , js_name = register)]
     #[doc = "The `register()` method."]
     #[doc = ""]
@@ -113,6 +130,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `ServiceWorkerContainer`*"]
     pub fn register(this: &ServiceWorkerContainer, script_url: &str) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "RegistrationOptions")]
     # [wasm_bindgen (method , structural , js_class = "ServiceWorkerContainer" , js_name = register)]
     #[doc = "The `register()` method."]
@@ -125,4 +143,49 @@ extern "C" {
         script_url: &str,
         options: &RegistrationOptions,
     ) -> ::js_sys::Promise;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "ServiceWorkerRegistration")]
+    # [wasm_bindgen (method , structural , js_class = "ServiceWorkerContainer" , js_name = getRegistrations)]
+    #[doc = "The `getRegistrations()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/getRegistrations)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `ServiceWorkerContainer`, `ServiceWorkerRegistration`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_registrations(
+        this: &ServiceWorkerContainer,
+    ) -> ::js_sys::Promise<::js_sys::Array<ServiceWorkerRegistration>>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "ServiceWorkerRegistration")]
+    # [wasm_bindgen (method , structural , js_class = "ServiceWorkerContainer" , js_name = register)]
+    #[doc = "The `register()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/register)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `ServiceWorkerContainer`, `ServiceWorkerRegistration`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn register(
+        this: &ServiceWorkerContainer,
+        script_url: &str,
+    ) -> ::js_sys::Promise<ServiceWorkerRegistration>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(all(feature = "RegistrationOptions", feature = "ServiceWorkerRegistration",))]
+    # [wasm_bindgen (method , structural , js_class = "ServiceWorkerContainer" , js_name = register)]
+    #[doc = "The `register()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/ServiceWorkerContainer/register)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `RegistrationOptions`, `ServiceWorkerContainer`, `ServiceWorkerRegistration`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn register_with_options(
+        this: &ServiceWorkerContainer,
+        script_url: &str,
+        options: &RegistrationOptions,
+    ) -> ::js_sys::Promise<ServiceWorkerRegistration>;
 }
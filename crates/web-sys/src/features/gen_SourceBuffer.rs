@@ -224,6 +224,7 @@ extern "C" {
         this: &SourceBuffer,
         data: &::js_sys::Uint8Array,
     ) -> Result<(), JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "SourceBuffer" , js_name = appendBufferAsync)]
     #[doc = "The `appendBufferAsync()` method."]
     #[doc = ""]
@@ -234,6 +235,7 @@ extern "C" {
         this: &SourceBuffer,
         data: &::js_sys::ArrayBuffer,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "SourceBuffer" , js_name = appendBufferAsync)]
     #[doc = "The `appendBufferAsync()` method."]
     #[doc = ""]
@@ -244,6 +246,7 @@ extern "C" {
         this: &SourceBuffer,
         data: &::js_sys::Object,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "SourceBuffer" , js_name = appendBufferAsync)]
     #[doc = "The `appendBufferAsync()` method."]
     #[doc = ""]
@@ -254,6 +257,7 @@ extern "C" {
         this: &SourceBuffer,
         data: &mut [u8],
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "SourceBuffer" , js_name = appendBufferAsync)]
     #[doc = "The `appendBufferAsync()` method."]
     #[doc = ""]
@@ -278,6 +282,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `SourceBuffer`*"]
     pub fn remove(this: &SourceBuffer, start: f64, end: f64) -> Result<(), JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "SourceBuffer" , js_name = removeAsync)]
     #[doc = "The `removeAsync()` method."]
     #[doc = ""]
@@ -289,4 +294,75 @@ extern "C" {
         start: f64,
         end: f64,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "SourceBuffer" , js_name = appendBufferAsync)]
+    #[doc = "The `appendBufferAsync()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/SourceBuffer/appendBufferAsync)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `SourceBuffer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn append_buffer_async_with_array_buffer(
+        this: &SourceBuffer,
+        data: &::js_sys::ArrayBuffer,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "SourceBuffer" , js_name = appendBufferAsync)]
+    #[doc = "The `appendBufferAsync()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/SourceBuffer/appendBufferAsync)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `SourceBuffer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn append_buffer_async_with_array_buffer_view(
+        this: &SourceBuffer,
+        data: &::js_sys::Object,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "SourceBuffer" , js_name = appendBufferAsync)]
+    #[doc = "The `appendBufferAsync()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/SourceBuffer/appendBufferAsync)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `SourceBuffer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn append_buffer_async_with_u8_array(
+        this: &SourceBuffer,
+        data: &mut [u8],
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "SourceBuffer" , js_name = appendBufferAsync)]
+    #[doc = "The `appendBufferAsync()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/SourceBuffer/appendBufferAsync)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `SourceBuffer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn append_buffer_async_with_js_u8_array(
+        this: &SourceBuffer,
+        data: &::js_sys::Uint8Array,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "SourceBuffer" , js_name = removeAsync)]
+    #[doc = "The `removeAsync()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/SourceBuffer/removeAsync)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `SourceBuffer`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn remove_async(
+        this: &SourceBuffer,
+        start: f64,
+        end: f64,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
 }
@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `StorageManager`*"]
     pub type StorageManager;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "StorageManager" , js_name = estimate)]
     #[doc = "The `estimate()` method."]
     #[doc = ""]
@@ -19,6 +20,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `StorageManager`*"]
     pub fn estimate(this: &StorageManager) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (method , structural , js_class = "StorageManager" , js_name = getDirectory)]
     #[doc = "The `getDirectory()` method."]
     #[doc = ""]
@@ -26,6 +28,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `StorageManager`*"]
     pub fn get_directory(this: &StorageManager) -> ::js_sys::Promise;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "StorageManager" , js_name = persist)]
     #[doc = "The `persist()` method."]
     #[doc = ""]
@@ -33,6 +36,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `StorageManager`*"]
     pub fn persist(this: &StorageManager) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "StorageManager" , js_name = persisted)]
     #[doc = "The `persisted()` method."]
     #[doc = ""]
@@ -40,4 +44,52 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `StorageManager`*"]
     pub fn persisted(this: &StorageManager) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "StorageEstimate")]
+    # [wasm_bindgen (catch , method , structural , js_class = "StorageManager" , js_name = estimate)]
+    #[doc = "The `estimate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StorageManager/estimate)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `StorageEstimate`, `StorageManager`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn estimate(this: &StorageManager) -> Result<::js_sys::Promise<StorageEstimate>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "FileSystemDirectoryHandle")]
+    # [wasm_bindgen (method , structural , js_class = "StorageManager" , js_name = getDirectory)]
+    #[doc = "The `getDirectory()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StorageManager/getDirectory)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `FileSystemDirectoryHandle`, `StorageManager`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn get_directory(this: &StorageManager) -> ::js_sys::Promise<FileSystemDirectoryHandle>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "StorageManager" , js_name = persist)]
+    #[doc = "The `persist()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StorageManager/persist)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `StorageManager`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn persist(this: &StorageManager) -> Result<::js_sys::Promise<::js_sys::Boolean>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "StorageManager" , js_name = persisted)]
+    #[doc = "The `persisted()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/StorageManager/persisted)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `StorageManager`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn persisted(
+        this: &StorageManager,
+    ) -> Result<::js_sys::Promise<::js_sys::Boolean>, JsValue>;
 }
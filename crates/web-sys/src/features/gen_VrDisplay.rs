@@ -91,6 +91,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `VrDisplay`*"]
     pub fn cancel_animation_frame(this: &VrDisplay, handle: i32) -> Result<(), JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "VRDisplay" , js_name = exitPresent)]
     #[doc = "The `exitPresent()` method."]
     #[doc = ""]
@@ -147,6 +148,7 @@ extern "C" {
         this: &VrDisplay,
         callback: &::js_sys::Function,
     ) -> Result<i32, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "VRDisplay" , js_name = requestPresent)]
     #[doc = "The `requestPresent()` method."]
     #[doc = ""]
@@ -171,4 +173,31 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `VrDisplay`*"]
     pub fn submit_frame(this: &VrDisplay);
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "VRDisplay" , js_name = exitPresent)]
+    #[doc = "The `exitPresent()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/VRDisplay/exitPresent)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `VrDisplay`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn exit_present(
+        this: &VrDisplay,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "VRDisplay" , js_name = requestPresent)]
+    #[doc = "The `requestPresent()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/VRDisplay/requestPresent)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `VrDisplay`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn request_present(
+        this: &VrDisplay,
+        layers: &::wasm_bindgen::JsValue,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
 }
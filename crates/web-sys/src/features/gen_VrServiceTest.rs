@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `VrServiceTest`*"]
     pub type VrServiceTest;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "VRServiceTest" , js_name = attachVRController)]
     #[doc = "The `attachVRController()` method."]
     #[doc = ""]
@@ -22,6 +23,7 @@ extern "C" {
         this: &VrServiceTest,
         id: &str,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "VRServiceTest" , js_name = attachVRDisplay)]
     #[doc = "The `attachVRDisplay()` method."]
     #[doc = ""]
@@ -29,4 +31,34 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `VrServiceTest`*"]
     pub fn attach_vr_display(this: &VrServiceTest, id: &str) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "VrMockController")]
+    # [wasm_bindgen (catch , method , structural , js_class = "VRServiceTest" , js_name = attachVRController)]
+    #[doc = "The `attachVRController()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/VRServiceTest/attachVRController)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `VrMockController`, `VrServiceTest`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn attach_vr_controller(
+        this: &VrServiceTest,
+        id: &str,
+    ) -> Result<::js_sys::Promise<VrMockController>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "VrMockDisplay")]
+    # [wasm_bindgen (catch , method , structural , js_class = "VRServiceTest" , js_name = attachVRDisplay)]
+    #[doc = "The `attachVRDisplay()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/VRServiceTest/attachVRDisplay)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `VrMockDisplay`, `VrServiceTest`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn attach_vr_display(
+        this: &VrServiceTest,
+        id: &str,
+    ) -> Result<::js_sys::Promise<VrMockDisplay>, JsValue>;
 }
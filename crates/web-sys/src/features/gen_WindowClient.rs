@@ -27,6 +27,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `WindowClient`*"]
     pub fn focused(this: &WindowClient) -> bool;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "WindowClient" , js_name = focus)]
     #[doc = "The `focus()` method."]
     #[doc = ""]
@@ -34,6 +35,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `WindowClient`*"]
     pub fn focus(this: &WindowClient) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "WindowClient" , js_name = navigate)]
     #[doc = "The `navigate()` method."]
     #[doc = ""]
@@ -41,4 +43,29 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `WindowClient`*"]
     pub fn navigate(this: &WindowClient, url: &str) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "WindowClient" , js_name = focus)]
+    #[doc = "The `focus()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/WindowClient/focus)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `WindowClient`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn focus(this: &WindowClient) -> Result<::js_sys::Promise<WindowClient>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "WindowClient" , js_name = navigate)]
+    #[doc = "The `navigate()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/WindowClient/navigate)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `WindowClient`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn navigate(
+        this: &WindowClient,
+        url: &str,
+    ) -> Result<::js_sys::Promise<WindowClient>, JsValue>;
 }
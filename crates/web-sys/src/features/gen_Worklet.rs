@@ -12,6 +12,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Worklet`*"]
     pub type Worklet;
+    #[cfg(not(web_sys_unstable_apis))]
     # [wasm_bindgen (catch , method , structural , js_class = "Worklet" , js_name = addModule)]
     #[doc = "The `addModule()` method."]
     #[doc = ""]
@@ -19,6 +20,7 @@ extern "C" {
     #[doc = ""]
     #[doc = "*This API requires the following crate features to be activated: `Worklet`*"]
     pub fn add_module(this: &Worklet, module_url: &str) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(not(web_sys_unstable_apis))]
     #[cfg(feature = "WorkletOptions")]
     # [wasm_bindgen (catch , method , structural , js_class = "Worklet" , js_name = addModule)]
     #[doc = "The `addModule()` method."]
@@ -31,4 +33,34 @@ extern "C" {
         module_url: &str,
         options: &WorkletOptions,
     ) -> Result<::js_sys::Promise, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    # [wasm_bindgen (catch , method , structural , js_class = "Worklet" , js_name = addModule)]
+    #[doc = "The `addModule()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Worklet/addModule)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Worklet`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn add_module(
+        this: &Worklet,
+        module_url: &str,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
+    #[cfg(web_sys_unstable_apis)]
+    #[cfg(feature = "WorkletOptions")]
+    # [wasm_bindgen (catch , method , structural , js_class = "Worklet" , js_name = addModule)]
+    #[doc = "The `addModule()` method."]
+    #[doc = ""]
+    #[doc = "[MDN Documentation](https://developer.mozilla.org/en-US/docs/Web/API/Worklet/addModule)"]
+    #[doc = ""]
+    #[doc = "*This API requires the following crate features to be activated: `Worklet`, `WorkletOptions`*"]
+    #[doc = ""]
+    #[doc = "*This API is unstable and requires `--cfg=web_sys_unstable_apis` to be activated, as"]
+    #[doc = "[described in the `wasm-bindgen` guide](https://wasm-bindgen.github.io/wasm-bindgen/web-sys/unstable-apis.html)*"]
+    pub fn add_module_with_options(
+        this: &Worklet,
+        module_url: &str,
+        options: &WorkletOptions,
+    ) -> Result<::js_sys::Promise<::js_sys::Undefined>, JsValue>;
 }
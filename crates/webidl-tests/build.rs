@@ -18,6 +18,35 @@ fn main() {
         wasm_bindgen_webidl::Options {
             features: false,
             next_unstable: std::cell::Cell::new(next_unstable),
+            typed_promises: false,
+            callback_traits: false,
+        },
+    )
+    .unwrap();
+
+    // Callback interface traits are only generated with `callback_traits`,
+    // which changes how all callbacks are typed, so they're tested separately.
+    wasm_bindgen_webidl::generate(
+        "webidls/callback_traits".as_ref(),
+        &out_dir.join("callback_traits"),
+        wasm_bindgen_webidl::Options {
+            features: false,
+            next_unstable: std::cell::Cell::new(false),
+            typed_promises: false,
+            callback_traits: true,
+        },
+    )
+    .unwrap();
+
+    wasm_bindgen_webidl::generate_typescript(
+        "typescript".as_ref(),
+        &out_dir.join("typescript"),
+        None,
+        wasm_bindgen_webidl::Options {
+            features: false,
+            next_unstable: std::cell::Cell::new(false),
+            typed_promises: false,
+            callback_traits: false,
         },
     )
     .unwrap();
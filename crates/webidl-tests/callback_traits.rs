@@ -0,0 +1,31 @@
+use crate::generated_callback_traits::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+struct Adder {
+    messages: Rc<RefCell<Vec<String>>>,
+}
+
+impl CalculatorImpl for Adder {
+    fn add(&self, a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    fn log(&self, message: String) {
+        self.messages.borrow_mut().push(message);
+    }
+}
+
+#[wasm_bindgen_test]
+fn callback_interface_calls_trait_impl() {
+    let messages = Rc::new(RefCell::new(Vec::new()));
+    let calculator = CalculatorObject::new(Adder {
+        messages: messages.clone(),
+    });
+
+    let user = CalculatorUser::new().unwrap();
+    assert_eq!(user.add_with(&calculator, 1, 2), 3);
+    user.log_with(&calculator, "hello");
+    assert_eq!(*messages.borrow(), ["hello"]);
+}
@@ -758,3 +758,55 @@ global.UpcastTest = class UpcastTest {
     return obj.value;
   }
 };
+
+global.CalculatorUser = class CalculatorUser {
+  addWith(calculator, a, b) {
+    return calculator.add(a, b);
+  }
+
+  logWith(calculator, message) {
+    calculator.log(message);
+  }
+};
+
+global.Point = class Point {
+  constructor(x, y) {
+    this._x = x;
+    this.y = y;
+  }
+
+  get x() {
+    return this._x;
+  }
+
+  distanceTo(other) {
+    return Math.hypot(this.x - other.x, this.y - other.y);
+  }
+
+  scale(x, y = x) {
+    return new Point(this.x * x, this.y * y);
+  }
+
+  static origin() {
+    return new Point(0, 0);
+  }
+};
+
+global.NamedPoint = class NamedPoint extends global.Point {
+  constructor(name, x, y) {
+    super(x, y);
+    this.name = name;
+  }
+};
+
+global.describePoint = function (point, label) {
+  return `${label ?? "point"} at (${point.x}, ${point.y})`;
+};
+
+global.formatLength = function (value, unit) {
+  return `${value}${unit}`;
+};
+
+global.sumAll = function (...values) {
+  return values.reduce((a, b) => a + b, 0);
+};
@@ -21,8 +21,17 @@ mod generated {
     include!(concat!(env!("OUT_DIR"), "/mod.rs"));
 }
 
+mod generated_callback_traits {
+    include!(concat!(env!("OUT_DIR"), "/callback_traits/mod.rs"));
+}
+
+mod generated_typescript {
+    include!(concat!(env!("OUT_DIR"), "/typescript/mod.rs"));
+}
+
 pub mod array;
 pub mod array_buffer;
+pub mod callback_traits;
 pub mod callbacks;
 pub mod callbacks_typed;
 pub mod consts;
@@ -39,5 +48,6 @@ pub mod setlike;
 pub mod signature_stability;
 pub mod simple;
 pub mod throws;
+pub mod typescript;
 pub mod unstable;
 pub mod upcast;
@@ -0,0 +1,50 @@
+use crate::generated_typescript::*;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn classes() {
+    let point = Point::new(3.0, 4.0).unwrap();
+    assert_eq!(point.x(), 3.0);
+    point.set_y(8.0);
+    assert_eq!(point.y(), 8.0);
+    point.set_y(4.0);
+    assert_eq!(point.distance_to(&Point::origin()), 5.0);
+
+    // Overloads get a method each.
+    let scaled = point.scale(2.0);
+    assert_eq!((scaled.x(), scaled.y()), (6.0, 8.0));
+    let scaled = point.scale_with_y(2.0, 3.0);
+    assert_eq!((scaled.x(), scaled.y()), (6.0, 12.0));
+}
+
+#[wasm_bindgen_test]
+fn inheritance() {
+    let named = NamedPoint::new("a", 1.0, 2.0).unwrap();
+    assert_eq!(named.name(), "a");
+    named.set_name("b");
+    assert_eq!(named.name(), "b");
+
+    let point: &Point = &named;
+    assert_eq!(point.distance_to(&Point::new(1.0, 2.0).unwrap()), 0.0);
+}
+
+#[wasm_bindgen_test]
+fn functions() {
+    let point = Point::new(1.0, 2.0).unwrap();
+    assert_eq!(describe_point(&point), "point at (1, 2)");
+    assert_eq!(
+        describe_point_with_label(&point, Some("start")),
+        "start at (1, 2)"
+    );
+    assert_eq!(describe_point_with_label(&point, None), "point at (1, 2)");
+
+    // String literal unions are bound as enums.
+    assert_eq!(format_length(2.0, Unit::Em), "2em");
+
+    // Rest parameters are bound as a variadic function and fixed arities.
+    let values = js_sys::Array::of3(&JsValue::from(1), &JsValue::from(2), &JsValue::from(3));
+    assert_eq!(sum_all(&values), 6.0);
+    assert_eq!(sum_all_0(), 0.0);
+    assert_eq!(sum_all_2(1.0, 2.0), 3.0);
+}
@@ -0,0 +1,23 @@
+/** A point in the plane. */
+export declare class Point {
+    constructor(x: number, y: number);
+    readonly x: number;
+    y: number;
+    /** Returns the distance between this point and `other`. */
+    distanceTo(other: Point): number;
+    scale(factor: number): Point;
+    scale(x: number, y: number): Point;
+    static origin(): Point;
+}
+
+/** A point with a name. */
+export declare class NamedPoint extends Point {
+    constructor(name: string, x: number, y: number);
+    name: string;
+}
+
+export type Unit = "px" | "em";
+
+export declare function describePoint(point: Point, label?: string | null): string;
+export declare function formatLength(value: number, unit: Unit): string;
+export declare function sumAll(...values: number[]): number;
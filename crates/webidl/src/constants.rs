@@ -48,7 +48,10 @@ pub(crate) static BUILTIN_IDENTS: Lazy<BTreeSet<&'static str>> = Lazy::new(|| {
         "JsString",
         "ArrayTuple",
         "Symbol",
+        "BigInt",
+        "Error",
         "JsError",
+        "JsOption",
         "Boolean",
         "Number",
         // wasm-bindgen built-in types
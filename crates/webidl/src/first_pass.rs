@@ -103,7 +103,9 @@ impl<'src> FirstPassRecord<'src> {
                 catch: false,
                 variadic: false,
                 unstable: stability.is_unstable(),
+                doc: None,
                 has_unstable_override: false,
+                typed_promise: false,
             },
         );
     }
@@ -195,6 +197,14 @@ pub(crate) struct CallbackInterfaceData<'src> {
     /// For single-function callback interfaces, the typed callback signature (next_unstable)
     pub(crate) params: Vec<WbgType<'src>>,
     pub(crate) return_type: Option<WbgType<'src>>,
+    /// All named operations, used to generate a Rust trait for the interface
+    pub(crate) operations: Vec<CallbackOperation<'src>>,
+}
+
+pub(crate) struct CallbackOperation<'src> {
+    pub(crate) name: &'src str,
+    pub(crate) args: Vec<(&'src str, WbgType<'src>)>,
+    pub(crate) return_type: Option<WbgType<'src>>,
 }
 
 pub(crate) struct CallbackData<'src> {
@@ -1701,11 +1711,57 @@ impl<'src> FirstPass<'src, ()> for weedle::CallbackInterfaceDefinition<'src> {
             (Vec::new(), None)
         };
 
+        // Overloaded operations can't be implemented by a single Rust method,
+        // so only the first one of every name is kept.
+        let mut callback_operations: Vec<CallbackOperation<'src>> = Vec::new();
+        for op in &operations {
+            let Some(name) = op.identifier.map(|id| id.0) else {
+                continue;
+            };
+            if callback_operations.iter().any(|op| op.name == name) {
+                log::warn!(
+                    "skipping overloaded callback interface operation: {}.{name}",
+                    self.identifier.0
+                );
+                continue;
+            }
+            let args = op
+                .args
+                .body
+                .list
+                .iter()
+                .map(|arg| match arg {
+                    weedle::argument::Argument::Single(single) => {
+                        let ty = single.type_.type_.to_wbg_type(record);
+                        let ty = if single.optional.is_some() {
+                            WbgType::JsOption(Box::new(ty))
+                        } else {
+                            ty
+                        };
+                        (single.identifier.0, ty)
+                    }
+                    weedle::argument::Argument::Variadic(variadic) => {
+                        (variadic.identifier.0, variadic.type_.to_wbg_type(record))
+                    }
+                })
+                .collect();
+            let return_type = match &op.return_type {
+                weedle::types::ReturnType::Undefined(_) => None,
+                weedle::types::ReturnType::Type(ty) => Some(ty.to_wbg_type(record)),
+            };
+            callback_operations.push(CallbackOperation {
+                name,
+                args,
+                return_type,
+            });
+        }
+
         let data = CallbackInterfaceData {
             single_function,
             method_name,
             params,
             return_type,
+            operations: callback_operations,
         };
         record.callback_interfaces.insert(self.identifier.0, data);
         Ok(())
@@ -13,7 +13,7 @@ use crate::util::{get_cfg_features, mdn_doc, required_doc_string};
 use crate::wbg_type::WbgType;
 use crate::Options;
 
-fn add_features(features: &mut BTreeSet<String>, ty: &impl TraverseType) {
+pub(crate) fn add_features(features: &mut BTreeSet<String>, ty: &impl TraverseType) {
     ty.traverse_type(&mut |ident| {
         let ident = ident.to_string();
 
@@ -68,6 +68,7 @@ fn generate_arguments(
     variadic: bool,
     variadic_type: Option<&WbgType<'_>>,
     generics_compat: bool,
+    options: &Options,
 ) -> Option<Vec<TokenStream>> {
     let mut output = Vec::with_capacity(arguments.len());
     for (i, (name, wbg_ty)) in arguments.iter().enumerate() {
@@ -94,6 +95,7 @@ fn generate_arguments(
             // Fallback to untyped Array
             output.push(quote!( #name: &::js_sys::Array ));
         } else {
+            let generics_compat = generics_compat && !options.typed_callback(wbg_ty);
             let ty = match wbg_ty.to_syn_type(
                 crate::util::TypePosition::ARGUMENT,
                 false,
@@ -271,11 +273,13 @@ impl Const {
     }
 }
 
+#[derive(Clone)]
 pub enum InterfaceAttributeKind {
     Getter,
     Setter,
 }
 
+#[derive(Clone)]
 pub struct InterfaceAttribute {
     pub js_name: String,
     pub rust_name: String,
@@ -286,10 +290,17 @@ pub struct InterfaceAttribute {
     pub catch: bool,
     pub kind: InterfaceAttributeKind,
     pub unstable: bool,
+    /// Documentation used instead of the MDN link, like the JSDoc comment of
+    /// a TypeScript declaration.
+    pub doc: Option<String>,
     /// True if this is a stable attribute that has an unstable override with
     /// the same name but different type. When true, this attribute is gated
     /// behind `#[cfg(not(web_sys_unstable_apis))]`.
     pub has_unstable_override: bool,
+    /// True if this is the copy of a stable getter returning a promise which
+    /// is typed with its resolution type. It's gated behind
+    /// `#[cfg(web_sys_unstable_apis)]`, replacing the untyped getter.
+    pub typed_promise: bool,
 }
 
 impl InterfaceAttribute {
@@ -311,7 +322,9 @@ impl InterfaceAttribute {
             catch,
             kind,
             unstable,
+            doc,
             has_unstable_override,
+            typed_promise,
         } = self;
 
         // If this is a stable attribute that has an unstable override,
@@ -322,12 +335,12 @@ impl InterfaceAttribute {
                 #[cfg(not(web_sys_unstable_apis))]
             })
         } else {
-            maybe_unstable_attr(*unstable)
+            maybe_unstable_attr(*unstable || *typed_promise)
         };
         let unstable_docs = if *has_unstable_override {
             None
         } else {
-            maybe_unstable_docs(*unstable)
+            maybe_unstable_docs(*unstable || *typed_promise)
         };
 
         let mdn_docs = mdn_doc(parent_js_name, Some(js_name));
@@ -404,7 +417,10 @@ impl InterfaceAttribute {
             });
 
         let doc_comment = comment(
-            format!("{prefix} for the `{js_name}` field of this object.\n\n{mdn_docs}"),
+            match doc {
+                Some(doc) => doc.clone(),
+                None => format!("{prefix} for the `{js_name}` field of this object.\n\n{mdn_docs}"),
+            },
             &doc_comment,
         );
 
@@ -452,10 +468,17 @@ pub struct InterfaceMethod<'a> {
     pub catch: bool,
     pub variadic: bool,
     pub unstable: bool,
+    /// Documentation used instead of the MDN link, like the JSDoc comment of
+    /// a TypeScript declaration.
+    pub doc: Option<String>,
     /// True if this is a stable method that has an unstable override with
     /// the same name/signature but different return type. When true, this method
     /// is gated behind `#[cfg(not(web_sys_unstable_apis))]`.
     pub has_unstable_override: bool,
+    /// True if this is the copy of a stable method returning a promise which
+    /// is typed with its resolution type. It's gated behind
+    /// `#[cfg(web_sys_unstable_apis)]`, replacing the untyped method.
+    pub typed_promise: bool,
 }
 
 impl<'a> InterfaceMethod<'a> {
@@ -501,7 +524,9 @@ impl InterfaceMethod<'_> {
             catch,
             variadic,
             unstable,
+            doc,
             has_unstable_override,
+            typed_promise,
         } = self;
 
         // If this is a stable method that has an unstable override,
@@ -512,12 +537,12 @@ impl InterfaceMethod<'_> {
                 #[cfg(not(web_sys_unstable_apis))]
             })
         } else {
-            maybe_unstable_attr(*unstable)
+            maybe_unstable_attr(*unstable || *typed_promise)
         };
         let unstable_docs = if *has_unstable_override {
             None
         } else {
-            maybe_unstable_docs(*unstable)
+            maybe_unstable_docs(*unstable || *typed_promise)
         };
         // Unstable APIs always use typed generics (generics_compat=false).
         // Stable APIs use legacy types by default, typed generics if next_unstable is set.
@@ -531,6 +556,8 @@ impl InterfaceMethod<'_> {
         use crate::util::TypePosition;
         let ret_ty = match ret_wbg_ty {
             Some(wbg_ty) => {
+                let generics_compat =
+                    generics_compat && !options.typed_return(wbg_ty) && !*typed_promise;
                 match wbg_ty.to_syn_type(TypePosition::RETURN, false, generics_compat) {
                     Ok(ty) => ty,
                     Err(e) => {
@@ -592,6 +619,7 @@ impl InterfaceMethod<'_> {
 
         // Add features from argument types
         for (_, wbg_ty) in arguments.iter() {
+            let generics_compat = generics_compat && !options.typed_callback(wbg_ty);
             if let Ok(Some(ty)) =
                 wbg_ty.to_syn_type(crate::util::TypePosition::ARGUMENT, false, generics_compat)
             {
@@ -617,7 +645,10 @@ impl InterfaceMethod<'_> {
         let mut features_doc = features.clone();
         features_doc.insert(parent_name.to_string());
 
-        let doc_comment = comment(doc_comment, &required_doc_string(options, &features_doc));
+        let doc_comment = comment(
+            doc.clone().unwrap_or(doc_comment),
+            &required_doc_string(options, &features_doc),
+        );
 
         let deprecated = deprecated
             .as_ref()
@@ -656,6 +687,7 @@ impl InterfaceMethod<'_> {
             *variadic,
             variadic_type.as_ref(),
             generics_compat,
+            options,
         ) {
             Some(args) => args,
             None => {
@@ -698,6 +730,10 @@ impl InterfaceMethod<'_> {
 pub struct Interface<'a> {
     pub name: Ident,
     pub js_name: String,
+    /// The JS module the type is imported from, instead of the global scope.
+    pub js_module: Option<String>,
+    /// Documentation used instead of the MDN link.
+    pub doc: Option<String>,
     pub deprecated: Option<Option<String>>,
     pub has_interface: bool,
     pub parents: Vec<Ident>,
@@ -712,6 +748,8 @@ impl Interface<'_> {
         let Interface {
             name,
             js_name,
+            js_module,
+            doc,
             deprecated,
             has_interface,
             parents,
@@ -725,9 +763,16 @@ impl Interface<'_> {
         let unstable_docs = maybe_unstable_docs(*unstable);
 
         let doc_comment = comment(
-            format!("The `{name}` class.\n\n{}", mdn_doc(js_name, None)),
+            match doc {
+                Some(doc) => doc.clone(),
+                None => format!("The `{name}` class.\n\n{}", mdn_doc(js_name, None)),
+            },
             &get_features_doc(options, name.to_string()),
         );
+        let extern_attr = match js_module {
+            Some(module) => quote!( #[wasm_bindgen(module = #module)] ),
+            None => quote!( #[wasm_bindgen] ),
+        };
 
         let is_type_of = if *has_interface {
             None
@@ -785,7 +830,7 @@ impl Interface<'_> {
             use wasm_bindgen::prelude::*;
 
             #unstable_attr
-            #[wasm_bindgen]
+            #extern_attr
             extern "C" {
                 #[wasm_bindgen(
                     #is_type_of
@@ -1191,14 +1236,18 @@ pub struct Function<'a> {
     pub catch: bool,
     pub variadic: bool,
     pub unstable: bool,
+    /// Documentation used instead of the MDN link.
+    pub doc: Option<String>,
 }
 
 impl Function<'_> {
+    /// Generates the import of this function, from the `js_namespace` object
+    /// if any, or from the global scope or module of the extern block.
     pub(crate) fn generate(
         &self,
         options: &Options,
         parent_name: &Ident,
-        parent_js_name: String,
+        js_namespace: Option<&str>,
     ) -> Option<TokenStream> {
         let Function {
             name,
@@ -1209,6 +1258,7 @@ impl Function<'_> {
             catch,
             variadic,
             unstable,
+            doc,
         } = self;
 
         // Unstable APIs always use typed generics (generics_compat=false).
@@ -1223,6 +1273,7 @@ impl Function<'_> {
         use crate::util::TypePosition;
         let ret_ty = match ret_wbg_ty {
             Some(wbg_ty) => {
+                let generics_compat = generics_compat && !options.typed_return(wbg_ty);
                 match wbg_ty.to_syn_type(TypePosition::RETURN, false, generics_compat) {
                     Ok(ty) => ty,
                     Err(_) => return None,
@@ -1234,18 +1285,27 @@ impl Function<'_> {
         let unstable_attr = maybe_unstable_attr(*unstable);
         let unstable_docs = maybe_unstable_docs(*unstable);
 
-        let js_namespace = raw_ident(&parent_js_name);
-
-        let doc_comment = format!(
-            "The `{parent_js_name}.{js_name}()` function.\n\n{}",
-            mdn_doc(&parent_js_name, Some(js_name))
-        );
+        // Without a namespace there's no MDN page to link to, so a function
+        // without documentation of its own only lists the features it requires.
+        let doc_comment = doc.clone().or_else(|| {
+            js_namespace.map(|js_namespace| {
+                format!(
+                    "The `{js_namespace}.{js_name}()` function.\n\n{}",
+                    mdn_doc(js_namespace, Some(js_name))
+                )
+            })
+        });
+        let js_namespace = js_namespace.map(|js_namespace| {
+            let js_namespace = raw_ident(js_namespace);
+            quote!( js_namespace = #js_namespace, )
+        });
 
         // Compute feature set
         let mut features = BTreeSet::new();
 
         // Add features from argument types
         for (_, wbg_ty) in arguments.iter() {
+            let generics_compat = generics_compat && !options.typed_callback(wbg_ty);
             if let Ok(Some(ty)) =
                 wbg_ty.to_syn_type(crate::util::TypePosition::ARGUMENT, false, generics_compat)
             {
@@ -1266,7 +1326,14 @@ impl Function<'_> {
         let mut features_doc = features.clone();
         features_doc.insert(parent_name.to_string());
 
-        let doc_comment = comment(doc_comment, &required_doc_string(options, &features_doc));
+        let features_doc = required_doc_string(options, &features_doc);
+        let doc_comment = match doc_comment {
+            Some(doc_comment) => comment(doc_comment, &features_doc),
+            None => comment(
+                String::new(),
+                &features_doc.map(|doc| doc.trim_start().to_string()),
+            ),
+        };
 
         let catch_attr = if *catch { Some(quote!(catch,)) } else { None };
 
@@ -1280,6 +1347,7 @@ impl Function<'_> {
             *variadic,
             variadic_type.as_ref(),
             generics_compat,
+            options,
         )?;
 
         // Build the return type token
@@ -1300,7 +1368,7 @@ impl Function<'_> {
             #[wasm_bindgen(
                 #catch_attr
                 #variadic_attr
-                js_namespace = #js_namespace,
+                #js_namespace
                 js_name = #js_name_ident
             )]
             #doc_comment
@@ -1313,6 +1381,9 @@ impl Function<'_> {
 pub struct Namespace<'a> {
     pub name: Ident,
     pub js_name: String,
+    /// The JS module the namespace is imported from, instead of the global
+    /// scope.
+    pub js_module: Option<String>,
     pub consts: Vec<Const>,
     pub attributes: Vec<NamespaceAttribute>,
     pub functions: Vec<Function<'a>>,
@@ -1324,6 +1395,7 @@ impl Namespace<'_> {
         let Namespace {
             name,
             js_name,
+            js_module,
             consts,
             attributes,
             functions,
@@ -1335,7 +1407,7 @@ impl Namespace<'_> {
 
         let functions = functions
             .iter()
-            .filter_map(|x| x.generate(options, name, js_name.to_string()))
+            .filter_map(|x| x.generate(options, name, Some(js_name.as_str())))
             .collect::<Vec<_>>();
 
         // For namespace attributes, we need a type binding that represents the namespace
@@ -1348,12 +1420,17 @@ impl Namespace<'_> {
             .map(|x| x.generate(options, name, &ns_type_name, js_name))
             .collect::<Vec<_>>();
 
+        let extern_attr = match js_module {
+            Some(module) => quote!( #[wasm_bindgen(module = #module)] ),
+            None => quote!( #[wasm_bindgen] ),
+        };
+
         // Only generate the namespace type if we have attributes that need it
         let ns_type_binding = if attributes.is_empty() {
             None
         } else {
             Some(quote! {
-                #[wasm_bindgen]
+                #extern_attr
                 extern "C" {
                     #[wasm_bindgen(js_name = #js_namespace)]
                     pub type #ns_type_name;
@@ -1365,7 +1442,7 @@ impl Namespace<'_> {
             None
         } else {
             Some(quote! {
-                #[wasm_bindgen]
+                #extern_attr
                 extern "C" {
                     #(#attributes)*
                     #(#functions)*
@@ -1396,3 +1473,43 @@ impl Namespace<'_> {
         }
     }
 }
+
+/// Functions imported from the global scope or a JS module, rather than from
+/// a namespace object.
+pub struct ModuleFunctions<'a> {
+    pub name: Ident,
+    pub js_module: Option<String>,
+    pub functions: Vec<Function<'a>>,
+}
+
+impl ModuleFunctions<'_> {
+    pub fn generate(&self, options: &Options) -> TokenStream {
+        let ModuleFunctions {
+            name,
+            js_module,
+            functions,
+        } = self;
+
+        let functions = functions
+            .iter()
+            .filter_map(|x| x.generate(options, name, None))
+            .collect::<Vec<_>>();
+
+        let extern_attr = match js_module {
+            Some(module) => quote!( #[wasm_bindgen(module = #module)] ),
+            None => quote!( #[wasm_bindgen] ),
+        };
+
+        quote! {
+            #![allow(unused_imports)]
+            #![allow(clippy::all)]
+            use super::*;
+            use wasm_bindgen::prelude::*;
+
+            #extern_attr
+            extern "C" {
+                #(#functions)*
+            }
+        }
+    }
+}
@@ -13,20 +13,23 @@ mod constants;
 mod first_pass;
 mod generator;
 mod traverse;
+mod typescript;
 mod util;
 mod wbg_type;
 
 use crate::first_pass::OperationData;
 use crate::first_pass::{FirstPass, FirstPassRecord, InterfaceData, OperationId};
 use crate::generator::{
-    Const, Dictionary, DictionaryField, Enum, EnumVariant, Function, Interface, InterfaceAttribute,
-    InterfaceAttributeKind, InterfaceMethod, Namespace, NamespaceAttribute, NamespaceAttributeKind,
+    add_features, Const, Dictionary, DictionaryField, Enum, EnumVariant, Function, Interface,
+    InterfaceAttribute, InterfaceAttributeKind, InterfaceMethod, Namespace, NamespaceAttribute,
+    NamespaceAttributeKind,
 };
 use crate::traverse::TraverseType;
 use crate::util::{
-    camel_case_ident, get_rust_deprecated, getter_throws, is_structural, is_type_unstable,
-    optional_return_ty, read_dir, rust_ident, setter_throws, shouty_snake_case_ident,
-    snake_case_ident, throws, webidl_const_v_to_backend_const_v, TypePosition,
+    camel_case_ident, get_cfg_features, get_rust_deprecated, getter_throws, is_structural,
+    is_type_unstable, optional_return_ty, read_dir, required_doc_string, rust_ident, setter_throws,
+    shouty_snake_case_ident, snake_case_ident, throws, webidl_const_v_to_backend_const_v,
+    TypePosition,
 };
 use crate::wbg_type::ToWbgType;
 use anyhow::Context;
@@ -54,7 +57,7 @@ use weedle::Parse;
 /// need to generate both versions with appropriate `#[cfg]` guards:
 /// - Stable: `#[cfg(not(web_sys_unstable_apis))]`
 /// - Unstable: `#[cfg(web_sys_unstable_apis)]`
-fn mark_stable_attributes_with_unstable_overrides(attributes: &mut [InterfaceAttribute]) {
+fn mark_stable_attributes_with_unstable_overrides(attributes: &mut Vec<InterfaceAttribute>) {
     // Find attribute names that have both stable and unstable versions
     let unstable_names: HashSet<String> = attributes
         .iter()
@@ -62,6 +65,10 @@ fn mark_stable_attributes_with_unstable_overrides(attributes: &mut [InterfaceAtt
         .map(|attr| attr.js_name.clone())
         .collect();
 
+    // Typed promise getters only exist with `web_sys_unstable_apis`, where the
+    // unstable attribute replaces them.
+    attributes.retain(|attr| !(attr.typed_promise && unstable_names.contains(&attr.js_name)));
+
     // Mark stable attributes that have an unstable counterpart
     for attr in attributes.iter_mut() {
         if !attr.unstable && unstable_names.contains(&attr.js_name) {
@@ -93,6 +100,42 @@ fn mark_stable_methods_with_unstable_overrides(methods: &mut [InterfaceMethod])
     }
 }
 
+/// Gate stable methods returning a promise behind `not(web_sys_unstable_apis)`
+/// and add a copy for `web_sys_unstable_apis` which returns the promise typed
+/// with its resolution type, like `Promise<JsString>`.
+///
+/// Methods with an unstable override are skipped, as the override already
+/// replaces them with `web_sys_unstable_apis`.
+fn add_typed_promise_methods(options: &Options, methods: &mut Vec<InterfaceMethod>) {
+    let mut typed = Vec::new();
+
+    for method in methods.iter_mut() {
+        if method.unstable || method.has_unstable_override {
+            continue;
+        }
+        let Some(ret) = method
+            .ret_wbg_ty
+            .as_ref()
+            .filter(|ret| options.gated_typed_promise(ret))
+        else {
+            continue;
+        };
+        // `Promise<any>` is the same either way.
+        let legacy_ty = ret.to_syn_type(TypePosition::RETURN, false, true);
+        let typed_ty = ret.to_syn_type(TypePosition::RETURN, false, false);
+        if matches!((legacy_ty, typed_ty), (Ok(Some(legacy)), Ok(Some(typed))) if legacy != typed) {
+            method.has_unstable_override = true;
+            typed.push(InterfaceMethod {
+                has_unstable_override: false,
+                typed_promise: true,
+                ..method.clone()
+            });
+        }
+    }
+
+    methods.extend(typed);
+}
+
 /// Options to configure the conversion process
 #[derive(Default)]
 pub struct Options {
@@ -101,6 +144,42 @@ pub struct Options {
     /// Whether to generate the next major unstable generics output for stable APIs.
     /// Unstable APIs always use typed generics regardless of this flag.
     pub next_unstable: std::cell::Cell<bool>,
+    /// Whether promises returned by stable operations and attributes are typed
+    /// with their IDL-declared resolution type, like `Promise<JsString>`, even
+    /// without `next_unstable`.
+    pub typed_promises: bool,
+    /// Whether a Rust trait and wrapper type are generated for every callback
+    /// interface, and callbacks are typed with their signature, like
+    /// `Function<fn(Event) -> Undefined>`, even without `next_unstable`. With
+    /// `next_unstable`, callback interfaces aren't generated at all.
+    pub callback_traits: bool,
+}
+
+impl Options {
+    /// Whether `ty` is a promise which is returned with its resolution type
+    /// although the API otherwise uses legacy types.
+    pub(crate) fn typed_promise(&self, ty: &WbgType<'_>) -> bool {
+        self.typed_promises && ty.is_promise()
+    }
+
+    /// Whether `ty` is a promise returned by a stable API which is only typed
+    /// with its resolution type with `web_sys_unstable_apis`, as neither
+    /// `typed_promises` nor `next_unstable` types it unconditionally.
+    pub(crate) fn gated_typed_promise(&self, ty: &WbgType<'_>) -> bool {
+        !self.typed_promises && !self.next_unstable.get() && ty.is_promise()
+    }
+
+    /// Whether `ty` is a callback which is typed with its signature although
+    /// the API otherwise uses legacy types.
+    pub(crate) fn typed_callback(&self, ty: &WbgType<'_>) -> bool {
+        self.callback_traits && ty.is_callback()
+    }
+
+    /// Whether the returned `ty` keeps its generic types although the API
+    /// otherwise uses legacy types.
+    pub(crate) fn typed_return(&self, ty: &WbgType<'_>) -> bool {
+        self.typed_promise(ty) || self.typed_callback(ty)
+    }
 }
 
 impl std::fmt::Debug for Options {
@@ -108,6 +187,8 @@ impl std::fmt::Debug for Options {
         f.debug_struct("Options")
             .field("features", &self.features)
             .field("next_unstable", &self.next_unstable.get())
+            .field("typed_promises", &self.typed_promises)
+            .field("callback_traits", &self.callback_traits)
             .finish()
     }
 }
@@ -140,6 +221,27 @@ impl fmt::Display for WebIDLParseError {
 
 impl std::error::Error for WebIDLParseError {}
 
+/// A parse error of TypeScript declarations indicating where parsing failed
+#[derive(Debug)]
+pub struct TypeScriptParseError {
+    /// The byte position at which parsing failed
+    pub position: usize,
+    /// What went wrong
+    pub message: String,
+}
+
+impl fmt::Display for TypeScriptParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed to parse TypeScript at byte position {}: {}",
+            self.position, self.message
+        )
+    }
+}
+
+impl std::error::Error for TypeScriptParseError {}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub(crate) enum ApiStability {
     #[default]
@@ -252,8 +354,26 @@ pub fn compile(
 ) -> Result<BTreeMap<String, Feature>> {
     let ast = parse(webidl_source, experimental_source, options)?;
 
-    let features = ast
-        .into_iter()
+    Ok(into_features(ast))
+}
+
+/// Compile the given TypeScript declarations into Rust source text containing
+/// `wasm-bindgen` bindings to the things they declare.
+///
+/// Declarations outside of `declare module` blocks are imported from
+/// `module`, or from the global scope if it's `None`.
+pub fn compile_typescript(
+    source: &str,
+    module: Option<&str>,
+    options: Options,
+) -> Result<BTreeMap<String, Feature>> {
+    let ast = typescript::parse(source, module, &options)?;
+
+    Ok(into_features(ast))
+}
+
+fn into_features(ast: BTreeMap<String, Program>) -> BTreeMap<String, Feature> {
+    ast.into_iter()
         .filter_map(|(name, program)| {
             let code = program.to_string()?;
             let required_features = program.required_features.into_iter().collect();
@@ -265,9 +385,7 @@ pub fn compile(
                 },
             ))
         })
-        .collect();
-
-    Ok(features)
+        .collect()
 }
 
 impl<'src> FirstPassRecord<'src> {
@@ -366,6 +484,159 @@ impl<'src> FirstPassRecord<'src> {
             }
         };
         tokens.to_tokens(&mut program.tokens);
+
+        if self.options.callback_traits {
+            self.append_callback_interface_trait(program, &name, js_name, data);
+        }
+    }
+
+    /// Generates a trait with the operations of a callback interface, and a
+    /// wrapper creating a JS object which calls an implementation of it.
+    fn append_callback_interface_trait(
+        &self,
+        program: &mut Program,
+        name: &Ident,
+        js_name: &str,
+        data: &first_pass::CallbackInterfaceData,
+    ) {
+        use quote::{format_ident, quote};
+
+        if data.operations.is_empty() {
+            return;
+        }
+
+        let trait_name = format_ident!("{}Impl", name);
+        let wrapper_name = format_ident!("{}Object", name);
+
+        let mut features = BTreeSet::new();
+        let mut methods = Vec::new();
+        let mut closure_types = Vec::new();
+        let mut closures = Vec::new();
+        let mut fields = Vec::new();
+        for op in &data.operations {
+            // Arguments are received from JS and the result is handed back to
+            // it, so both are converted like return values.
+            let mut convert = |wbg_type: &WbgType| {
+                let ty = wbg_type.to_syn_type(TypePosition::RETURN, false, true);
+                if let Ok(Some(ty)) = &ty {
+                    add_features(&mut features, ty);
+                }
+                ty.ok().flatten()
+            };
+            let Some(arg_types) = op
+                .args
+                .iter()
+                .map(|(_, wbg_type)| convert(wbg_type))
+                .collect::<Option<Vec<_>>>()
+            else {
+                log::warn!(
+                    "unsupported callback interface operation {js_name}.{}",
+                    op.name
+                );
+                return;
+            };
+            let ret_ty = match &op.return_type {
+                Some(wbg_type) => match convert(wbg_type) {
+                    Some(ty) => quote!( -> #ty ),
+                    None => {
+                        log::warn!(
+                            "unsupported callback interface operation {js_name}.{}",
+                            op.name
+                        );
+                        return;
+                    }
+                },
+                None => quote!(),
+            };
+            let arg_names = op
+                .args
+                .iter()
+                .map(|(arg_name, _)| rust_ident(&snake_case_ident(arg_name)))
+                .collect::<Vec<_>>();
+
+            let method = rust_ident(&snake_case_ident(op.name));
+            let method_doc = format!("Called for the `{}` operation.", op.name);
+            let op_js_name = op.name;
+            methods.push(quote! {
+                #[doc = #method_doc]
+                fn #method(&self, #(#arg_names: #arg_types),*) #ret_ty;
+            });
+            let field = format_ident!("_{}", method);
+            closure_types.push(quote! {
+                #field: Closure<dyn FnMut(#(#arg_types),*) #ret_ty>
+            });
+            fields.push(quote!( #field: #method ));
+            closures.push(quote! {
+                let #method = Closure::<dyn FnMut(#(#arg_types),*) #ret_ty>::new({
+                    let imp = imp.clone();
+                    move |#(#arg_names: #arg_types),*| imp.#method(#(#arg_names),*)
+                });
+                set_operation(&object, #op_js_name, #method.as_ref());
+            });
+        }
+
+        features.remove(&name.to_string());
+        let cfg_features = get_cfg_features(&self.options, &features);
+        features.insert(name.to_string());
+        let features_doc = required_doc_string(&self.options, &features).unwrap_or_default();
+
+        let trait_doc = format!(
+            "The `{js_name}` callback interface, implemented in Rust.\n\n\
+             [`{wrapper_name}`] creates a JS object calling the methods of an \
+             implementation.{features_doc}"
+        );
+        let wrapper_doc = format!(
+            "A JS `{js_name}` object calling the methods of a [`{trait_name}`].\n\n\
+             The object can only be called while this wrapper is alive, so it \
+             has to be kept for as long as the object is used by JS.{features_doc}"
+        );
+        let new_doc = format!("Creates a JS object calling the methods of `imp`.{features_doc}");
+
+        let tokens = quote! {
+            #[wasm_bindgen]
+            extern "C" {
+                #cfg_features
+                #[wasm_bindgen(js_namespace = Reflect, js_name = set)]
+                fn set_operation(target: &#name, key: &str, value: &JsValue);
+            }
+
+            #cfg_features
+            #[doc = #trait_doc]
+            pub trait #trait_name: 'static {
+                #(#methods)*
+            }
+
+            #cfg_features
+            #[doc = #wrapper_doc]
+            pub struct #wrapper_name {
+                object: #name,
+                #(#closure_types,)*
+            }
+
+            #cfg_features
+            impl #wrapper_name {
+                #[doc = #new_doc]
+                pub fn new(imp: impl #trait_name) -> Self {
+                    let imp = ::alloc::rc::Rc::new(imp);
+                    let object = #name::new();
+                    #(#closures)*
+                    Self {
+                        object,
+                        #(#fields,)*
+                    }
+                }
+            }
+
+            #cfg_features
+            impl ::core::ops::Deref for #wrapper_name {
+                type Target = #name;
+
+                fn deref(&self) -> &#name {
+                    &self.object
+                }
+            }
+        };
+        tokens.to_tokens(&mut program.tokens);
     }
 
     fn append_enum(
@@ -552,7 +823,7 @@ impl<'src> FirstPassRecord<'src> {
         let generics_compat = if unstable_override {
             false
         } else {
-            !self.options.next_unstable.get()
+            !self.options.next_unstable.get() && !self.options.typed_callback(&wbg_type)
         };
         let ty = wbg_type
             .to_syn_type(TypePosition::ARGUMENT, false, generics_compat)
@@ -646,6 +917,7 @@ impl<'src> FirstPassRecord<'src> {
             Namespace {
                 name,
                 js_name,
+                js_module: None,
                 consts,
                 attributes,
                 functions,
@@ -716,6 +988,7 @@ impl<'src> FirstPassRecord<'src> {
                 catch: x.catch,
                 variadic: x.variadic,
                 unstable: false,
+                doc: None,
             });
         }
     }
@@ -735,10 +1008,13 @@ impl<'src> FirstPassRecord<'src> {
         } else {
             !self.options.next_unstable.get()
         };
-        let ty = definition
-            .type_
-            .to_wbg_type(self)
-            .to_syn_type(TypePosition::RETURN, false, generics_compat)
+        let wbg_type = definition.type_.to_wbg_type(self);
+        let ty = wbg_type
+            .to_syn_type(
+                TypePosition::RETURN,
+                false,
+                generics_compat && !self.options.typed_return(&wbg_type),
+            )
             .unwrap_or(None);
 
         let js_name = definition.identifier.0.to_string();
@@ -890,6 +1166,9 @@ impl<'src> FirstPassRecord<'src> {
         // changing `undefined` to `PerformanceMark` for Performance.mark).
         mark_stable_methods_with_unstable_overrides(&mut methods);
 
+        // Type the promises of stable methods with `web_sys_unstable_apis`.
+        add_typed_promise_methods(&self.options, &mut methods);
+
         // Add custom methods (e.g., maplike/setlike iterators)
         // Generator will produce both compat and non-compat output
         for (_, method) in data.custom_methods.iter() {
@@ -899,6 +1178,8 @@ impl<'src> FirstPassRecord<'src> {
         Interface {
             name: name.clone(),
             js_name: js_name.clone(),
+            js_module: None,
+            doc: None,
             deprecated: deprecated.clone(),
             has_interface,
             parents: parents.clone(),
@@ -942,16 +1223,19 @@ impl<'src> FirstPassRecord<'src> {
         } else {
             !self.options.next_unstable.get()
         };
-        let ty = type_
-            .type_
-            .to_wbg_type(self)
-            .to_syn_type(TypePosition::RETURN, false, generics_compat)
+        let wbg_type = type_.type_.to_wbg_type(self);
+        let ty = wbg_type
+            .to_syn_type(
+                TypePosition::RETURN,
+                false,
+                generics_compat && !self.options.typed_return(&wbg_type),
+            )
             .unwrap_or(None);
 
         // Skip types which can't be converted
         if let Some(ty) = ty {
             let kind = InterfaceAttributeKind::Getter;
-            attributes.push(InterfaceAttribute {
+            let mut getter = InterfaceAttribute {
                 is_static,
                 structural,
                 catch: catch || getter_throws(parent_js_name, &js_name, attrs),
@@ -961,8 +1245,34 @@ impl<'src> FirstPassRecord<'src> {
                 deprecated: deprecated.clone(),
                 kind,
                 unstable,
+                doc: None,
                 has_unstable_override: false,
-            });
+                typed_promise: false,
+            };
+
+            // A promise is only typed with `web_sys_unstable_apis`, by a copy of
+            // the getter replacing the untyped one. `Promise<any>` is the same
+            // either way.
+            let typed_ty = if !unstable && self.options.gated_typed_promise(&wbg_type) {
+                wbg_type
+                    .to_syn_type(TypePosition::RETURN, false, false)
+                    .unwrap_or(None)
+                    .filter(|typed_ty| *typed_ty != getter.ty)
+            } else {
+                None
+            };
+            if let Some(ty) = typed_ty {
+                getter.has_unstable_override = true;
+                attributes.push(getter.clone());
+                attributes.push(InterfaceAttribute {
+                    ty,
+                    has_unstable_override: false,
+                    typed_promise: true,
+                    ..getter
+                });
+            } else {
+                attributes.push(getter);
+            }
         }
 
         if !readonly {
@@ -975,10 +1285,13 @@ impl<'src> FirstPassRecord<'src> {
                     .filter(|list| list.contains(&js_name.as_str()))
                     .is_some()
             {
-                let ty = type_
-                    .type_
-                    .to_wbg_type(self)
-                    .to_syn_type(TypePosition::ARGUMENT, true, generics_compat)
+                let wbg_type = type_.type_.to_wbg_type(self);
+                let ty = wbg_type
+                    .to_syn_type(
+                        TypePosition::ARGUMENT,
+                        true,
+                        generics_compat && !self.options.typed_callback(&wbg_type),
+                    )
                     .unwrap_or(None);
 
                 // Skip types which can't be converted
@@ -993,12 +1306,15 @@ impl<'src> FirstPassRecord<'src> {
                         deprecated: Some(None),
                         kind: InterfaceAttributeKind::Setter,
                         unstable,
+                        doc: None,
                         has_unstable_override: false,
+                        typed_promise: false,
                     });
                 }
             }
 
             for (idl, ty) in idls.into_iter().filter_map(|idl| {
+                let generics_compat = generics_compat && !self.options.typed_callback(&idl);
                 idl.to_syn_type(TypePosition::ARGUMENT, false, generics_compat)
                     .ok()
                     .flatten()
@@ -1023,7 +1339,9 @@ impl<'src> FirstPassRecord<'src> {
                     deprecated: deprecated.clone(),
                     kind: InterfaceAttributeKind::Setter,
                     unstable,
+                    doc: None,
                     has_unstable_override: false,
+                    typed_promise: false,
                 });
             }
         }
@@ -1074,11 +1392,115 @@ impl<'src> FirstPassRecord<'src> {
 pub fn generate(from: &Path, to: &Path, options: Options) -> Result<String> {
     let generate_features = options.features;
 
-    let source = read_source_from_path(&from.join("enabled"))?;
-    let unstable_source = read_source_from_path(&from.join("unstable"))?;
+    let source = read_source_from_path(&from.join("enabled"), "webidl")?;
+    let unstable_source = read_source_from_path(&from.join("unstable"), "webidl")?;
 
     let features = parse_webidl(options, source, unstable_source)?;
 
+    return write_features(to, &features, generate_features);
+
+    fn parse_webidl(
+        options: Options,
+        enabled: SourceFile,
+        unstable: SourceFile,
+    ) -> Result<BTreeMap<String, Feature>> {
+        match compile(&enabled.contents, &unstable.contents, options) {
+            Ok(features) => Ok(features),
+            Err(e) => {
+                if let Some(err) = e.downcast_ref::<WebIDLParseError>() {
+                    if let Some(pos) = enabled.resolve_offset(err.0) {
+                        let ctx = format!(
+                            "compiling WebIDL into wasm-bindgen bindings in file \
+                             \"{}\", line {} column {}",
+                            pos.filename,
+                            pos.line + 1,
+                            pos.col + 1
+                        );
+                        return Err(e.context(ctx));
+                    } else {
+                        return Err(e.context("compiling WebIDL into wasm-bindgen bindings"));
+                    }
+                }
+                Err(e.context("compiling WebIDL into wasm-bindgen bindings"))
+            }
+        }
+    }
+}
+
+/// Generates bindings for TypeScript declaration files.
+///
+/// * Reads `.d.ts` files in `from`
+/// * Generates Rust source code in the directory `to`
+/// * Imports declarations outside of `declare module` blocks from `module`,
+///   or from the global scope if it's `None`
+/// * `options.features` indicates whether everything is gated by features or
+///   not
+///
+/// If features are enabled, returns a string that should be appended to
+/// `Cargo.toml` which lists all the known features.
+pub fn generate_typescript(
+    from: &Path,
+    to: &Path,
+    module: Option<&str>,
+    options: Options,
+) -> Result<String> {
+    let generate_features = options.features;
+
+    let source = read_source_from_path(from, "d.ts")?;
+
+    let features = match compile_typescript(&source.contents, module, options) {
+        Ok(features) => features,
+        Err(e) => {
+            let ctx = match e
+                .downcast_ref::<TypeScriptParseError>()
+                .and_then(|err| source.resolve_offset(err.position))
+            {
+                Some(pos) => format!(
+                    "compiling TypeScript into wasm-bindgen bindings in file \
+                     \"{}\", line {} column {}",
+                    pos.filename,
+                    pos.line + 1,
+                    pos.col + 1
+                ),
+                None => "compiling TypeScript into wasm-bindgen bindings".to_string(),
+            };
+            return Err(e.context(ctx));
+        }
+    };
+
+    write_features(to, &features, generate_features)
+}
+
+/// Read all files in a directory whose name ends with `.{extension}` into a
+/// single `SourceFile`
+fn read_source_from_path(dir: &Path, extension: &str) -> Result<SourceFile> {
+    let suffix = format!(".{extension}");
+    let entries = read_dir(dir).context("reading source directory")?;
+    let mut source = SourceFile::default();
+    for path in entries {
+        let is_source = path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .is_some_and(|name| name.ends_with(&suffix));
+        if !is_source {
+            continue;
+        }
+        source
+            .add_file(&path)
+            .with_context(|| format!("reading contents of file \"{}\"", path.display()))?;
+    }
+
+    Ok(source)
+}
+
+/// Writes the generated `features` into the directory `to`, replacing its
+/// contents, and returns the features to list in `Cargo.toml` if
+/// `generate_features` is set.
+fn write_features(
+    to: &Path,
+    features: &BTreeMap<String, Feature>,
+    generate_features: bool,
+) -> Result<String> {
     if to.exists() {
         fs::remove_dir_all(to).context("Removing features directory")?;
     }
@@ -1108,7 +1530,7 @@ pub fn generate(from: &Path, to: &Path, options: Options) -> Result<String> {
 
     rustfmt(to_format)?;
 
-    return if generate_features {
+    if generate_features {
         let features = features
             .iter()
             .map(|(name, feature)| {
@@ -1125,71 +1547,28 @@ pub fn generate(from: &Path, to: &Path, options: Options) -> Result<String> {
         Ok(features)
     } else {
         Ok(String::new())
-    };
-
-    /// Read all WebIDL files in a directory into a single `SourceFile`
-    fn read_source_from_path(dir: &Path) -> Result<SourceFile> {
-        let entries = read_dir(dir).context("reading webidls directory")?;
-        let mut source = SourceFile::default();
-        for path in entries {
-            if path.extension() != Some(OsStr::new("webidl")) {
-                continue;
-            }
-            source
-                .add_file(&path)
-                .with_context(|| format!("reading contents of file \"{}\"", path.display()))?;
-        }
-
-        Ok(source)
     }
+}
 
-    fn rustfmt(paths: impl IntoIterator<Item = PathBuf>) -> Result<()> {
-        // run rustfmt on the generated file - really handy for debugging
-
-        // On Windows, the command line length is limited to 32k characters, so
-        // we need to split the command into multiple invocations. I've
-        // arbitrarily chosen to format 400 files at a time, because it works.
-        let paths: Vec<_> = paths.into_iter().collect();
-        for chunk in paths.chunks(400) {
-            let result = Command::new("rustfmt")
-                .arg("--edition")
-                .arg("2021")
-                .args(chunk)
-                .status()
-                .context("rustfmt failed")?;
-
-            assert!(result.success(), "rustfmt failed");
-        }
-
-        Ok(())
+fn rustfmt(paths: impl IntoIterator<Item = PathBuf>) -> Result<()> {
+    // run rustfmt on the generated file - really handy for debugging
+
+    // On Windows, the command line length is limited to 32k characters, so
+    // we need to split the command into multiple invocations. I've
+    // arbitrarily chosen to format 400 files at a time, because it works.
+    let paths: Vec<_> = paths.into_iter().collect();
+    for chunk in paths.chunks(400) {
+        let result = Command::new("rustfmt")
+            .arg("--edition")
+            .arg("2021")
+            .args(chunk)
+            .status()
+            .context("rustfmt failed")?;
+
+        assert!(result.success(), "rustfmt failed");
     }
 
-    fn parse_webidl(
-        options: Options,
-        enabled: SourceFile,
-        unstable: SourceFile,
-    ) -> Result<BTreeMap<String, Feature>> {
-        match compile(&enabled.contents, &unstable.contents, options) {
-            Ok(features) => Ok(features),
-            Err(e) => {
-                if let Some(err) = e.downcast_ref::<WebIDLParseError>() {
-                    if let Some(pos) = enabled.resolve_offset(err.0) {
-                        let ctx = format!(
-                            "compiling WebIDL into wasm-bindgen bindings in file \
-                             \"{}\", line {} column {}",
-                            pos.filename,
-                            pos.line + 1,
-                            pos.col + 1
-                        );
-                        return Err(e.context(ctx));
-                    } else {
-                        return Err(e.context("compiling WebIDL into wasm-bindgen bindings"));
-                    }
-                }
-                Err(e.context("compiling WebIDL into wasm-bindgen bindings"))
-            }
-        }
-    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1213,6 +1592,8 @@ mod tests {
         let options = Options {
             features: false,
             next_unstable: std::cell::Cell::new(true),
+            typed_promises: false,
+            callback_traits: false,
         };
         let result = compile(webidl, "", options).unwrap();
 
@@ -1258,6 +1639,8 @@ mod tests {
         let options = Options {
             features: false,
             next_unstable: std::cell::Cell::new(true),
+            typed_promises: false,
+            callback_traits: false,
         };
         let result = compile(webidl, "", options).unwrap();
 
@@ -1278,4 +1661,230 @@ mod tests {
             "Expected catch attribute in generated code for [Throws]"
         );
     }
+
+    #[test]
+    fn test_typed_promises() {
+        let webidl = r#"
+            interface Body {
+                Promise<USVString> text();
+                Promise<any> json();
+                sequence<DOMString> names();
+                readonly attribute Promise<boolean> ready;
+            };
+        "#;
+
+        let compile_body = |typed_promises| {
+            let options = Options {
+                features: false,
+                next_unstable: std::cell::Cell::new(false),
+                typed_promises,
+                callback_traits: false,
+            };
+            compile(webidl, "", options).unwrap()["Body"].code.clone()
+        };
+
+        // By default, promises are only typed with `web_sys_unstable_apis`.
+        let gated = compile_body(false);
+        assert_eq!(
+            gated
+                .matches("# [cfg (not (web_sys_unstable_apis))]")
+                .count(),
+            2,
+            "{gated}"
+        );
+        assert_eq!(
+            gated.matches("# [cfg (web_sys_unstable_apis)]").count(),
+            2,
+            "{gated}"
+        );
+        assert!(
+            gated.contains("pub fn text (this : & Body ,) -> :: js_sys :: Promise ;"),
+            "{gated}"
+        );
+        assert!(
+            gated.contains(
+                "pub fn text (this : & Body ,) -> :: js_sys :: Promise < :: js_sys :: JsString > ;"
+            ),
+            "{gated}"
+        );
+        assert!(
+            gated.contains(
+                "pub fn ready (this : & Body ,) -> :: js_sys :: Promise < :: js_sys :: Boolean > ;"
+            ),
+            "{gated}"
+        );
+
+        let typed = compile_body(true);
+        assert!(!typed.contains("web_sys_unstable_apis"), "{typed}");
+        assert!(
+            typed.contains("-> :: js_sys :: Promise < :: js_sys :: JsString >"),
+            "{typed}"
+        );
+        assert!(
+            typed.contains("-> :: js_sys :: Promise < :: js_sys :: Boolean >"),
+            "{typed}"
+        );
+        // `Promise<any>` stays untyped and other generics keep legacy types.
+        assert!(typed.contains("-> :: js_sys :: Promise ;"), "{typed}");
+        assert!(typed.contains("-> :: js_sys :: Array ;"), "{typed}");
+    }
+
+    #[test]
+    fn test_callback_traits() {
+        let webidl = r#"
+            interface Event {};
+            callback interface EventListener {
+                undefined handleEvent(Event event);
+            };
+            callback EventHandler = any (Event event);
+            interface EventTarget {
+                undefined addEventListener(DOMString type, EventListener? callback);
+                undefined setHandler(EventHandler handler);
+            };
+        "#;
+
+        let compile_with = |callback_traits| {
+            let options = Options {
+                features: false,
+                next_unstable: std::cell::Cell::new(false),
+                typed_promises: false,
+                callback_traits,
+            };
+            compile(webidl, "", options).unwrap()
+        };
+
+        let legacy = compile_with(false);
+        let listener = &legacy["EventListener"].code;
+        assert!(!listener.contains("EventListenerImpl"), "{listener}");
+        let target = &legacy["EventTarget"].code;
+        assert!(!target.contains(":: js_sys :: Function <"), "{target}");
+
+        let typed = compile_with(true);
+        let listener = &typed["EventListener"].code;
+        assert!(
+            listener.contains("pub trait EventListenerImpl"),
+            "{listener}"
+        );
+        assert!(
+            listener.contains("pub struct EventListenerObject"),
+            "{listener}"
+        );
+        assert!(
+            listener.contains("Closure < dyn FnMut (Event) >"),
+            "{listener}"
+        );
+        let target = &typed["EventTarget"].code;
+        assert!(
+            target.contains(":: js_sys :: Function < fn (Event)"),
+            "{target}"
+        );
+    }
+
+    #[test]
+    fn test_typescript() {
+        let declarations = r#"
+            /** A chart drawn on a canvas. */
+            export declare class Chart extends Base {
+                constructor(canvas: Canvas | string, config?: ChartConfig);
+                readonly id: number;
+                data: number[] | null;
+                update(mode?: UpdateMode): void;
+                static register(...items: Plugin[]): void;
+                getElementsAtEvent(e: Event): object[];
+                getElementsAtEvent(x: number, y: number): object[];
+            }
+            declare class Base {}
+            declare class Canvas {}
+            interface ChartConfig {
+                type: string;
+                plugins?: Plugin[];
+            }
+            interface Plugin {
+                id: string;
+            }
+            type UpdateMode = "resize" | "reset" | "none";
+            export declare function register<T extends Plugin>(plugin: T): Promise<T>;
+            /** Formats `value` like the chart's axes. */
+            export declare function format(value: number): string;
+            export declare namespace helpers {
+                function color(value: string | number): string;
+            }
+        "#;
+
+        let options = Options {
+            features: true,
+            ..Default::default()
+        };
+        let features = compile_typescript(declarations, Some("chart.js"), options).unwrap();
+
+        let chart = &features["Chart"];
+        assert_eq!(chart.required_features, ["Base"]);
+        let code = &chart.code;
+        assert!(code.contains("module = \"chart.js\""), "{code}");
+        assert!(code.contains("extends = Base"), "{code}");
+        assert!(code.contains("typescript_type = \"Chart\""), "{code}");
+        assert!(code.contains("A chart drawn on a canvas."), "{code}");
+        // Union arguments and omitted optional arguments get their own
+        // bindings, named like WebIDL overloads.
+        assert!(code.contains("pub fn new_with_canvas ("), "{code}");
+        assert!(code.contains("pub fn new_with_str_and_config ("), "{code}");
+        assert!(code.contains("pub fn update ("), "{code}");
+        assert!(code.contains("pub fn update_with_mode ("), "{code}");
+        assert!(
+            code.contains("pub fn get_elements_at_event_with_e ("),
+            "{code}"
+        );
+        assert!(
+            code.contains("pub fn get_elements_at_event_with_x_and_y ("),
+            "{code}"
+        );
+        assert!(code.contains("pub fn set_data ("), "{code}");
+        assert!(code.contains("-> Option < :: js_sys :: Array >"), "{code}");
+        assert!(code.contains("variadic"), "{code}");
+        assert!(code.contains("pub fn register_0 ("), "{code}");
+
+        // Interfaces only exist in TypeScript and aren't imported from the
+        // module.
+        let config = &features["ChartConfig"].code;
+        assert!(!config.contains("module ="), "{config}");
+        assert!(
+            config.contains(
+                "pub fn plugins (this : & ChartConfig ,) -> Option < :: js_sys :: Array >"
+            ),
+            "{config}"
+        );
+
+        let update_mode = &features["UpdateMode"].code;
+        assert!(update_mode.contains("Resize = \"resize\""), "{update_mode}");
+
+        let register = &features["register"].code;
+        assert!(register.contains("module = \"chart.js\""), "{register}");
+        assert!(
+            register.contains(
+                "pub fn register (plugin : & :: wasm_bindgen :: JsValue) -> :: js_sys :: Promise"
+            ),
+            "{register}"
+        );
+        // Without JSDoc, a global function is only documented with the
+        // features it requires.
+        assert!(
+            !register.contains("The `register()` function."),
+            "{register}"
+        );
+        assert!(
+            register.contains("# [doc = \"*This API requires the following crate features to be activated: `register`*\"] pub fn register"),
+            "{register}"
+        );
+
+        let format = &features["format"].code;
+        assert!(
+            format.contains("# [doc = \"Formats `value` like the chart's axes.\"]"),
+            "{format}"
+        );
+
+        let helpers = &features["helpers"].code;
+        assert!(helpers.contains("js_namespace = helpers"), "{helpers}");
+        assert!(helpers.contains("pub fn color_with_str ("), "{helpers}");
+        assert!(helpers.contains("pub fn color_with_f64 ("), "{helpers}");
+    }
 }
@@ -6,7 +6,7 @@ use std::path::PathBuf;
 use update_cargo_toml::update_cargo_toml_features;
 
 #[derive(Parser, Debug)]
-#[clap(about = "Converts WebIDL into wasm-bindgen compatible code.")]
+#[clap(about = "Converts WebIDL or TypeScript declarations into wasm-bindgen compatible code.")]
 struct Opt {
     input_dir: PathBuf,
 
@@ -18,6 +18,25 @@ struct Opt {
     #[clap(long)]
     next_unstable: bool,
 
+    /// Type promises returned by stable APIs with their resolution type.
+    #[clap(long)]
+    typed_promises: bool,
+
+    /// Generate Rust traits for callback interfaces and type callbacks with
+    /// their signature.
+    #[clap(long)]
+    callback_traits: bool,
+
+    /// Read TypeScript declaration files (`*.d.ts`) from the input directory
+    /// instead of WebIDL.
+    #[clap(long)]
+    typescript: bool,
+
+    /// The JS module TypeScript declarations are imported from, unless they
+    /// are in a `declare module` block. Without it, they're global.
+    #[clap(long, requires = "typescript")]
+    module: Option<String>,
+
     cargo_toml_path: Option<PathBuf>,
 }
 
@@ -29,14 +48,23 @@ fn main() -> Result<()> {
     let features = !opt.no_features;
     let next_unstable = opt.next_unstable;
 
-    let generated_features = wasm_bindgen_webidl::generate(
-        &opt.input_dir,
-        &opt.output_dir,
-        wasm_bindgen_webidl::Options {
-            features,
-            next_unstable: std::cell::Cell::new(next_unstable),
-        },
-    )?;
+    let options = wasm_bindgen_webidl::Options {
+        features,
+        next_unstable: std::cell::Cell::new(next_unstable),
+        typed_promises: opt.typed_promises,
+        callback_traits: opt.callback_traits,
+    };
+
+    let generated_features = if opt.typescript {
+        wasm_bindgen_webidl::generate_typescript(
+            &opt.input_dir,
+            &opt.output_dir,
+            opt.module.as_deref(),
+            options,
+        )?
+    } else {
+        wasm_bindgen_webidl::generate(&opt.input_dir, &opt.output_dir, options)?
+    };
 
     if let Some(cargo_toml_path) = opt.cargo_toml_path {
         if features {
@@ -0,0 +1,1126 @@
+//! Generates bindings from TypeScript declaration files.
+//!
+//! Declarations are converted into the same types the WebIDL definitions are,
+//! so that the bindings look like the ones of `web-sys`: classes and
+//! interfaces become imported types which extend their declared supertypes,
+//! properties become getters and setters, overloads are expanded and named
+//! like WebIDL overloads, string enums and unions of string literals become
+//! enums, and namespaces become modules.
+//!
+//! Types which can't be represented in Rust, like type parameters and types
+//! imported from other declaration files, are bound as `JsValue`.
+
+mod parser;
+
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, HashSet};
+
+use anyhow::Result;
+use proc_macro2::Ident;
+use quote::ToTokens;
+
+use crate::generator::{
+    Enum, EnumVariant, Function, Interface, InterfaceAttribute, InterfaceAttributeKind,
+    InterfaceMethod, InterfaceMethodKind, ModuleFunctions, Namespace,
+};
+use crate::util::{
+    camel_case_ident, rust_ident, snake_case_ident, TypePosition, MAX_VARIADIC_ARGUMENTS_COUNT,
+};
+use crate::wbg_type::{IdentifierType, WbgType};
+use crate::{Options, Program};
+use parser::{Item, Member, Param, Signature, Type};
+
+/// Parses TypeScript declarations into the programs of their bindings, keyed
+/// by the name of their feature.
+///
+/// Declarations outside of a `declare module` block are imported from
+/// `module`, or from the global scope if it's `None`.
+pub(crate) fn parse<'src>(
+    source: &'src str,
+    module: Option<&'src str>,
+    options: &Options,
+) -> Result<BTreeMap<String, Program>> {
+    let items = parser::parse(source)?;
+
+    let mut declarations = Declarations::default();
+    declarations.collect(&items, module, None);
+
+    let mut programs = BTreeMap::new();
+
+    for (js_name, declaration) in declarations.types.iter() {
+        let name = rust_ident(&camel_case_ident(js_name));
+        match declaration {
+            Declaration::Interface(data) => {
+                if let Some(program) = new_program(&mut programs, &name, js_name) {
+                    declarations.append_interface(program, options, name, js_name, data);
+                }
+            }
+            Declaration::StringEnum(variants) => {
+                if let Some(program) = new_program(&mut programs, &name, js_name) {
+                    Enum {
+                        name,
+                        variants: variants
+                            .iter()
+                            .map(|(name, value)| EnumVariant {
+                                name: name.clone(),
+                                value: value.to_string(),
+                            })
+                            .collect(),
+                        unstable: false,
+                    }
+                    .generate(options)
+                    .to_tokens(&mut program.tokens);
+                }
+            }
+            Declaration::Primitive(_) | Declaration::Alias(_) => {}
+        }
+    }
+
+    for (js_name, data) in declarations.namespaces.iter() {
+        let name = rust_ident(&snake_case_ident(js_name));
+        let functions = declarations.functions(options, Some(js_name), &data.functions);
+        if functions.is_empty() {
+            continue;
+        }
+        if let Some(program) = new_program(&mut programs, &name, js_name) {
+            Namespace {
+                name,
+                js_name: js_name.to_string(),
+                js_module: data.module.map(String::from),
+                consts: Vec::new(),
+                attributes: Vec::new(),
+                functions,
+                unstable: false,
+            }
+            .generate(options)
+            .to_tokens(&mut program.tokens);
+        }
+    }
+
+    for (js_name, data) in declarations.functions.iter() {
+        let name = rust_ident(&snake_case_ident(js_name));
+        let functions = declarations.functions(options, None, &data.overloads);
+        if functions.is_empty() {
+            continue;
+        }
+        if let Some(program) = new_program(&mut programs, &name, js_name) {
+            ModuleFunctions {
+                name,
+                js_module: data.module.map(String::from),
+                functions,
+            }
+            .generate(options)
+            .to_tokens(&mut program.tokens);
+        }
+    }
+
+    Ok(programs)
+}
+
+/// Returns the program of the declaration `js_name` bound as `name`, unless
+/// another declaration is already bound with that name.
+fn new_program<'p>(
+    programs: &'p mut BTreeMap<String, Program>,
+    name: &Ident,
+    js_name: &str,
+) -> Option<&'p mut Program> {
+    match programs.entry(name.to_string()) {
+        Entry::Vacant(entry) => Some(entry.insert(Program::default())),
+        Entry::Occupied(_) => {
+            log::warn!("skipping {js_name}, whose name is taken by another declaration");
+            None
+        }
+    }
+}
+
+/// A type declared by the declaration files.
+enum Declaration<'a, 'src> {
+    Interface(InterfaceData<'a, 'src>),
+    /// An enum of strings, or a type alias of a union of string literals,
+    /// bound as an enum with these variants.
+    StringEnum(Vec<(Ident, &'src str)>),
+    /// Any other enum, bound as the type of its values.
+    Primitive(WbgType<'src>),
+    Alias(&'a parser::TypeAlias<'src>),
+}
+
+/// The merged declarations of an interface or class.
+struct InterfaceData<'a, 'src> {
+    /// The module the class is imported from.
+    module: Option<&'src str>,
+    /// Whether this is a class which exists at runtime, and can thus be
+    /// constructed and checked with `instanceof`.
+    is_class: bool,
+    declarations: Vec<&'a parser::Interface<'src>>,
+}
+
+struct NamespaceData<'a, 'src> {
+    /// The module the namespace is imported from.
+    module: Option<&'src str>,
+    functions: Vec<&'a parser::Function<'src>>,
+}
+
+struct FunctionData<'a, 'src> {
+    /// The module the function is imported from.
+    module: Option<&'src str>,
+    overloads: Vec<&'a parser::Function<'src>>,
+}
+
+#[derive(Default)]
+struct Declarations<'a, 'src> {
+    types: BTreeMap<&'src str, Declaration<'a, 'src>>,
+    namespaces: BTreeMap<&'src str, NamespaceData<'a, 'src>>,
+    functions: BTreeMap<&'src str, FunctionData<'a, 'src>>,
+}
+
+/// The context types are converted in.
+#[derive(Clone, Default)]
+struct Scope<'src> {
+    /// The class or interface `this` refers to.
+    this: Option<&'src str>,
+    /// The type parameters in scope, along with the types they're bound as.
+    type_params: Vec<(&'src str, WbgType<'src>)>,
+    /// The type aliases being resolved, so that recursive ones aren't
+    /// resolved forever.
+    aliases: Vec<&'src str>,
+}
+
+impl<'src> Scope<'src> {
+    /// Returns this scope with the type parameters of a generic declaration,
+    /// which are bound as `JsValue`.
+    fn with_type_params(&self, type_params: &[&'src str]) -> Scope<'src> {
+        let mut scope = self.clone();
+        scope
+            .type_params
+            .extend(type_params.iter().map(|name| (*name, WbgType::Any)));
+        scope
+    }
+}
+
+/// An overload of an operation with its optional arguments omitted and its
+/// unions flattened, see `create_imports`.
+#[derive(PartialEq)]
+struct ExpandedSignature<'src> {
+    /// The index of the overload this was expanded from.
+    overload: usize,
+    /// The types of the leading parameters of the overload which are passed.
+    args: Vec<WbgType<'src>>,
+    variadic: bool,
+}
+
+/// Whether `name` can be bound in Rust, which isn't the case for JS
+/// identifiers with `$` or non-ASCII characters.
+fn is_ident(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !snake_case_ident(name).is_empty()
+}
+
+/// Converts the text of a JSDoc comment into the text of a doc comment.
+fn doc_comment(doc: Option<&str>) -> Option<String> {
+    let lines = doc?
+        .lines()
+        .map(|line| {
+            let line = line.trim_start();
+            let line = line.strip_prefix('*').unwrap_or(line);
+            line.strip_prefix(' ').unwrap_or(line).trim_end()
+        })
+        .collect::<Vec<_>>();
+    let doc = lines.join("\n").trim_matches('\n').to_string();
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc)
+    }
+}
+
+/// Returns the deprecation of a declaration from its `@deprecated` JSDoc tag.
+fn deprecated(doc: Option<&str>) -> Option<Option<String>> {
+    let doc = doc_comment(doc)?;
+    let note = doc
+        .lines()
+        .find_map(|line| line.strip_prefix("@deprecated"))?
+        .trim();
+    Some(if note.is_empty() {
+        None
+    } else {
+        Some(note.to_string())
+    })
+}
+
+/// Returns the name of the parameter at `index`, naming destructured ones
+/// after their position.
+fn param_name(param: &Param<'_>, index: usize) -> String {
+    match param.name {
+        Some(name) if is_ident(name) => name.to_string(),
+        _ => format!("arg{index}"),
+    }
+}
+
+fn interface(name: &str) -> WbgType<'_> {
+    WbgType::Identifier {
+        name,
+        ty: IdentifierType::Interface(name),
+    }
+}
+
+/// Returns the variants of an enum of `values`, named by the accompanying
+/// names, or `None` if they can't be named uniquely.
+fn string_enum<'src>(
+    values: impl IntoIterator<Item = (&'src str, &'src str)>,
+) -> Option<Vec<(Ident, &'src str)>> {
+    let mut names = HashSet::new();
+    let mut variants = Vec::new();
+    for (name, value) in values {
+        let name = if name.is_empty() {
+            "None".to_string()
+        } else {
+            camel_case_ident(name)
+        };
+        if name.is_empty() || !is_ident(&name) || !names.insert(name.clone()) {
+            return None;
+        }
+        variants.push((rust_ident(&name), value));
+    }
+    Some(variants)
+}
+
+impl<'a, 'src> Declarations<'a, 'src> {
+    fn collect(
+        &mut self,
+        items: &'a [Item<'src>],
+        module: Option<&'src str>,
+        namespace: Option<&'src str>,
+    ) {
+        for item in items {
+            let name = match item {
+                Item::Interface(parser::Interface { name, .. })
+                | Item::Function(parser::Function { name, .. })
+                | Item::Enum(parser::Enum { name, .. })
+                | Item::TypeAlias(parser::TypeAlias { name, .. })
+                | Item::Namespace(parser::Namespace { name, .. }) => *name,
+                Item::Module(module) => {
+                    self.collect(&module.items, module.name, namespace);
+                    continue;
+                }
+            };
+            if !is_ident(name) {
+                log::warn!("skipping {name}, which isn't a valid Rust identifier");
+                continue;
+            }
+
+            match item {
+                Item::Interface(interface) => {
+                    // Classes in namespaces can't be imported, as imported
+                    // types can't be namespaced.
+                    let is_class = interface.is_class && namespace.is_none();
+                    let declaration = self.types.entry(name).or_insert_with(|| {
+                        Declaration::Interface(InterfaceData {
+                            module,
+                            is_class: false,
+                            declarations: Vec::new(),
+                        })
+                    });
+                    match declaration {
+                        Declaration::Interface(data) => {
+                            data.is_class |= is_class;
+                            data.declarations.push(interface);
+                        }
+                        _ => log::warn!("skipping {name}, which is already declared"),
+                    }
+                }
+                Item::Function(function) => match namespace {
+                    Some(namespace) => {
+                        let data = self.namespaces.get_mut(namespace).unwrap();
+                        data.functions.push(function);
+                    }
+                    None => {
+                        let data = self.functions.entry(name).or_insert_with(|| FunctionData {
+                            module,
+                            overloads: Vec::new(),
+                        });
+                        data.overloads.push(function);
+                    }
+                },
+                Item::Enum(enum_) => {
+                    let strings = enum_
+                        .members
+                        .iter()
+                        .map(|(name, value)| value.map(|value| (*name, value)))
+                        .collect::<Option<Vec<_>>>();
+                    let declaration = match strings {
+                        Some(strings) => match string_enum(strings) {
+                            Some(variants) => Declaration::StringEnum(variants),
+                            None => Declaration::Primitive(WbgType::DomString),
+                        },
+                        None if enum_.members.iter().all(|(_, value)| value.is_none()) => {
+                            Declaration::Primitive(WbgType::Double)
+                        }
+                        None => Declaration::Primitive(WbgType::Any),
+                    };
+                    self.declare(name, declaration);
+                }
+                Item::TypeAlias(alias) => {
+                    let declaration = match &alias.ty {
+                        Type::Union(types) => types
+                            .iter()
+                            .map(|ty| match ty {
+                                Type::StringLiteral(value) => Some((*value, *value)),
+                                _ => None,
+                            })
+                            .collect::<Option<Vec<_>>>()
+                            .map(|values| match string_enum(values) {
+                                Some(variants) => Declaration::StringEnum(variants),
+                                None => Declaration::Primitive(WbgType::DomString),
+                            }),
+                        _ => None,
+                    };
+                    self.declare(name, declaration.unwrap_or(Declaration::Alias(alias)));
+                }
+                Item::Namespace(ns) => {
+                    if namespace.is_some() {
+                        log::warn!("skipping nested namespace {name}");
+                        continue;
+                    }
+                    self.namespaces
+                        .entry(name)
+                        .or_insert_with(|| NamespaceData {
+                            module,
+                            functions: Vec::new(),
+                        });
+                    self.collect(&ns.items, module, Some(name));
+                }
+                Item::Module(_) => unreachable!(),
+            }
+        }
+    }
+
+    fn declare(&mut self, name: &'src str, declaration: Declaration<'a, 'src>) {
+        if self.types.contains_key(name) {
+            log::warn!("skipping {name}, which is already declared");
+        } else {
+            self.types.insert(name, declaration);
+        }
+    }
+
+    fn wbg_type(&self, ty: &Type<'src>, scope: &Scope<'src>) -> WbgType<'src> {
+        match ty {
+            Type::Named(name, args) => self.named_type(name, args, scope),
+            Type::Array(ty) => WbgType::Sequence(Box::new(self.wbg_type(ty, scope))),
+            Type::Tuple => WbgType::Sequence(Box::new(WbgType::Any)),
+            Type::Union(types) => self.union_type(types, scope),
+            // Bind intersections as the first interface they include, whose
+            // members are then available.
+            Type::Intersection(types) => types
+                .iter()
+                .map(|ty| self.wbg_type(ty, scope))
+                .find(|ty| {
+                    matches!(
+                        ty,
+                        WbgType::Identifier {
+                            ty: IdentifierType::Interface(_),
+                            ..
+                        }
+                    )
+                })
+                .unwrap_or(WbgType::Object),
+            Type::Function(signature) => {
+                let scope = scope.with_type_params(&signature.type_params);
+                WbgType::Callback {
+                    params: signature
+                        .params
+                        .iter()
+                        .map(|param| self.param_type(param, &scope))
+                        .collect(),
+                    return_type: match self.return_type(signature, &scope) {
+                        WbgType::Undefined => None,
+                        ty => Some(Box::new(ty)),
+                    },
+                }
+            }
+            Type::Object => WbgType::Object,
+            Type::StringLiteral(_) => WbgType::DomString,
+            Type::NumberLiteral => WbgType::Double,
+            Type::BooleanLiteral | Type::Predicate => WbgType::Boolean,
+            Type::This => scope.this.map_or(WbgType::Any, interface),
+            Type::Unsupported => WbgType::Any,
+        }
+    }
+
+    fn named_type(
+        &self,
+        name: &'src str,
+        args: &[Type<'src>],
+        scope: &Scope<'src>,
+    ) -> WbgType<'src> {
+        if let Some((_, ty)) = scope
+            .type_params
+            .iter()
+            .rev()
+            .find(|(param, _)| *param == name)
+        {
+            return ty.clone();
+        }
+        let arg = |index: usize| {
+            args.get(index)
+                .map_or(WbgType::Any, |ty| self.wbg_type(ty, scope))
+        };
+        match name {
+            "string" | "String" => WbgType::DomString,
+            "number" | "Number" => WbgType::Double,
+            "boolean" | "Boolean" => WbgType::Boolean,
+            "bigint" | "BigInt" => WbgType::BigInt,
+            "symbol" | "Symbol" => WbgType::Symbol,
+            "object" | "Object" | "Record" | "Pick" | "Omit" => WbgType::Object,
+            "any" | "unknown" | "never" => WbgType::Any,
+            "void" | "undefined" | "null" => WbgType::Undefined,
+            "Date" => WbgType::Date,
+            "Error" => WbgType::Error,
+            "ArrayBuffer" => WbgType::ArrayBuffer,
+            "DataView" => WbgType::DataView {
+                allow_shared: false,
+            },
+            "Int8Array" | "Uint8Array" | "Uint8ClampedArray" | "Int16Array" | "Uint16Array"
+            | "Int32Array" | "Uint32Array" | "Float32Array" | "Float64Array" => {
+                let (allow_shared, immutable) = (false, false);
+                match name {
+                    "Int8Array" => WbgType::Int8Array {
+                        allow_shared,
+                        immutable,
+                    },
+                    "Uint8Array" => WbgType::Uint8Array {
+                        allow_shared,
+                        immutable,
+                    },
+                    "Uint8ClampedArray" => WbgType::Uint8ClampedArray {
+                        allow_shared,
+                        immutable,
+                    },
+                    "Int16Array" => WbgType::Int16Array {
+                        allow_shared,
+                        immutable,
+                    },
+                    "Uint16Array" => WbgType::Uint16Array {
+                        allow_shared,
+                        immutable,
+                    },
+                    "Int32Array" => WbgType::Int32Array {
+                        allow_shared,
+                        immutable,
+                    },
+                    "Uint32Array" => WbgType::Uint32Array {
+                        allow_shared,
+                        immutable,
+                    },
+                    "Float32Array" => WbgType::Float32Array {
+                        allow_shared,
+                        immutable,
+                    },
+                    _ => WbgType::Float64Array {
+                        allow_shared,
+                        immutable,
+                    },
+                }
+            }
+            "Function" | "CallableFunction" => WbgType::Identifier {
+                name,
+                ty: IdentifierType::Callback,
+            },
+            "Promise" | "PromiseLike" => WbgType::Promise(Box::new(arg(0))),
+            "Array" | "ReadonlyArray" => WbgType::Sequence(Box::new(arg(0))),
+            "Iterator" | "IterableIterator" | "Generator" => WbgType::Iterator(Box::new(arg(0))),
+            "AsyncIterator" | "AsyncIterableIterator" | "AsyncGenerator" => {
+                WbgType::AsyncIterator(Box::new(arg(0)))
+            }
+            "Partial" | "Required" | "Readonly" | "NonNullable" => arg(0),
+            _ => match self.types.get(name) {
+                Some(Declaration::Interface(_)) => interface(name),
+                Some(Declaration::StringEnum(_)) => WbgType::Identifier {
+                    name,
+                    ty: IdentifierType::Enum(name),
+                },
+                Some(Declaration::Primitive(ty)) => ty.clone(),
+                Some(Declaration::Alias(alias)) => {
+                    if scope.aliases.contains(&name) {
+                        return WbgType::Any;
+                    }
+                    let mut alias_scope = scope.clone();
+                    alias_scope.aliases.push(name);
+                    alias_scope.type_params.extend(
+                        alias
+                            .type_params
+                            .iter()
+                            .enumerate()
+                            .map(|(index, param)| (*param, arg(index))),
+                    );
+                    self.wbg_type(&alias.ty, &alias_scope)
+                }
+                None => WbgType::Any,
+            },
+        }
+    }
+
+    fn union_type(&self, types: &[Type<'src>], scope: &Scope<'src>) -> WbgType<'src> {
+        let mut nullable = false;
+        let mut members = Vec::new();
+        let mut push = |ty: WbgType<'src>| {
+            if !members.contains(&ty) {
+                members.push(ty);
+            }
+        };
+        for ty in types {
+            match self.wbg_type(ty, scope) {
+                WbgType::Undefined => nullable = true,
+                WbgType::JsOption(ty) => {
+                    nullable = true;
+                    push(*ty);
+                }
+                WbgType::Union(types) => types.into_iter().for_each(&mut push),
+                ty => push(ty),
+            }
+        }
+
+        let ty = if members.contains(&WbgType::Any) {
+            return WbgType::Any;
+        } else if members.len() > 1 {
+            WbgType::Union(members)
+        } else if let Some(ty) = members.pop() {
+            ty
+        } else {
+            return WbgType::Undefined;
+        };
+        if nullable {
+            WbgType::JsOption(Box::new(ty))
+        } else {
+            ty
+        }
+    }
+
+    fn param_type(&self, param: &Param<'src>, scope: &Scope<'src>) -> WbgType<'src> {
+        match (param.rest, self.wbg_type(&param.ty, scope)) {
+            (true, WbgType::Sequence(ty)) => *ty,
+            (true, _) => WbgType::Any,
+            (false, ty) => ty,
+        }
+    }
+
+    fn return_type(&self, signature: &Signature<'src>, scope: &Scope<'src>) -> WbgType<'src> {
+        signature
+            .ret
+            .as_ref()
+            .map_or(WbgType::Any, |ty| self.wbg_type(ty, scope))
+    }
+
+    /// Returns the declared supertypes of the interface `js_name`, nearest
+    /// first.
+    fn superclasses(&self, js_name: &'src str) -> Vec<&'src str> {
+        let mut parents = Vec::new();
+        let mut current = js_name;
+        let mut next = 0;
+        loop {
+            if let Some(Declaration::Interface(data)) = self.types.get(current) {
+                for parent in data.declarations.iter().flat_map(|x| &x.extends) {
+                    if let Type::Named(parent, _) = parent {
+                        if *parent != js_name
+                            && !parents.contains(parent)
+                            && matches!(self.types.get(parent), Some(Declaration::Interface(_)))
+                        {
+                            parents.push(*parent);
+                        }
+                    }
+                }
+            }
+            match parents.get(next) {
+                Some(parent) => current = parent,
+                None => break parents,
+            }
+            next += 1;
+        }
+    }
+
+    fn append_interface(
+        &self,
+        program: &mut Program,
+        options: &Options,
+        name: Ident,
+        js_name: &'src str,
+        data: &InterfaceData<'a, 'src>,
+    ) {
+        let scope = Scope {
+            this: Some(js_name),
+            ..Scope::default()
+        }
+        .with_type_params(
+            &data
+                .declarations
+                .iter()
+                .flat_map(|x| x.type_params.iter().copied())
+                .collect::<Vec<_>>(),
+        );
+
+        let parents = self
+            .superclasses(js_name)
+            .into_iter()
+            .map(|parent| {
+                let ident = rust_ident(&camel_case_ident(parent));
+                program.required_features.insert(ident.to_string());
+                ident
+            })
+            .collect::<Vec<_>>();
+
+        let mut attributes = Vec::new();
+        let mut constructors = Vec::new();
+        let mut operations: Vec<((bool, &'src str), Vec<_>)> = Vec::new();
+        for member in data.declarations.iter().flat_map(|x| &x.members) {
+            match member {
+                Member::Property(property) => {
+                    if !is_ident(property.name) {
+                        log::warn!("skipping {js_name}.{}", property.name);
+                    } else if data.is_class || !property.is_static {
+                        self.append_property(&mut attributes, options, property, &scope);
+                    }
+                }
+                Member::Method(method) => {
+                    let key = (method.is_static, method.name);
+                    if !is_ident(method.name) {
+                        log::warn!("skipping {js_name}.{}()", method.name);
+                    } else if method.is_static && !data.is_class {
+                        continue;
+                    } else if let Some((_, overloads)) =
+                        operations.iter_mut().find(|(x, _)| *x == key)
+                    {
+                        overloads.push((&method.signature, method.doc));
+                    } else {
+                        operations.push((key, vec![(&method.signature, method.doc)]));
+                    }
+                }
+                Member::Constructor(constructor) => {
+                    if data.is_class {
+                        constructors.push((&constructor.signature, constructor.doc));
+                    }
+                }
+            }
+        }
+
+        let mut methods = Vec::new();
+        if !constructors.is_empty() {
+            methods.extend(self.operation(
+                options,
+                "new",
+                &InterfaceMethodKind::Constructor(None),
+                false,
+                &constructors,
+                &scope,
+                Some(&format!(
+                    "The `new {js_name}(..)` constructor, creating a new instance of `{js_name}`."
+                )),
+            ));
+        }
+        for ((is_static, method), overloads) in operations {
+            methods.extend(self.operation(
+                options,
+                method,
+                &InterfaceMethodKind::Regular,
+                is_static,
+                &overloads,
+                &scope,
+                Some(&format!("The `{method}()` method.")),
+            ));
+        }
+
+        // Overloads and accessors can end up with the same Rust names, of
+        // which only the first is bound.
+        let mut names = HashSet::new();
+        attributes.retain(|x: &InterfaceAttribute| {
+            let unique = names.insert(rust_ident(&x.rust_name).to_string());
+            if !unique {
+                log::warn!("skipping {js_name}.{}, whose name is taken", x.js_name);
+            }
+            unique
+        });
+        methods.retain(|x: &InterfaceMethod<'_>| {
+            let unique = names.insert(x.name.to_string());
+            if !unique {
+                log::warn!("skipping {js_name}.{}(), whose name is taken", x.js_name);
+            }
+            unique
+        });
+
+        let doc = data
+            .declarations
+            .iter()
+            .find_map(|x| doc_comment(x.doc))
+            .unwrap_or_else(|| {
+                if data.is_class {
+                    format!("The `{js_name}` class.")
+                } else {
+                    format!("The `{js_name}` interface.")
+                }
+            });
+
+        Interface {
+            name,
+            js_name: js_name.to_string(),
+            // Interfaces only exist in declarations, so only classes are
+            // actually imported.
+            js_module: data.module.filter(|_| data.is_class).map(String::from),
+            doc: Some(doc),
+            deprecated: data.declarations.iter().find_map(|x| deprecated(x.doc)),
+            has_interface: data.is_class,
+            parents,
+            consts: Vec::new(),
+            attributes,
+            methods,
+            unstable: false,
+        }
+        .generate(options)
+        .to_tokens(&mut program.tokens);
+    }
+
+    fn append_property(
+        &self,
+        attributes: &mut Vec<InterfaceAttribute>,
+        options: &Options,
+        property: &parser::Property<'src>,
+        scope: &Scope<'src>,
+    ) {
+        let generics_compat = !options.next_unstable.get();
+        let js_name = property.name;
+        let wbg_type = self.wbg_type(&property.ty, scope);
+        let doc = doc_comment(property.doc);
+
+        if property.get {
+            let wbg_type = match wbg_type {
+                WbgType::JsOption(_) => wbg_type.clone(),
+                _ if property.optional => WbgType::JsOption(Box::new(wbg_type.clone())),
+                _ => wbg_type.clone(),
+            };
+            let ty = wbg_type
+                .to_syn_type(
+                    TypePosition::RETURN,
+                    false,
+                    generics_compat && !options.typed_return(&wbg_type),
+                )
+                .unwrap_or(None);
+
+            if let Some(ty) = ty {
+                attributes.push(InterfaceAttribute {
+                    js_name: js_name.to_string(),
+                    rust_name: snake_case_ident(js_name),
+                    deprecated: deprecated(property.doc),
+                    ty,
+                    is_static: property.is_static,
+                    structural: true,
+                    catch: false,
+                    kind: InterfaceAttributeKind::Getter,
+                    unstable: false,
+                    doc: Some(doc.clone().unwrap_or_else(|| {
+                        format!("Getter for the `{js_name}` field of this object.")
+                    })),
+                    has_unstable_override: false,
+                    typed_promise: false,
+                });
+            }
+        }
+
+        if property.set {
+            let wbg_types = wbg_type.flatten(None);
+            let any_different_type = wbg_types.len() > 1;
+
+            for (wbg_type, ty) in wbg_types.into_iter().filter_map(|wbg_type| {
+                let generics_compat = generics_compat && !options.typed_callback(&wbg_type);
+                wbg_type
+                    .to_syn_type(TypePosition::ARGUMENT, false, generics_compat)
+                    .ok()
+                    .flatten()
+                    .map(|ty| (wbg_type, ty))
+            }) {
+                let mut rust_name = format!("set_{}", snake_case_ident(js_name));
+
+                if any_different_type {
+                    let mut ext = String::new();
+                    wbg_type.push_snake_case_name(&mut ext);
+                    rust_name.push('_');
+                    rust_name.push_str(&snake_case_ident(&ext));
+                }
+
+                attributes.push(InterfaceAttribute {
+                    js_name: js_name.to_string(),
+                    rust_name,
+                    deprecated: deprecated(property.doc),
+                    ty,
+                    is_static: property.is_static,
+                    structural: true,
+                    catch: false,
+                    kind: InterfaceAttributeKind::Setter,
+                    unstable: false,
+                    doc: Some(doc.clone().unwrap_or_else(|| {
+                        format!("Setter for the `{js_name}` field of this object.")
+                    })),
+                    has_unstable_override: false,
+                    typed_promise: false,
+                });
+            }
+        }
+    }
+
+    /// Binds `functions`, which are properties of the `namespace` object if
+    /// any.
+    fn functions(
+        &self,
+        options: &Options,
+        namespace: Option<&str>,
+        functions: &[&'a parser::Function<'src>],
+    ) -> Vec<Function<'src>> {
+        let mut rust_names = HashSet::new();
+        let mut names = Vec::new();
+        for function in functions {
+            if !names.contains(&function.name) {
+                names.push(function.name);
+            }
+        }
+
+        names
+            .into_iter()
+            .flat_map(|js_name| {
+                let overloads = functions
+                    .iter()
+                    .filter(|x| x.name == js_name)
+                    .map(|x| (&x.signature, x.doc))
+                    .collect::<Vec<_>>();
+                self.operation(
+                    options,
+                    js_name,
+                    &InterfaceMethodKind::Regular,
+                    false,
+                    &overloads,
+                    &Scope::default(),
+                    // A global function has nothing more specific to say
+                    // about itself than its signature.
+                    namespace
+                        .map(|namespace| format!("The `{namespace}.{js_name}()` function."))
+                        .as_deref(),
+                )
+            })
+            .map(|x| Function {
+                name: x.name,
+                js_name: x.js_name,
+                arguments: x.arguments,
+                variadic_type: x.variadic_type,
+                ret_wbg_ty: x.ret_wbg_ty,
+                catch: x.catch,
+                variadic: x.variadic,
+                unstable: false,
+                doc: x.doc,
+            })
+            .filter(|x| {
+                let unique = rust_names.insert(x.name.to_string());
+                if !unique {
+                    log::warn!(
+                        "skipping an overload of {}(), whose name is taken",
+                        x.js_name
+                    );
+                }
+                unique
+            })
+            .collect()
+    }
+
+    /// Binds the overloads of an operation, expanding them into a binding for
+    /// every combination of omitted optional arguments and union members like
+    /// `create_imports` does.
+    fn operation(
+        &self,
+        options: &Options,
+        js_name: &str,
+        kind: &InterfaceMethodKind,
+        is_static: bool,
+        overloads: &[(&Signature<'src>, Option<&'src str>)],
+        scope: &Scope<'src>,
+        default_doc: Option<&str>,
+    ) -> Vec<InterfaceMethod<'src>> {
+        let mut signatures = Vec::new();
+        for (overload, (signature, _)) in overloads.iter().enumerate() {
+            let scope = scope.with_type_params(&signature.type_params);
+            let params = &signature.params;
+            let mut prefixes = vec![Vec::new()];
+            for (i, param) in params.iter().enumerate() {
+                let omittable = params[i..].iter().all(|x| x.optional || x.rest);
+                if param.optional && omittable {
+                    for args in prefixes.iter() {
+                        signatures.push(ExpandedSignature {
+                            overload,
+                            args: args.clone(),
+                            variadic: false,
+                        });
+                    }
+                }
+
+                let wbg_type = self.param_type(param, &scope);
+                if param.rest {
+                    for args in prefixes.iter_mut() {
+                        args.push(wbg_type.clone());
+                    }
+                } else {
+                    let wbg_types = if param.optional && !omittable {
+                        vec![WbgType::JsOption(Box::new(wbg_type))]
+                    } else {
+                        wbg_type.flatten(None)
+                    };
+                    prefixes = prefixes
+                        .into_iter()
+                        .flat_map(|args| {
+                            wbg_types.iter().map(move |wbg_type| {
+                                let mut args = args.clone();
+                                args.push(wbg_type.clone());
+                                args
+                            })
+                        })
+                        .collect();
+                }
+            }
+
+            let variadic = params.last().is_some_and(|x| x.rest);
+            for args in prefixes {
+                signatures.push(ExpandedSignature {
+                    overload,
+                    args,
+                    variadic,
+                });
+            }
+        }
+
+        let is_constructor = matches!(kind, InterfaceMethodKind::Constructor(_));
+        let mut methods: Vec<InterfaceMethod<'src>> = Vec::new();
+        for signature in signatures.iter() {
+            let (orig, doc) = overloads[signature.overload];
+            let scope = scope.with_type_params(&orig.type_params);
+            let rust_name = compute_rust_name(signature, &signatures, overloads, js_name);
+            let ret_wbg_ty = match (is_constructor, scope.this) {
+                (true, Some(this)) => interface(this),
+                _ => self.return_type(orig, &scope),
+            };
+            let arguments = signature
+                .args
+                .iter()
+                .zip(&orig.params)
+                .enumerate()
+                .map(|(i, (wbg_type, param))| {
+                    (
+                        rust_ident(&snake_case_ident(&param_name(param, i))),
+                        wbg_type.clone(),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            let method = InterfaceMethod {
+                name: rust_ident(&rust_name),
+                js_name: js_name.to_string(),
+                deprecated: deprecated(doc),
+                arguments,
+                variadic_type: if signature.variadic {
+                    signature.args.last().cloned()
+                } else {
+                    None
+                },
+                ret_wbg_ty: Some(ret_wbg_ty),
+                kind: kind.clone(),
+                is_static,
+                structural: true,
+                // Like WebIDL constructors, constructors are assumed to throw.
+                catch: is_constructor,
+                variadic: signature.variadic,
+                unstable: false,
+                doc: doc_comment(doc).or_else(|| default_doc.map(str::to_string)),
+                has_unstable_override: false,
+                typed_promise: false,
+            };
+            if methods.iter().any(|x| x.same_signature(&method)) {
+                continue;
+            }
+            methods.push(method.clone());
+
+            if method.variadic && !options.next_unstable.get() {
+                let last_wbg_type = signature.args.last().unwrap();
+                let last_index = signature.args.len() - 1;
+                let last_name = param_name(&orig.params[last_index], last_index);
+                for i in 0..=MAX_VARIADIC_ARGUMENTS_COUNT {
+                    let arguments = method.arguments[..last_index]
+                        .iter()
+                        .cloned()
+                        .chain((1..=i).map(|j| {
+                            (
+                                rust_ident(&snake_case_ident(&format!("{last_name}_{j}"))),
+                                last_wbg_type.clone(),
+                            )
+                        }))
+                        .collect();
+                    methods.push(InterfaceMethod {
+                        name: rust_ident(&format!("{rust_name}_{i}")),
+                        arguments,
+                        variadic: false,
+                        variadic_type: Some(last_wbg_type.clone()),
+                        ..method.clone()
+                    });
+                }
+            }
+        }
+        methods
+    }
+}
+
+/// Names an expanded signature after the arguments which distinguish it from
+/// the other expansions, like `compute_rust_name` in `create_imports`.
+fn compute_rust_name(
+    signature: &ExpandedSignature<'_>,
+    all_signatures: &[ExpandedSignature<'_>],
+    overloads: &[(&Signature<'_>, Option<&str>)],
+    js_name: &str,
+) -> String {
+    let mut rust_name = snake_case_ident(js_name);
+    let mut first = true;
+    let params = &overloads[signature.overload].0.params;
+
+    for (i, arg) in signature.args.iter().enumerate() {
+        let mut any_same_name = false;
+        let mut any_different_type = false;
+        let mut any_different = false;
+        let arg_name = param_name(&params[i], i);
+
+        for other in all_signatures {
+            if signature == other {
+                continue;
+            }
+            let other_params = &overloads[other.overload].0.params;
+            if other_params.get(i).map(|x| param_name(x, i)).as_ref() == Some(&arg_name) {
+                any_same_name = true;
+            }
+            if let Some(other_arg) = other.args.get(i) {
+                if other_arg != arg {
+                    any_different_type = true;
+                    any_different = true;
+                }
+            } else {
+                any_different = true;
+            }
+        }
+
+        if !any_different {
+            continue;
+        }
+        if first {
+            rust_name.push_str("_with_");
+            first = false;
+        } else {
+            rust_name.push_str("_and_");
+        }
+
+        if any_same_name && any_different_type {
+            arg.push_snake_case_name(&mut rust_name);
+        } else {
+            rust_name.push_str(&snake_case_ident(&arg_name));
+        }
+    }
+
+    rust_name
+}
@@ -0,0 +1,1311 @@
+//! A parser for the subset of TypeScript used by declaration files.
+//!
+//! Only the shape of declarations is kept: interfaces, classes, functions,
+//! enums, type aliases, namespaces and modules. Everything that can't be
+//! expressed in bindings, like conditional or mapped types, is parsed as
+//! [`Type::Unsupported`], and statements which don't declare anything, like
+//! imports and variables, are skipped.
+
+use anyhow::Result;
+
+use crate::TypeScriptParseError;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum TokenKind<'src> {
+    Ident(&'src str),
+    /// A string literal, without its quotes.
+    Str(&'src str),
+    Number(&'src str),
+    /// A template literal, only used as a type.
+    Template,
+    Punct(&'static str),
+    Eof,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Token<'src> {
+    kind: TokenKind<'src>,
+    /// The JSDoc comment directly preceding this token.
+    doc: Option<&'src str>,
+    /// The byte offset of the token in the source.
+    pos: usize,
+}
+
+// Longer punctuation first, so that it's matched before its prefixes. `>` is
+// never combined, so that nested type arguments like `A<B<C>>` can be closed
+// one at a time.
+const PUNCTUATION: &[&str] = &[
+    "...", "=>", "?.", "{", "}", "(", ")", "[", "]", "<", ">", ",", ";", ":", "?", ".", "=", "|",
+    "&", "*", "!", "@", "-", "+", "#",
+];
+
+fn error(position: usize, message: impl Into<String>) -> anyhow::Error {
+    TypeScriptParseError {
+        position,
+        message: message.into(),
+    }
+    .into()
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token<'_>>> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut doc = None;
+    // Skip the byte order mark which some declaration files start with.
+    let mut i = if source.starts_with('\u{feff}') { 3 } else { 0 };
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+        } else if source[i..].starts_with("//") {
+            i = source[i..].find('\n').map_or(bytes.len(), |end| i + end);
+        } else if source[i..].starts_with("/*") {
+            let Some(end) = source[i + 2..].find("*/") else {
+                return Err(error(i, "unterminated comment"));
+            };
+            let end = i + 2 + end + 2;
+            if source[i..].starts_with("/**") && end - i > 4 {
+                doc = Some(&source[i + 3..end - 2]);
+            }
+            i = end;
+        } else if c.is_ascii_alphabetic() || c == b'_' || c == b'$' || c >= 0x80 {
+            let start = i;
+            while i < bytes.len()
+                && (bytes[i].is_ascii_alphanumeric()
+                    || bytes[i] == b'_'
+                    || bytes[i] == b'$'
+                    || bytes[i] >= 0x80)
+            {
+                i += 1;
+            }
+            tokens.push(Token {
+                kind: TokenKind::Ident(&source[start..i]),
+                doc: doc.take(),
+                pos: start,
+            });
+        } else if c.is_ascii_digit() {
+            let start = i;
+            let hex = matches!(bytes.get(i + 1), Some(b'x' | b'X'));
+            while i < bytes.len()
+                && (bytes[i].is_ascii_alphanumeric()
+                    || bytes[i] == b'.'
+                    // The sign of an exponent, like in `1e-5`.
+                    || (matches!(bytes[i], b'-' | b'+')
+                        && matches!(bytes[i - 1], b'e' | b'E')
+                        && !hex))
+            {
+                i += 1;
+            }
+            tokens.push(Token {
+                kind: TokenKind::Number(&source[start..i]),
+                doc: doc.take(),
+                pos: start,
+            });
+        } else if c == b'"' || c == b'\'' || c == b'`' {
+            let start = i;
+            i += 1;
+            // Template literals can nest others in their substitutions.
+            let mut depth = 0;
+            loop {
+                match bytes.get(i) {
+                    None => return Err(error(start, "unterminated string")),
+                    Some(b'\\') => i += 2,
+                    Some(b'$') if c == b'`' && bytes.get(i + 1) == Some(&b'{') => {
+                        depth += 1;
+                        i += 2;
+                    }
+                    Some(b'}') if depth > 0 => {
+                        depth -= 1;
+                        i += 1;
+                    }
+                    Some(&b) if b == c && depth == 0 => break,
+                    Some(_) => i += 1,
+                }
+            }
+            i += 1;
+            let kind = if c == b'`' {
+                TokenKind::Template
+            } else {
+                TokenKind::Str(&source[start + 1..i - 1])
+            };
+            tokens.push(Token {
+                kind,
+                doc: doc.take(),
+                pos: start,
+            });
+        } else {
+            let Some(punct) = PUNCTUATION.iter().find(|p| source[i..].starts_with(**p)) else {
+                let c = source[i..].chars().next().unwrap();
+                return Err(error(i, format!("unexpected character `{c}`")));
+            };
+            tokens.push(Token {
+                kind: TokenKind::Punct(punct),
+                doc: doc.take(),
+                pos: i,
+            });
+            i += punct.len();
+        }
+    }
+    tokens.push(Token {
+        kind: TokenKind::Eof,
+        doc: None,
+        pos: source.len(),
+    });
+    Ok(tokens)
+}
+
+/// A declaration of a declaration file.
+#[derive(Debug)]
+pub(crate) enum Item<'src> {
+    /// An interface or a class.
+    Interface(Interface<'src>),
+    Function(Function<'src>),
+    Enum(Enum<'src>),
+    TypeAlias(TypeAlias<'src>),
+    /// A namespace, declaring the members of a JS object.
+    Namespace(Namespace<'src>),
+    /// The declarations of a JS module, or of the global scope with `declare
+    /// global`.
+    Module(Module<'src>),
+}
+
+#[derive(Debug)]
+pub(crate) struct Interface<'src> {
+    pub(crate) doc: Option<&'src str>,
+    pub(crate) name: &'src str,
+    pub(crate) is_class: bool,
+    pub(crate) type_params: Vec<&'src str>,
+    pub(crate) extends: Vec<Type<'src>>,
+    pub(crate) members: Vec<Member<'src>>,
+}
+
+#[derive(Debug)]
+pub(crate) enum Member<'src> {
+    Property(Property<'src>),
+    Method(Method<'src>),
+    Constructor(Constructor<'src>),
+}
+
+#[derive(Debug)]
+pub(crate) struct Property<'src> {
+    pub(crate) doc: Option<&'src str>,
+    pub(crate) name: &'src str,
+    pub(crate) is_static: bool,
+    pub(crate) optional: bool,
+    /// Whether the property can be read, which isn't the case for a `set`
+    /// accessor without a `get` accessor.
+    pub(crate) get: bool,
+    /// Whether the property can be written.
+    pub(crate) set: bool,
+    pub(crate) ty: Type<'src>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Method<'src> {
+    pub(crate) doc: Option<&'src str>,
+    pub(crate) name: &'src str,
+    pub(crate) is_static: bool,
+    pub(crate) signature: Signature<'src>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Constructor<'src> {
+    pub(crate) doc: Option<&'src str>,
+    pub(crate) signature: Signature<'src>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Function<'src> {
+    pub(crate) doc: Option<&'src str>,
+    pub(crate) name: &'src str,
+    pub(crate) signature: Signature<'src>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Enum<'src> {
+    pub(crate) name: &'src str,
+    /// The names and string values of the members, which are `None` for
+    /// numeric members.
+    pub(crate) members: Vec<(&'src str, Option<&'src str>)>,
+}
+
+#[derive(Debug)]
+pub(crate) struct TypeAlias<'src> {
+    pub(crate) name: &'src str,
+    pub(crate) type_params: Vec<&'src str>,
+    pub(crate) ty: Type<'src>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Namespace<'src> {
+    pub(crate) name: &'src str,
+    pub(crate) items: Vec<Item<'src>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Module<'src> {
+    /// The module specifier, which is `None` for `declare global`.
+    pub(crate) name: Option<&'src str>,
+    pub(crate) items: Vec<Item<'src>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Signature<'src> {
+    pub(crate) type_params: Vec<&'src str>,
+    pub(crate) params: Vec<Param<'src>>,
+    /// The return type, which is `None` if it's omitted and thus `any`.
+    pub(crate) ret: Option<Type<'src>>,
+}
+
+#[derive(Debug)]
+pub(crate) struct Param<'src> {
+    /// The name of the parameter, which is `None` for destructuring patterns.
+    pub(crate) name: Option<&'src str>,
+    pub(crate) optional: bool,
+    pub(crate) rest: bool,
+    pub(crate) ty: Type<'src>,
+}
+
+#[derive(Debug)]
+pub(crate) enum Type<'src> {
+    /// A reference to a named type, possibly qualified like `ns.Type`, or a
+    /// keyword type like `string`.
+    Named(&'src str, Vec<Type<'src>>),
+    Array(Box<Type<'src>>),
+    Tuple,
+    Union(Vec<Type<'src>>),
+    Intersection(Vec<Type<'src>>),
+    Function(Box<Signature<'src>>),
+    /// An object literal or mapped type.
+    Object,
+    StringLiteral(&'src str),
+    NumberLiteral,
+    BooleanLiteral,
+    /// A type predicate like `x is T`, which is a `boolean` at runtime.
+    Predicate,
+    /// The type of `this`.
+    This,
+    Unsupported,
+}
+
+/// Parses the declarations of a `.d.ts` file.
+pub(crate) fn parse(source: &str) -> Result<Vec<Item<'_>>> {
+    let tokens = tokenize(source)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let items = parser.items()?;
+    parser.expect_eof()?;
+    Ok(items)
+}
+
+struct Parser<'src> {
+    tokens: Vec<Token<'src>>,
+    pos: usize,
+}
+
+impl<'src> Parser<'src> {
+    fn peek(&self) -> TokenKind<'src> {
+        self.tokens[self.pos].kind
+    }
+
+    fn peek_at(&self, offset: usize) -> TokenKind<'src> {
+        self.tokens
+            .get(self.pos + offset)
+            .map_or(TokenKind::Eof, |t| t.kind)
+    }
+
+    fn doc(&self) -> Option<&'src str> {
+        self.tokens[self.pos].doc
+    }
+
+    fn bump(&mut self) -> TokenKind<'src> {
+        let kind = self.peek();
+        if kind != TokenKind::Eof {
+            self.pos += 1;
+        }
+        kind
+    }
+
+    fn is_punct(&self, punct: &str) -> bool {
+        matches!(self.peek(), TokenKind::Punct(x) if x == punct)
+    }
+
+    fn is_ident(&self, ident: &str) -> bool {
+        matches!(self.peek(), TokenKind::Ident(x) if x == ident)
+    }
+
+    fn eat_punct(&mut self, punct: &str) -> bool {
+        let found = self.is_punct(punct);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn eat_ident(&mut self, ident: &str) -> bool {
+        let found = self.is_ident(ident);
+        if found {
+            self.pos += 1;
+        }
+        found
+    }
+
+    fn error<T>(&self, expected: &str) -> Result<T> {
+        let token = &self.tokens[self.pos];
+        Err(error(
+            token.pos,
+            format!("expected {expected}, found {:?}", token.kind),
+        ))
+    }
+
+    fn expect_punct(&mut self, punct: &str) -> Result<()> {
+        if self.eat_punct(punct) {
+            Ok(())
+        } else {
+            self.error(&format!("`{punct}`"))
+        }
+    }
+
+    fn expect_ident(&mut self) -> Result<&'src str> {
+        match self.peek() {
+            TokenKind::Ident(ident) => {
+                self.pos += 1;
+                Ok(ident)
+            }
+            _ => self.error("an identifier"),
+        }
+    }
+
+    fn expect_eof(&self) -> Result<()> {
+        match self.peek() {
+            TokenKind::Eof => Ok(()),
+            _ => self.error("a declaration"),
+        }
+    }
+
+    /// Skips tokens up to the end of the current statement or member, which
+    /// is a `;` or `,` outside of brackets, or the `}` closing the enclosing
+    /// block.
+    fn skip_statement(&mut self) {
+        let mut depth = 0usize;
+        loop {
+            match self.peek() {
+                TokenKind::Eof => return,
+                TokenKind::Punct("{" | "(" | "[") => depth += 1,
+                TokenKind::Punct("}" | ")" | "]") if depth == 0 => return,
+                TokenKind::Punct("}" | ")" | "]") => {
+                    depth -= 1;
+                    // Blocks like function bodies end a statement.
+                    if depth == 0 && self.is_punct("}") {
+                        self.pos += 1;
+                        // `import { .. } from "module"` goes on after its braces.
+                        if self.is_ident("from") {
+                            continue;
+                        }
+                        self.eat_punct(";");
+                        return;
+                    }
+                }
+                TokenKind::Punct(";" | ",") if depth == 0 => {
+                    self.pos += 1;
+                    return;
+                }
+                _ => {}
+            }
+            self.pos += 1;
+        }
+    }
+
+    /// Skips a balanced group of brackets, starting at its opening bracket.
+    fn skip_group(&mut self) -> Result<()> {
+        let mut depth = 0usize;
+        loop {
+            match self.bump() {
+                TokenKind::Punct("{" | "(" | "[") => depth += 1,
+                TokenKind::Punct("}" | ")" | "]") => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return Ok(());
+                    }
+                }
+                TokenKind::Eof => return self.error("a closing bracket"),
+                _ => {}
+            }
+        }
+    }
+
+    fn items(&mut self) -> Result<Vec<Item<'src>>> {
+        let mut items = Vec::new();
+        while !self.is_punct("}") && self.peek() != TokenKind::Eof {
+            if let Some(item) = self.item()? {
+                items.push(item);
+            }
+        }
+        Ok(items)
+    }
+
+    fn item(&mut self) -> Result<Option<Item<'src>>> {
+        let doc = self.doc();
+        if self.eat_punct(";") {
+            return Ok(None);
+        }
+        if self.is_ident("import") || self.is_ident("export") && self.is_export_only() {
+            self.skip_statement();
+            return Ok(None);
+        }
+        self.eat_ident("export");
+        if self.eat_ident("default") {
+            log::warn!("skipping default export");
+            self.skip_statement();
+            return Ok(None);
+        }
+        while self.eat_ident("declare") || self.eat_ident("abstract") || self.eat_ident("async") {}
+
+        let item = match self.peek() {
+            TokenKind::Ident("interface") => {
+                self.pos += 1;
+                Item::Interface(self.interface(doc, false)?)
+            }
+            TokenKind::Ident("class") => {
+                self.pos += 1;
+                Item::Interface(self.interface(doc, true)?)
+            }
+            TokenKind::Ident("function") => {
+                self.pos += 1;
+                self.eat_punct("*");
+                let name = self.expect_ident()?;
+                let signature = self.signature()?;
+                self.end_of_declaration()?;
+                Item::Function(Function {
+                    doc,
+                    name,
+                    signature,
+                })
+            }
+            TokenKind::Ident("const") if self.peek_at(1) == TokenKind::Ident("enum") => {
+                self.pos += 2;
+                Item::Enum(self.enum_()?)
+            }
+            TokenKind::Ident("enum") => {
+                self.pos += 1;
+                Item::Enum(self.enum_()?)
+            }
+            TokenKind::Ident("type") if matches!(self.peek_at(1), TokenKind::Ident(_)) => {
+                self.pos += 1;
+                let name = self.expect_ident()?;
+                let type_params = self.type_params()?;
+                self.expect_punct("=")?;
+                let ty = self.ty()?;
+                self.eat_punct(";");
+                Item::TypeAlias(TypeAlias {
+                    name,
+                    type_params,
+                    ty,
+                })
+            }
+            TokenKind::Ident("namespace" | "module")
+                if matches!(self.peek_at(1), TokenKind::Ident(_)) =>
+            {
+                self.pos += 1;
+                let mut name = self.expect_ident()?;
+                if self.is_punct(".") {
+                    log::warn!("skipping nested namespace {name}");
+                    self.skip_statement();
+                    return Ok(None);
+                }
+                if !self.is_punct("{") {
+                    // A shorthand declaration without a body.
+                    self.eat_punct(";");
+                    return Ok(None);
+                }
+                let items = self.block()?;
+                if name == "global" {
+                    name = "";
+                }
+                Item::Namespace(Namespace { name, items })
+            }
+            TokenKind::Ident("module") if matches!(self.peek_at(1), TokenKind::Str(_)) => {
+                self.pos += 1;
+                let TokenKind::Str(name) = self.bump() else {
+                    unreachable!()
+                };
+                if !self.is_punct("{") {
+                    self.eat_punct(";");
+                    return Ok(None);
+                }
+                let items = self.block()?;
+                Item::Module(Module {
+                    name: Some(name),
+                    items,
+                })
+            }
+            TokenKind::Ident("global") if self.peek_at(1) == TokenKind::Punct("{") => {
+                self.pos += 1;
+                let items = self.block()?;
+                Item::Module(Module { name: None, items })
+            }
+            TokenKind::Ident("const" | "let" | "var") => {
+                log::warn!("skipping variable declaration");
+                self.skip_statement();
+                return Ok(None);
+            }
+            _ => return self.error("a declaration"),
+        };
+        // `namespace global` is how older declaration files spell `declare
+        // global`.
+        Ok(Some(match item {
+            Item::Namespace(Namespace { name: "", items }) => {
+                Item::Module(Module { name: None, items })
+            }
+            item => item,
+        }))
+    }
+
+    /// Returns whether the `export` keyword at the current position starts a
+    /// statement which doesn't declare anything, like `export { a, b };`,
+    /// `export * from "m";`, `export = a;` or `export as namespace a;`.
+    fn is_export_only(&self) -> bool {
+        match self.peek_at(1) {
+            TokenKind::Punct("{" | "*" | "=") | TokenKind::Ident("as" | "import") => true,
+            // `export type { A, B }`
+            TokenKind::Ident("type") => matches!(self.peek_at(2), TokenKind::Punct("{" | "*")),
+            _ => false,
+        }
+    }
+
+    fn end_of_declaration(&mut self) -> Result<()> {
+        if self.is_punct("{") {
+            // A body, which declaration files don't have but sources might.
+            self.skip_group()?;
+        } else {
+            self.eat_punct(";");
+        }
+        Ok(())
+    }
+
+    fn block(&mut self) -> Result<Vec<Item<'src>>> {
+        self.expect_punct("{")?;
+        let items = self.items()?;
+        self.expect_punct("}")?;
+        Ok(items)
+    }
+
+    fn interface(&mut self, doc: Option<&'src str>, is_class: bool) -> Result<Interface<'src>> {
+        let name = self.expect_ident()?;
+        let type_params = self.type_params()?;
+        let mut extends = Vec::new();
+        loop {
+            if self.eat_ident("extends") {
+                extends.push(self.ty_no_union()?);
+                while self.eat_punct(",") {
+                    extends.push(self.ty_no_union()?);
+                }
+            } else if self.eat_ident("implements") {
+                // Implemented interfaces are only checked, they aren't
+                // inherited from at runtime.
+                self.ty_no_union()?;
+                while self.eat_punct(",") {
+                    self.ty_no_union()?;
+                }
+            } else {
+                break;
+            }
+        }
+        self.expect_punct("{")?;
+        let mut members = Vec::new();
+        while !self.eat_punct("}") {
+            if self.peek() == TokenKind::Eof {
+                return self.error("`}`");
+            }
+            let start = self.pos;
+            match self.member()? {
+                Some(member) => members.push(member),
+                None if self.pos == start => return self.error("a member"),
+                None => {}
+            }
+        }
+        Ok(Interface {
+            doc,
+            name,
+            is_class,
+            type_params,
+            extends,
+            members,
+        })
+    }
+
+    fn member(&mut self) -> Result<Option<Member<'src>>> {
+        let doc = self.doc();
+        if self.eat_punct(";") || self.eat_punct(",") {
+            return Ok(None);
+        }
+
+        let mut is_static = false;
+        let mut readonly = false;
+        let mut private = false;
+        loop {
+            // Modifiers can also be used as member names, like `static(): void`.
+            let is_modifier = matches!(
+                self.peek_at(1),
+                TokenKind::Ident(_) | TokenKind::Str(_) | TokenKind::Number(_)
+            ) || self.peek_at(1) == TokenKind::Punct("[")
+                || self.peek_at(1) == TokenKind::Punct("#");
+            match self.peek() {
+                TokenKind::Ident("static") if is_modifier => is_static = true,
+                TokenKind::Ident("readonly") if is_modifier => readonly = true,
+                TokenKind::Ident("private" | "protected") if is_modifier => private = true,
+                TokenKind::Ident("public" | "abstract" | "declare" | "override" | "async")
+                    if is_modifier => {}
+                _ => break,
+            }
+            self.pos += 1;
+        }
+
+        // Accessors, unless `get` or `set` is the name of the member.
+        let accessor = match (self.peek(), self.peek_at(1)) {
+            (TokenKind::Ident(kind @ ("get" | "set")), TokenKind::Ident(_) | TokenKind::Str(_)) => {
+                self.pos += 1;
+                Some(kind)
+            }
+            _ => None,
+        };
+
+        let name = match self.peek() {
+            TokenKind::Ident(name) | TokenKind::Str(name) | TokenKind::Number(name) => {
+                self.pos += 1;
+                name
+            }
+            // Call, construct and index signatures, computed names and
+            // private names can't be bound.
+            _ => {
+                self.skip_statement();
+                return Ok(None);
+            }
+        };
+
+        if name == "new" && (self.is_punct("(") || self.is_punct("<")) {
+            // A construct signature of an interface.
+            self.signature()?;
+            self.skip_statement();
+            return Ok(None);
+        }
+
+        let optional = self.eat_punct("?");
+        self.eat_punct("!");
+
+        let member = if self.is_punct("(") || self.is_punct("<") {
+            let signature = self.signature()?;
+            self.end_of_declaration()?;
+            match accessor {
+                Some("get") => Member::Property(Property {
+                    doc,
+                    name,
+                    is_static,
+                    optional,
+                    get: true,
+                    set: false,
+                    ty: signature.ret.unwrap_or(Type::Named("any", Vec::new())),
+                }),
+                Some(_) => {
+                    let ty = match signature.params.into_iter().next() {
+                        Some(param) => param.ty,
+                        None => Type::Named("any", Vec::new()),
+                    };
+                    Member::Property(Property {
+                        doc,
+                        name,
+                        is_static,
+                        optional,
+                        get: false,
+                        set: true,
+                        ty,
+                    })
+                }
+                None if name == "constructor" => {
+                    Member::Constructor(Constructor { doc, signature })
+                }
+                None => Member::Method(Method {
+                    doc,
+                    name,
+                    is_static,
+                    signature,
+                }),
+            }
+        } else {
+            let ty = if self.eat_punct(":") {
+                self.ty()?
+            } else {
+                Type::Named("any", Vec::new())
+            };
+            if self.eat_punct("=") {
+                // An initializer, which declaration files only use for
+                // constant properties.
+                self.skip_statement();
+            } else if !self.eat_punct(";") {
+                self.eat_punct(",");
+            }
+            Member::Property(Property {
+                doc,
+                name,
+                is_static,
+                optional,
+                get: true,
+                set: !readonly,
+                ty,
+            })
+        };
+
+        if private {
+            return Ok(None);
+        }
+        Ok(Some(member))
+    }
+
+    fn enum_(&mut self) -> Result<Enum<'src>> {
+        let name = self.expect_ident()?;
+        self.expect_punct("{")?;
+        let mut members = Vec::new();
+        while !self.eat_punct("}") {
+            let member = match self.bump() {
+                TokenKind::Ident(member) | TokenKind::Str(member) => member,
+                _ => {
+                    self.pos -= 1;
+                    return self.error("an enum member");
+                }
+            };
+            let value = if self.eat_punct("=") {
+                match (self.peek(), self.peek_at(1)) {
+                    (TokenKind::Str(value), TokenKind::Punct("," | "}")) => {
+                        self.pos += 1;
+                        Some(value)
+                    }
+                    _ => {
+                        // Skip computed values up to the next member.
+                        while !matches!(self.peek(), TokenKind::Punct("," | "}") | TokenKind::Eof) {
+                            self.pos += 1;
+                        }
+                        None
+                    }
+                }
+            } else {
+                None
+            };
+            members.push((member, value));
+            if !self.eat_punct(",") {
+                self.expect_punct("}")?;
+                break;
+            }
+        }
+        Ok(Enum { name, members })
+    }
+
+    fn type_params(&mut self) -> Result<Vec<&'src str>> {
+        let mut params = Vec::new();
+        if !self.eat_punct("<") {
+            return Ok(params);
+        }
+        while !self.eat_punct(">") {
+            self.eat_ident("const");
+            self.eat_ident("in");
+            self.eat_ident("out");
+            params.push(self.expect_ident()?);
+            if self.eat_ident("extends") {
+                self.ty()?;
+            }
+            if self.eat_punct("=") {
+                self.ty()?;
+            }
+            if !self.eat_punct(",") {
+                self.expect_punct(">")?;
+                break;
+            }
+        }
+        Ok(params)
+    }
+
+    fn signature(&mut self) -> Result<Signature<'src>> {
+        let type_params = self.type_params()?;
+        let params = self.params()?;
+        let ret = if self.eat_punct(":") {
+            Some(self.return_type()?)
+        } else {
+            None
+        };
+        Ok(Signature {
+            type_params,
+            params,
+            ret,
+        })
+    }
+
+    fn params(&mut self) -> Result<Vec<Param<'src>>> {
+        self.expect_punct("(")?;
+        let mut params = Vec::new();
+        while !self.eat_punct(")") {
+            while matches!(
+                (self.peek(), self.peek_at(1)),
+                (
+                    TokenKind::Ident("public" | "private" | "protected" | "readonly" | "override"),
+                    TokenKind::Ident(_) | TokenKind::Punct("{" | "[" | "...")
+                )
+            ) {
+                self.pos += 1;
+            }
+            let rest = self.eat_punct("...");
+            let name = match self.peek() {
+                TokenKind::Ident(name) => {
+                    self.pos += 1;
+                    Some(name)
+                }
+                TokenKind::Punct("{" | "[") => {
+                    self.skip_group()?;
+                    None
+                }
+                _ => return self.error("a parameter"),
+            };
+            let optional = self.eat_punct("?");
+            let ty = if self.eat_punct(":") {
+                self.ty()?
+            } else {
+                Type::Named("any", Vec::new())
+            };
+            if self.eat_punct("=") {
+                // Default values make a parameter optional.
+                while !matches!(self.peek(), TokenKind::Punct("," | ")") | TokenKind::Eof) {
+                    if matches!(self.peek(), TokenKind::Punct("{" | "(" | "[")) {
+                        self.skip_group()?;
+                    } else {
+                        self.pos += 1;
+                    }
+                }
+            }
+            // `this` parameters only declare the type of `this`.
+            if name != Some("this") {
+                params.push(Param {
+                    name,
+                    optional,
+                    rest,
+                    ty,
+                });
+            }
+            if !self.eat_punct(",") {
+                self.expect_punct(")")?;
+                break;
+            }
+        }
+        Ok(params)
+    }
+
+    fn return_type(&mut self) -> Result<Type<'src>> {
+        // Type predicates, like `x is string` or `asserts x is string`.
+        if self.is_ident("asserts") && matches!(self.peek_at(1), TokenKind::Ident(_)) {
+            self.pos += 2;
+            if self.eat_ident("is") {
+                self.ty()?;
+            }
+            return Ok(Type::Named("void", Vec::new()));
+        }
+        if matches!(self.peek(), TokenKind::Ident(_)) && self.peek_at(1) == TokenKind::Ident("is") {
+            self.pos += 2;
+            self.ty()?;
+            return Ok(Type::Predicate);
+        }
+        self.ty()
+    }
+
+    fn ty(&mut self) -> Result<Type<'src>> {
+        let ty = self.union()?;
+        // A conditional type.
+        if self.eat_ident("extends") {
+            self.union()?;
+            self.expect_punct("?")?;
+            self.ty()?;
+            self.expect_punct(":")?;
+            self.ty()?;
+            return Ok(Type::Unsupported);
+        }
+        Ok(ty)
+    }
+
+    /// Parses a type which can be followed by a `,` or `{`, like the parents
+    /// of an interface.
+    fn ty_no_union(&mut self) -> Result<Type<'src>> {
+        self.postfix()
+    }
+
+    fn union(&mut self) -> Result<Type<'src>> {
+        self.eat_punct("|");
+        let mut types = vec![self.intersection()?];
+        while self.eat_punct("|") {
+            types.push(self.intersection()?);
+        }
+        Ok(if types.len() == 1 {
+            types.pop().unwrap()
+        } else {
+            Type::Union(types)
+        })
+    }
+
+    fn intersection(&mut self) -> Result<Type<'src>> {
+        self.eat_punct("&");
+        let mut types = vec![self.postfix()?];
+        while self.eat_punct("&") {
+            types.push(self.postfix()?);
+        }
+        Ok(if types.len() == 1 {
+            types.pop().unwrap()
+        } else {
+            Type::Intersection(types)
+        })
+    }
+
+    fn postfix(&mut self) -> Result<Type<'src>> {
+        let mut ty = self.primary()?;
+        while self.is_punct("[") {
+            if self.peek_at(1) == TokenKind::Punct("]") {
+                self.pos += 2;
+                ty = Type::Array(Box::new(ty));
+            } else {
+                // An indexed access type like `T["key"]`.
+                self.skip_group()?;
+                ty = Type::Unsupported;
+            }
+        }
+        Ok(ty)
+    }
+
+    fn primary(&mut self) -> Result<Type<'src>> {
+        Ok(match self.peek() {
+            TokenKind::Punct("(") => {
+                if let Some(signature) = self.try_function_type()? {
+                    Type::Function(Box::new(signature))
+                } else {
+                    self.pos += 1;
+                    let ty = self.ty()?;
+                    self.expect_punct(")")?;
+                    ty
+                }
+            }
+            TokenKind::Punct("<") => {
+                let signature = self.function_type()?;
+                Type::Function(Box::new(signature))
+            }
+            TokenKind::Ident("new") => {
+                self.pos += 1;
+                self.function_type()?;
+                Type::Unsupported
+            }
+            TokenKind::Ident("abstract") if self.peek_at(1) == TokenKind::Ident("new") => {
+                self.pos += 2;
+                self.function_type()?;
+                Type::Unsupported
+            }
+            TokenKind::Punct("{") => {
+                self.skip_group()?;
+                Type::Object
+            }
+            TokenKind::Punct("[") => {
+                self.skip_group()?;
+                Type::Tuple
+            }
+            TokenKind::Str(value) => {
+                self.pos += 1;
+                Type::StringLiteral(value)
+            }
+            TokenKind::Template => {
+                self.pos += 1;
+                Type::Named("string", Vec::new())
+            }
+            TokenKind::Number(_) => {
+                self.pos += 1;
+                Type::NumberLiteral
+            }
+            TokenKind::Punct("-") if matches!(self.peek_at(1), TokenKind::Number(_)) => {
+                self.pos += 2;
+                Type::NumberLiteral
+            }
+            TokenKind::Ident("true" | "false") => {
+                self.pos += 1;
+                Type::BooleanLiteral
+            }
+            TokenKind::Ident("this") => {
+                self.pos += 1;
+                Type::This
+            }
+            TokenKind::Ident("typeof") => {
+                self.pos += 1;
+                if self.eat_ident("import") {
+                    self.skip_group()?;
+                }
+                self.qualified_name()?;
+                if self.is_punct("<") {
+                    self.type_args()?;
+                }
+                Type::Unsupported
+            }
+            TokenKind::Ident("keyof" | "unique" | "readonly") => {
+                let keyword = self.bump();
+                let ty = self.postfix()?;
+                match keyword {
+                    // `readonly T[]` is an array at runtime.
+                    TokenKind::Ident("readonly") => ty,
+                    TokenKind::Ident("unique") => Type::Named("symbol", Vec::new()),
+                    _ => Type::Unsupported,
+                }
+            }
+            TokenKind::Ident("infer") => {
+                self.pos += 1;
+                self.expect_ident()?;
+                if self.eat_ident("extends") {
+                    self.postfix()?;
+                }
+                Type::Unsupported
+            }
+            TokenKind::Ident("import") => {
+                // `import("module").Type`
+                self.pos += 1;
+                self.skip_group()?;
+                while self.eat_punct(".") {
+                    self.expect_ident()?;
+                }
+                if self.is_punct("<") {
+                    self.type_args()?;
+                }
+                Type::Unsupported
+            }
+            TokenKind::Ident(_) => {
+                let name = self.qualified_name()?;
+                let args = if self.is_punct("<") {
+                    self.type_args()?
+                } else {
+                    Vec::new()
+                };
+                Type::Named(name, args)
+            }
+            _ => return self.error("a type"),
+        })
+    }
+
+    /// Parses a possibly qualified name like `a.b.C`.
+    fn qualified_name(&mut self) -> Result<&'src str> {
+        self.expect_ident()?;
+        while self.is_punct(".") && matches!(self.peek_at(1), TokenKind::Ident(_)) {
+            self.pos += 2;
+        }
+        // Qualified names are only resolved by their last segment.
+        match self.tokens[self.pos - 1].kind {
+            TokenKind::Ident(name) => Ok(name),
+            _ => unreachable!(),
+        }
+    }
+
+    fn type_args(&mut self) -> Result<Vec<Type<'src>>> {
+        self.expect_punct("<")?;
+        let mut args = Vec::new();
+        while !self.eat_punct(">") {
+            args.push(self.ty()?);
+            if !self.eat_punct(",") {
+                self.expect_punct(">")?;
+                break;
+            }
+        }
+        Ok(args)
+    }
+
+    /// Parses a function type like `(a: A) => R` if the parenthesis at the
+    /// current position starts one, rather than a parenthesized type.
+    fn try_function_type(&mut self) -> Result<Option<Signature<'src>>> {
+        let start = self.pos;
+        let mut depth = 0usize;
+        // Find the matching parenthesis, and check whether it's followed by
+        // an arrow.
+        loop {
+            match self.bump() {
+                TokenKind::Punct("{" | "(" | "[") => depth += 1,
+                TokenKind::Punct("}" | ")" | "]") => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                TokenKind::Eof => {
+                    self.pos = start;
+                    return self.error("`)`");
+                }
+                _ => {}
+            }
+        }
+        let is_function = self.is_punct("=>");
+        self.pos = start;
+        if is_function {
+            self.function_type().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn function_type(&mut self) -> Result<Signature<'src>> {
+        let type_params = self.type_params()?;
+        let params = self.params()?;
+        self.expect_punct("=>")?;
+        let ret = Some(self.return_type()?);
+        Ok(Signature {
+            type_params,
+            params,
+            ret,
+        })
+    }
+}
+
+#[test]
+fn parse_declarations() {
+    let items = parse(
+        r#"
+        /// <reference types="node" />
+        import { Foo } from "./foo";
+
+        /** A chart. */
+        export declare class Chart<T = any> extends Base implements Drawable {
+            constructor(canvas: HTMLCanvasElement | string, config?: Config);
+            static readonly version: string;
+            private secret;
+            get width(): number;
+            update(mode?: "resize" | "reset"): void;
+            on(event: string, handler: (e: Event, ...args: any[]) => void): this;
+            [key: string]: unknown;
+        }
+
+        export interface Config extends Options<number>, Other {
+            type: ChartType;
+            data?: { labels: string[] };
+            isReady(value: unknown): value is Config;
+        }
+
+        export type ChartType = 'bar' | 'line';
+        export const enum Mode { Fast = "fast", Slow = "slow" }
+        export declare function register(...items: Plugin[]): void;
+        declare const defaults: Config;
+
+        declare module "chart.js/helpers" {
+            export function color(value: string): string;
+        }
+
+        declare global {
+            interface Window { chart?: Chart }
+        }
+
+        declare namespace util {
+            function clamp<T extends number>(x: T, [min, max]: [number, number]): T;
+        }
+        export {};
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(items.len(), 8, "{items:#?}");
+    let Item::Interface(chart) = &items[0] else {
+        panic!("{items:#?}")
+    };
+    assert_eq!(chart.doc, Some(" A chart. "));
+    assert!(chart.is_class);
+    assert_eq!(chart.type_params, ["T"]);
+    assert_eq!(chart.extends.len(), 1);
+    // The private member and the index signature are skipped.
+    assert_eq!(chart.members.len(), 5, "{chart:#?}");
+    assert!(matches!(
+        &chart.members[2],
+        Member::Property(Property {
+            name: "width",
+            get: true,
+            set: false,
+            ..
+        })
+    ));
+
+    let Item::Interface(config) = &items[1] else {
+        panic!("{items:#?}")
+    };
+    assert_eq!(config.extends.len(), 2);
+    assert!(matches!(
+        &config.members[1],
+        Member::Property(Property {
+            optional: true,
+            ty: Type::Object,
+            ..
+        })
+    ));
+
+    assert!(
+        matches!(&items[2], Item::TypeAlias(TypeAlias { name: "ChartType", ty: Type::Union(types), .. }) if types.len() == 2)
+    );
+    assert!(
+        matches!(&items[3], Item::Enum(Enum { members, .. }) if members[1] == ("Slow", Some("slow")))
+    );
+    assert!(
+        matches!(&items[4], Item::Function(Function { signature, .. }) if signature.params[0].rest)
+    );
+    assert!(
+        matches!(&items[5], Item::Module(Module { name: Some("chart.js/helpers"), items }) if items.len() == 1)
+    );
+    assert!(matches!(&items[6], Item::Module(Module { name: None, .. })));
+    assert!(
+        matches!(&items[7], Item::Namespace(Namespace { name: "util", items }) if items.len() == 1)
+    );
+}
+
+#[test]
+fn parse_byte_order_mark() {
+    let items = parse("\u{feff}export declare function f(): void;").unwrap();
+    assert!(
+        matches!(&items[..], [Item::Function(Function { name: "f", .. })]),
+        "{items:#?}"
+    );
+}
+
+#[test]
+fn parse_exponent_numbers() {
+    let items = parse(
+        r#"
+        export type Epsilon = 1e-5 | 2.5E+10 | 0xE;
+        export declare enum Scale { Small = 1e-3, Large = 1e3 }
+        "#,
+    )
+    .unwrap();
+    assert!(
+        matches!(&items[0], Item::TypeAlias(TypeAlias { ty: Type::Union(types), .. })
+            if types.len() == 3 && types.iter().all(|ty| matches!(ty, Type::NumberLiteral))),
+        "{items:#?}"
+    );
+    assert!(
+        matches!(&items[1], Item::Enum(Enum { members, .. })
+            if members[..] == [("Small", None), ("Large", None)]),
+        "{items:#?}"
+    );
+}
+
+#[test]
+fn parse_literal_types() {
+    let items = parse(
+        r#"
+        export type Align = "start" | 'end';
+        export type Event = `on${"click" | `key${string}`}`;
+        export declare function align(value: Align, event: `${number}px`): void;
+        "#,
+    )
+    .unwrap();
+    assert!(
+        matches!(&items[0], Item::TypeAlias(TypeAlias { ty: Type::Union(types), .. })
+            if matches!(types[..], [Type::StringLiteral("start"), Type::StringLiteral("end")])),
+        "{items:#?}"
+    );
+    // Template literal types are strings.
+    assert!(
+        matches!(&items[1], Item::TypeAlias(TypeAlias { ty: Type::Named("string", args), .. })
+            if args.is_empty()),
+        "{items:#?}"
+    );
+    assert!(
+        matches!(&items[2], Item::Function(Function { signature, .. })
+            if matches!(signature.params[1].ty, Type::Named("string", _))),
+        "{items:#?}"
+    );
+}
@@ -29,7 +29,7 @@ use syn::parse_quote;
 /// `operation_name_0`, `operation_name_1`, `operation_name_2`, ..., `operation_name_n` overloads
 /// which have the count of arguments for passing values to the variadic argument
 /// in their names, where `n` is this constant.
-const MAX_VARIADIC_ARGUMENTS_COUNT: usize = 7;
+pub(crate) const MAX_VARIADIC_ARGUMENTS_COUNT: usize = 7;
 
 /// Similar to std::fs::read_dir except it returns a sorted Vec,
 /// which is important to make the code generation deterministic.
@@ -692,7 +692,9 @@ impl<'src> FirstPassRecord<'src> {
                 catch,
                 variadic,
                 unstable: unstable_flag,
+                doc: None,
                 has_unstable_override,
+                typed_promise: false,
             })
         }
 
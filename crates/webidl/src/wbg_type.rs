@@ -421,8 +421,8 @@ impl<'a> ToWbgType<'a> for Identifier<'a> {
         } else if record.enums.contains_key(self.0) {
             IdentifierType::Enum(self.0)
         } else if let Some(callback_data) = record.callbacks.get(self.0) {
-            if record.options.next_unstable.get() {
-                // In next_unstable mode, use typed callback
+            if record.options.next_unstable.get() || record.options.callback_traits {
+                // In next_unstable mode, or with typed callbacks, use typed callback
                 return WbgType::Callback {
                     params: callback_data.params.clone(),
                     return_type: callback_data.return_type.clone().map(Box::new),
@@ -677,6 +677,24 @@ impl<'a> WbgType<'a> {
         }
     }
 
+    /// Returns true if this type is a promise, or a nullable one.
+    pub(crate) fn is_promise(&self) -> bool {
+        match self {
+            WbgType::Promise(_) => true,
+            WbgType::JsOption(wbg_type) => wbg_type.is_promise(),
+            _ => false,
+        }
+    }
+
+    /// Returns true if this type is a typed callback, or a nullable one.
+    pub(crate) fn is_callback(&self) -> bool {
+        match self {
+            WbgType::Callback { .. } => true,
+            WbgType::JsOption(wbg_type) => wbg_type.is_callback(),
+            _ => false,
+        }
+    }
+
     /// Returns true if this type is a primitive that can be used directly in a Rust slice.
     /// These types have efficient `IntoWasmAbi` implementations for `&[T]`.
     pub(crate) fn is_slice_primitive(&self) -> bool {
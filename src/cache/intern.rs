@@ -5,49 +5,251 @@ cfg_if! {
         use std::thread_local;
         use std::string::String;
         use std::borrow::ToOwned;
+        use std::boxed::Box;
         use std::cell::RefCell;
-        use std::collections::HashMap;
+        use std::collections::{BTreeMap, HashMap};
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+        use std::vec::Vec;
         use crate::JsValue;
 
+        /// The number of strings kept in the cache unless changed with
+        /// [`set_intern_capacity`].
+        const DEFAULT_CAPACITY: usize = 1024;
+
+        /// The number of approximate use counters for strings which aren't
+        /// interned, see [`Cache::should_auto_intern`].
+        const COUNTERS: usize = 256;
+
+        /// After this many misses all use counters are halved, so that strings
+        /// which were only used often a long time ago don't get interned.
+        const COUNTER_DECAY: u64 = 4096;
+
+        struct Entry {
+            value: JsValue,
+            /// The value of `Cache::tick` when this entry was last used.
+            last_used: u64,
+        }
+
         struct Cache {
-            entries: RefCell<HashMap<String, JsValue>>,
+            entries: HashMap<String, Entry>,
+            /// The keys of all entries by their `last_used` tick, oldest first.
+            recency: BTreeMap<u64, String>,
+            capacity: usize,
+            auto_intern_threshold: Option<u32>,
+            counters: Box<[u16; COUNTERS]>,
+            /// Set while a string is being interned, as creating its `JsValue`
+            /// passes the string through the cache again.
+            interning: bool,
+            /// The number of imports currently converting their arguments, see
+            /// [`DeferEvictions`].
+            calls: usize,
+            /// Evicted values which can't be dropped until all calls end.
+            deferred: Vec<JsValue>,
+            tick: u64,
+            hits: u64,
+            misses: u64,
+            evictions: u64,
         }
 
         thread_local! {
-            static CACHE: Cache = Cache {
-                entries: RefCell::new(HashMap::new()),
-            };
+            static CACHE: RefCell<Cache> = RefCell::new(Cache {
+                entries: HashMap::new(),
+                recency: BTreeMap::new(),
+                capacity: DEFAULT_CAPACITY,
+                auto_intern_threshold: None,
+                counters: Box::new([0; COUNTERS]),
+                interning: false,
+                calls: 0,
+                deferred: Vec::new(),
+                tick: 0,
+                hits: 0,
+                misses: 0,
+                evictions: 0,
+            });
+        }
+
+        impl Cache {
+            fn touch(&mut self, key: &str) -> Option<u32> {
+                self.tick += 1;
+                let tick = self.tick;
+                let entry = self.entries.get_mut(key)?;
+                let key = self.recency.remove(&entry.last_used).unwrap();
+                self.recency.insert(tick, key);
+                entry.last_used = tick;
+                Some(entry.value.idx)
+            }
+
+            fn insert(&mut self, key: &str, value: JsValue) {
+                self.tick += 1;
+                let last_used = self.tick;
+                self.recency.insert(last_used, key.to_owned());
+                self.entries.insert(key.to_owned(), Entry { value, last_used });
+            }
+
+            fn remove(&mut self, key: &str) -> Option<JsValue> {
+                let entry = self.entries.remove(key)?;
+                self.recency.remove(&entry.last_used);
+                Some(entry.value)
+            }
+
+            /// Counts a use of `key`, which isn't interned, and returns whether
+            /// it has been used often enough to be interned automatically.
+            ///
+            /// Uses are counted per hash bucket rather than per string, so that
+            /// this doesn't need to allocate. Collisions only make strings
+            /// get interned a bit earlier.
+            fn should_auto_intern(&mut self, key: &str) -> bool {
+                let threshold = match self.auto_intern_threshold {
+                    Some(threshold) => threshold,
+                    None => return false,
+                };
+                if self.misses % COUNTER_DECAY == 0 {
+                    for counter in self.counters.iter_mut() {
+                        *counter /= 2;
+                    }
+                }
+                let mut hasher = DefaultHasher::new();
+                key.hash(&mut hasher);
+                let counter = &mut self.counters[hasher.finish() as usize % COUNTERS];
+                *counter = counter.saturating_add(1);
+                if u32::from(*counter) < threshold {
+                    return false;
+                }
+                *counter = 0;
+                true
+            }
+
+            /// Removes the least recently used entries until there are at most
+            /// `capacity` left, returning their values so they can be dropped
+            /// once the cache isn't borrowed anymore.
+            ///
+            /// While an import converts its arguments, the raw index of an
+            /// evicted entry may already have been returned for an earlier
+            /// argument, so its value is kept alive until the call ends.
+            fn evict(&mut self, capacity: usize) -> Vec<JsValue> {
+                let mut evicted = Vec::new();
+                while self.entries.len() > capacity {
+                    let (_, oldest) = self.recency.pop_first().unwrap();
+                    evicted.push(self.entries.remove(&oldest).unwrap().value);
+                    self.evictions += 1;
+                }
+                if self.calls > 0 {
+                    self.deferred.append(&mut evicted);
+                }
+                evicted
+            }
+        }
+
+        enum Lookup {
+            Hit(u32),
+            Miss,
+            AutoIntern,
         }
 
         /// This returns the raw index of the cached JsValue, so you must take care
         /// so that you don't use it after it is freed.
         pub(crate) fn unsafe_get_str(s: &str) -> Option<u32> {
-            CACHE.with(|cache| {
-                let cache = cache.entries.borrow();
+            let lookup = CACHE.with(|cache| {
+                let mut cache = cache.borrow_mut();
+                if cache.interning {
+                    return Lookup::Miss;
+                }
+                if let Some(idx) = cache.touch(s) {
+                    cache.hits += 1;
+                    return Lookup::Hit(idx);
+                }
+                cache.misses += 1;
+                if cache.should_auto_intern(s) {
+                    Lookup::AutoIntern
+                } else {
+                    Lookup::Miss
+                }
+            });
 
-                cache.get(s).map(|x| x.idx)
-            })
+            match lookup {
+                Lookup::Hit(idx) => Some(idx),
+                Lookup::Miss => None,
+                Lookup::AutoIntern => {
+                    intern_str(s);
+                    CACHE.with(|cache| cache.borrow_mut().touch(s))
+                }
+            }
         }
 
         fn intern_str(key: &str) {
-            CACHE.with(|cache| {
-                let entries = &cache.entries;
-
-                // Can't use `entry` because `entry` requires a `String`
-                if !entries.borrow().contains_key(key) {
-                    // Note: we must not hold the borrow while we create the `JsValue`,
-                    // because it will try to look up the value in the cache first.
-                    let value = JsValue::from(key);
-                    entries.borrow_mut().insert(key.to_owned(), value);
+            let insert = CACHE.with(|cache| {
+                let mut cache = cache.borrow_mut();
+                if cache.capacity == 0 || cache.touch(key).is_some() {
+                    return false;
                 }
-            })
+                cache.interning = true;
+                true
+            });
+            if !insert {
+                return;
+            }
+
+            // Note: we must not hold the borrow while we create the `JsValue`,
+            // because it will try to look up the value in the cache first.
+            let value = JsValue::from(key);
+
+            let evicted = CACHE.with(|cache| {
+                let mut cache = cache.borrow_mut();
+                cache.interning = false;
+                let capacity = cache.capacity;
+                let evicted = cache.evict(capacity - 1);
+                cache.insert(key, value);
+                evicted
+            });
+            drop(evicted);
         }
 
         fn unintern_str(key: &str) {
-            CACHE.with(|cache| {
-                let mut cache = cache.entries.borrow_mut();
+            let value = CACHE.with(|cache| cache.borrow_mut().remove(key));
+            drop(value);
+        }
+
+        fn set_capacity(capacity: usize) {
+            let evicted = CACHE.with(|cache| {
+                let mut cache = cache.borrow_mut();
+                cache.capacity = capacity;
+                cache.evict(capacity)
+            });
+            drop(evicted);
+        }
+
+        fn enter_call() {
+            CACHE.with(|cache| cache.borrow_mut().calls += 1);
+        }
+
+        fn exit_call() {
+            let deferred = CACHE.with(|cache| {
+                let mut cache = cache.borrow_mut();
+                cache.calls -= 1;
+                if cache.calls == 0 {
+                    core::mem::take(&mut cache.deferred)
+                } else {
+                    Vec::new()
+                }
+            });
+            drop(deferred);
+        }
 
-                cache.remove(key);
+        fn set_threshold(threshold: Option<u32>) {
+            CACHE.with(|cache| cache.borrow_mut().auto_intern_threshold = threshold);
+        }
+
+        fn stats() -> InternStats {
+            CACHE.with(|cache| {
+                let cache = cache.borrow();
+                InternStats {
+                    hits: cache.hits,
+                    misses: cache.misses,
+                    evictions: cache.evictions,
+                    size: cache.entries.len(),
+                    capacity: cache.capacity,
+                }
             })
         }
     }
@@ -79,6 +281,9 @@ cfg_if! {
 /// string. Only cache strings which have a high likelihood of being sent
 /// to JS multiple times.
 ///
+/// The cache holds a limited number of strings, see [`set_intern_capacity`].
+/// Once it is full, the least recently sent string is evicted to make room.
+///
 /// Also, keep in mind that this function is a *performance hint*: it's not
 /// *guaranteed* that the string will be cached, and the caching strategy
 /// might change at any time, so don't rely upon it.
@@ -101,3 +306,92 @@ pub fn unintern(s: &str) {
     #[cfg(feature = "enable-interning")]
     unintern_str(s);
 }
+
+/// Sets the maximum number of strings kept in the intern cache of the current
+/// thread, which defaults to 1024.
+///
+/// Every interned string keeps a JS string alive. When the cache is full, the
+/// least recently used string is evicted to make room for a new one. Lowering
+/// the capacity evicts strings immediately, and a capacity of `0` disables
+/// interning.
+///
+/// This does nothing unless the `"enable-interning"` feature is enabled.
+#[allow(unused_variables)]
+#[inline]
+pub fn set_intern_capacity(capacity: usize) {
+    #[cfg(feature = "enable-interning")]
+    set_capacity(capacity);
+}
+
+/// Enables interning strings automatically once they have been sent to JS
+/// `threshold` times, or disables it with `None`, which is the default.
+///
+/// Uses of strings which aren't interned are counted approximately, so this
+/// doesn't allocate, but hashes every string sent to JS that isn't interned
+/// yet. Strings interned this way are subject to the same capacity as those
+/// passed to [`intern`].
+///
+/// This does nothing unless the `"enable-interning"` feature is enabled.
+#[allow(unused_variables)]
+#[inline]
+pub fn set_auto_intern_threshold(threshold: Option<u32>) {
+    #[cfg(feature = "enable-interning")]
+    set_threshold(threshold);
+}
+
+/// Keeps strings evicted from the intern cache alive while an import converts
+/// its arguments and calls into JS.
+///
+/// Each `&str` argument found in the cache is passed as the raw index of its
+/// `JsValue`, which JS only reads once all arguments are converted. Converting
+/// a later argument can intern it automatically and evict an earlier one, so
+/// evicted values are only dropped once the outermost call has returned.
+#[doc(hidden)]
+pub struct DeferEvictions(());
+
+impl DeferEvictions {
+    #[allow(clippy::new_without_default)]
+    #[inline]
+    pub fn new() -> DeferEvictions {
+        #[cfg(feature = "enable-interning")]
+        enter_call();
+        DeferEvictions(())
+    }
+}
+
+#[cfg(feature = "enable-interning")]
+impl Drop for DeferEvictions {
+    #[inline]
+    fn drop(&mut self) {
+        exit_call();
+    }
+}
+
+/// Statistics of the intern cache, returned by [`intern_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct InternStats {
+    /// The number of strings sent to JS which were found in the cache.
+    pub hits: u64,
+    /// The number of strings sent to JS which weren't found in the cache.
+    pub misses: u64,
+    /// The number of strings evicted because the cache was full.
+    pub evictions: u64,
+    /// The number of strings currently in the cache.
+    pub size: usize,
+    /// The maximum number of strings in the cache.
+    pub capacity: usize,
+}
+
+/// Returns statistics of the intern cache of the current thread, which can be
+/// used to tune [`set_intern_capacity`] and [`set_auto_intern_threshold`].
+///
+/// All statistics are zero unless the `"enable-interning"` feature is enabled.
+#[inline]
+pub fn intern_stats() -> InternStats {
+    #[cfg(feature = "enable-interning")]
+    return stats();
+
+    #[cfg(not(feature = "enable-interning"))]
+    InternStats::default()
+}
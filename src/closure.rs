@@ -64,6 +64,7 @@ use crate::JsValue;
 use crate::{convert::*, JsCast};
 use core::marker::PhantomData;
 use core::panic::AssertUnwindSafe;
+use core::sync::atomic::{AtomicU32, Ordering};
 
 #[wasm_bindgen_macro::wasm_bindgen(wasm_bindgen = crate)]
 extern "C" {
@@ -237,6 +238,7 @@ where
     ///
     /// **Note: Not unwind safe. Prefer [`own`](Self::own) or `own` with
     /// [`AssertUnwindSafe`](core::panic::AssertUnwindSafe) when possible.**
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn new<F>(t: F) -> Self
     where
         F: IntoWasmClosure<T> + 'static,
@@ -255,6 +257,7 @@ where
     ///
     /// See [`borrow`](Self::borrow) for creating a borrowed `ScopedClosure` with
     /// an associated lifetime (defaults to immutable `Fn`).
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn own<F>(t: F) -> Self
     where
         F: IntoWasmClosure<T> + MaybeUnwindSafe + 'static,
@@ -276,6 +279,7 @@ where
     ///
     /// **Note: Not unwind safe. Prefer [`own`](Self::own) or `own` with
     /// [`AssertUnwindSafe`](core::panic::AssertUnwindSafe) when possible.**
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn own_aborting<F>(t: F) -> Self
     where
         F: IntoWasmClosure<T> + 'static,
@@ -290,6 +294,7 @@ where
     ///
     /// **Note: Not unwind safe. Prefer [`wrap_assert_unwind_safe`](Self::wrap_assert_unwind_safe)
     /// when possible.**
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn wrap<F>(data: Box<F>) -> Self
     where
         F: IntoWasmClosure<T> + ?Sized,
@@ -304,6 +309,7 @@ where
     /// `AssertUnwindSafe(...)`, this must be verified explicitly.**
     ///
     /// This version catches panics when unwinding is available.
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn wrap_assert_unwind_safe<F>(data: Box<F>) -> Self
     where
         F: IntoWasmClosure<T> + ?Sized,
@@ -312,21 +318,51 @@ where
     }
 
     #[cfg(all(feature = "std", target_arch = "wasm32", panic = "unwind"))]
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     fn _wrap(data: Box<T>, unwind_safe: bool) -> Self {
-        Self {
+        LIVE_CLOSURES.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "closure-diagnostics")]
+        let key = diagnostics::key(&*data);
+        let closure = Self {
             js: crate::__rt::wbg_cast(OwnedClosureUnwind { data, unwind_safe }),
             _marker: PhantomData,
             _lifetime: PhantomData,
-        }
+        };
+        #[cfg(feature = "closure-diagnostics")]
+        diagnostics::created(Some(key), &closure.js);
+        closure
     }
 
     #[cfg(not(all(feature = "std", target_arch = "wasm32", panic = "unwind")))]
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     fn _wrap(data: Box<T>, _unwind_safe: bool) -> Self {
-        Self {
+        LIVE_CLOSURES.fetch_add(1, Ordering::Relaxed);
+        #[cfg(feature = "closure-diagnostics")]
+        let key = diagnostics::key(&*data);
+        let closure = Self {
             js: crate::__rt::wbg_cast(OwnedClosure(data)),
             _marker: PhantomData,
             _lifetime: PhantomData,
-        }
+        };
+        #[cfg(feature = "closure-diagnostics")]
+        diagnostics::created(Some(key), &closure.js);
+        closure
+    }
+
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
+    fn _borrow<'a, S: ?Sized>(ptr: u32, len: u32, unwind_safe: bool) -> ScopedClosure<'a, S> {
+        let closure = ScopedClosure {
+            js: crate::__rt::wbg_cast(BorrowedClosure::<T> {
+                data: WasmSlice { ptr, len },
+                unwind_safe,
+                _marker: PhantomData,
+            }),
+            _marker: PhantomData,
+            _lifetime: PhantomData,
+        };
+        #[cfg(feature = "closure-diagnostics")]
+        diagnostics::created(None, &closure.js);
+        closure
     }
 
     /// Creates a scoped closure by borrowing an immutable `Fn` closure with
@@ -382,42 +418,28 @@ where
     /// // Can also upcast to FnMut
     /// call_fnmut(closure.upcast_ref(), 42);
     /// ```
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn borrow<'a, F>(t: &'a F) -> ScopedClosure<'a, F::Static>
     where
         F: IntoWasmClosureRef<'a, T> + MaybeUnwindSafe + ?Sized,
     {
         let t: &T = t.unsize_closure_ref();
         let (ptr, len): (u32, u32) = unsafe { mem::transmute_copy(&t) };
-        ScopedClosure {
-            js: crate::__rt::wbg_cast(BorrowedClosure::<T> {
-                data: WasmSlice { ptr, len },
-                unwind_safe: true,
-                _marker: PhantomData,
-            }),
-            _marker: PhantomData,
-            _lifetime: PhantomData,
-        }
+        Self::_borrow(ptr, len, true)
     }
 
     /// Like [`borrow`](Self::borrow), but catches panics without requiring `MaybeUnwindSafe`.
     ///
     /// **Safety: Unwind safety is assumed when using this function, like using
     /// `AssertUnwindSafe(...)`, this must be verified explicitly.**
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn borrow_assert_unwind_safe<'a, F>(t: &'a F) -> ScopedClosure<'a, F::Static>
     where
         F: IntoWasmClosureRef<'a, T> + ?Sized,
     {
         let t: &T = t.unsize_closure_ref();
         let (ptr, len): (u32, u32) = unsafe { mem::transmute_copy(&t) };
-        ScopedClosure {
-            js: crate::__rt::wbg_cast(BorrowedClosure::<T> {
-                data: WasmSlice { ptr, len },
-                unwind_safe: true,
-                _marker: PhantomData,
-            }),
-            _marker: PhantomData,
-            _lifetime: PhantomData,
-        }
+        Self::_borrow(ptr, len, true)
     }
 
     /// Like [`borrow`](Self::borrow), but does not catch panics.
@@ -427,21 +449,14 @@ where
     ///
     /// **Note: Not unwind safe. Prefer [`borrow`](Self::borrow) or
     /// [`borrow_assert_unwind_safe`](Self::borrow_assert_unwind_safe) when possible.**
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn borrow_aborting<'a, F>(t: &'a F) -> ScopedClosure<'a, F::Static>
     where
         F: IntoWasmClosureRef<'a, T> + ?Sized,
     {
         let t: &T = t.unsize_closure_ref();
         let (ptr, len): (u32, u32) = unsafe { mem::transmute_copy(&t) };
-        ScopedClosure {
-            js: crate::__rt::wbg_cast(BorrowedClosure::<T> {
-                data: WasmSlice { ptr, len },
-                unwind_safe: false,
-                _marker: PhantomData,
-            }),
-            _marker: PhantomData,
-            _lifetime: PhantomData,
-        }
+        Self::_borrow(ptr, len, false)
     }
 
     /// Creates a scoped closure by mutably borrowing a `FnMut` closure.
@@ -470,42 +485,28 @@ where
     /// // closure dropped, `sum` is accessible again
     /// assert_eq!(sum, 6); // 1 + 2 + 3
     /// ```
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn borrow_mut<'a, F>(t: &'a mut F) -> ScopedClosure<'a, F::Static>
     where
         F: IntoWasmClosureRefMut<'a, T> + MaybeUnwindSafe + ?Sized,
     {
         let t: &mut T = t.unsize_closure_ref();
         let (ptr, len): (u32, u32) = unsafe { mem::transmute_copy(&t) };
-        ScopedClosure {
-            js: crate::__rt::wbg_cast(BorrowedClosure::<T> {
-                data: WasmSlice { ptr, len },
-                unwind_safe: true,
-                _marker: PhantomData,
-            }),
-            _marker: PhantomData,
-            _lifetime: PhantomData,
-        }
+        Self::_borrow(ptr, len, true)
     }
 
     /// Like [`borrow_mut`](Self::borrow_mut), but catches panics without requiring `MaybeUnwindSafe`.
     ///
     /// **Safety: Unwind safety is assumed when using this function, like using
     /// `AssertUnwindSafe(...)`, this must be verified explicitly.**
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn borrow_mut_assert_unwind_safe<'a, F>(t: &'a mut F) -> ScopedClosure<'a, F::Static>
     where
         F: IntoWasmClosureRefMut<'a, T> + ?Sized,
     {
         let t: &mut T = t.unsize_closure_ref();
         let (ptr, len): (u32, u32) = unsafe { mem::transmute_copy(&t) };
-        ScopedClosure {
-            js: crate::__rt::wbg_cast(BorrowedClosure::<T> {
-                data: WasmSlice { ptr, len },
-                unwind_safe: true,
-                _marker: PhantomData,
-            }),
-            _marker: PhantomData,
-            _lifetime: PhantomData,
-        }
+        Self::_borrow(ptr, len, true)
     }
 
     /// Like [`borrow_mut`](Self::borrow_mut), but does not catch panics.
@@ -515,21 +516,14 @@ where
     ///
     /// **Note: Not unwind safe. Prefer [`borrow_mut`](Self::borrow_mut) or
     /// [`borrow_mut_assert_unwind_safe`](Self::borrow_mut_assert_unwind_safe) when possible.**
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn borrow_mut_aborting<'a, F>(t: &'a mut F) -> ScopedClosure<'a, F::Static>
     where
         F: IntoWasmClosureRefMut<'a, T> + ?Sized,
     {
         let t: &mut T = t.unsize_closure_ref();
         let (ptr, len): (u32, u32) = unsafe { mem::transmute_copy(&t) };
-        ScopedClosure {
-            js: crate::__rt::wbg_cast(BorrowedClosure::<T> {
-                data: WasmSlice { ptr, len },
-                unwind_safe: false,
-                _marker: PhantomData,
-            }),
-            _marker: PhantomData,
-            _lifetime: PhantomData,
-        }
+        Self::_borrow(ptr, len, false)
     }
 }
 
@@ -606,6 +600,7 @@ where
     ///
     /// Note: the `A` and `R` type parameters are here just for backward compat
     /// and will be removed in the future.
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn once<F, A, R>(fn_once: F) -> Self
     where
         F: WasmClosureFnOnce<T, A, R> + MaybeUnwindSafe,
@@ -633,6 +628,7 @@ where
     ///
     /// Note: the `A` and `R` type parameters are here just for backward compat
     /// and will be removed in the future.
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn once_wrap<F, A, R>(fn_once: F) -> Self
     where
         F: WasmClosureFnOnceAbort<T, A, R>,
@@ -663,6 +659,7 @@ where
     ///
     /// Note: the `A` and `R` type parameters are here just for backward compat
     /// and will be removed in the future.
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn once_into_js<F, A, R>(fn_once: F) -> JsValue
     where
         F: WasmClosureFnOnce<T, A, R> + MaybeUnwindSafe,
@@ -689,6 +686,7 @@ where
     ///
     /// Note: the `A` and `R` type parameters are here just for backward compat
     /// and will be removed in the future.
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     pub fn once_into_js_wrap<F, A, R>(fn_once: F) -> JsValue
     where
         F: WasmClosureFnOnceAbort<T, A, R>,
@@ -924,6 +922,7 @@ impl<T: ?Sized> fmt::Debug for ImmediateClosure<'_, T> {
 pub trait WasmClosureFnOnce<FnMut: ?Sized, A, R>: 'static {
     fn into_fn_mut(self) -> Box<FnMut>;
 
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     fn into_js_function(self) -> JsValue;
 }
 
@@ -933,6 +932,7 @@ pub trait WasmClosureFnOnce<FnMut: ?Sized, A, R>: 'static {
 pub trait WasmClosureFnOnceAbort<FnMut: ?Sized, A, R>: 'static {
     fn into_fn_mut(self) -> Box<FnMut>;
 
+    #[cfg_attr(feature = "closure-diagnostics", track_caller)]
     fn into_js_function(self) -> JsValue;
 }
 
@@ -961,6 +961,106 @@ struct BorrowedClosure<T: ?Sized> {
     _marker: PhantomData<T>,
 }
 
+/// An owned closure whose Rust data has not been freed yet, as returned by
+/// [`crate::live_closures`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LiveClosure {
+    /// Where the closure was created.
+    pub location: &'static core::panic::Location<'static>,
+}
+
+impl fmt::Display for LiveClosure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "closure created at {}", self.location)
+    }
+}
+
+/// Bookkeeping of the `closure-diagnostics` feature.
+#[cfg(feature = "closure-diagnostics")]
+mod diagnostics {
+    use super::LiveClosure;
+    use crate::JsValue;
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+    use core::cell::RefCell;
+    use core::mem;
+    use core::panic::Location;
+    use std::collections::BTreeMap;
+    use std::thread_local;
+
+    /// The data and vtable pointers of an owned closure, which are also what
+    /// its destructor receives.
+    pub(super) type Key = (usize, usize);
+
+    thread_local! {
+        /// Creation sites of live owned closures. Closures without captures
+        /// all share a data pointer, so there can be several per key.
+        static LIVE: RefCell<BTreeMap<Key, Vec<&'static Location<'static>>>> =
+            const { RefCell::new(BTreeMap::new()) };
+    }
+
+    pub(super) fn key<T: ?Sized>(data: &T) -> Key {
+        let ptr: *const T = data;
+        // SAFETY: closures are always trait objects, i.e. two pointers.
+        unsafe { mem::transmute_copy(&ptr) }
+    }
+
+    /// Records a new closure created by our caller, and tags its JS function
+    /// with the location so the `--debug` glue can report it.
+    #[track_caller]
+    pub(super) fn created(key: Option<Key>, js: &JsValue) {
+        let location = Location::caller();
+        if let Some(key) = key {
+            LIVE.with(|live| live.borrow_mut().entry(key).or_default().push(location));
+        }
+        crate::__wbindgen_reflect_set(
+            js,
+            &JsValue::from_str("_wbg_origin"),
+            &JsValue::from_str(&location.to_string()),
+        );
+    }
+
+    pub(super) fn destroyed(key: Key) {
+        LIVE.with(|live| {
+            let mut live = live.borrow_mut();
+            if let Some(locations) = live.get_mut(&key) {
+                locations.pop();
+                if locations.is_empty() {
+                    live.remove(&key);
+                }
+            }
+        });
+    }
+
+    pub(super) fn live() -> Vec<LiveClosure> {
+        LIVE.with(|live| {
+            live.borrow()
+                .values()
+                .flatten()
+                .map(|&location| LiveClosure { location })
+                .collect()
+        })
+    }
+}
+
+/// Implementation of [`crate::live_closures`].
+pub(crate) fn live() -> alloc::vec::Vec<LiveClosure> {
+    #[cfg(feature = "closure-diagnostics")]
+    return diagnostics::live();
+
+    #[cfg(not(feature = "closure-diagnostics"))]
+    alloc::vec::Vec::new()
+}
+
+/// Number of owned closures whose Rust data has not been freed yet.
+static LIVE_CLOSURES: AtomicU32 = AtomicU32::new(0);
+
+/// Implementation of [`crate::closure_live_count`].
+pub(crate) fn live_count() -> u32 {
+    LIVE_CLOSURES.load(Ordering::Relaxed)
+}
+
 unsafe extern "C" fn destroy<T: ?Sized>(a: usize, mut b: usize) {
     if a == 0 {
         return;
@@ -968,6 +1068,9 @@ unsafe extern "C" fn destroy<T: ?Sized>(a: usize, mut b: usize) {
     // Mask out unwind_safe flag
     b &= !0x80000000;
     drop(mem::transmute_copy::<_, Box<T>>(&(a, b)));
+    LIVE_CLOSURES.fetch_sub(1, Ordering::Relaxed);
+    #[cfg(feature = "closure-diagnostics")]
+    diagnostics::destroyed((a, b));
 }
 
 impl<T> WasmDescribe for OwnedClosure<T>
@@ -239,6 +239,7 @@ macro_rules! closures {
                 })
             }
 
+            #[cfg_attr(feature = "closure-diagnostics", track_caller)]
             fn into_js_function(self) -> JsValue {
                 use alloc::rc::Rc;
                 use crate::__rt::WasmRefCell;
@@ -284,6 +285,7 @@ macro_rules! closures {
                 })
             }
 
+            #[cfg_attr(feature = "closure-diagnostics", track_caller)]
             fn into_js_function(self) -> JsValue {
                 use alloc::rc::Rc;
                 use crate::__rt::WasmRefCell;
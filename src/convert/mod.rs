@@ -7,9 +7,11 @@
 
 mod closures;
 mod impls;
+mod object;
 mod slices;
 mod traits;
 
 pub use self::impls::*;
+pub use self::object::*;
 pub use self::slices::WasmSlice;
 pub use self::traits::*;
@@ -0,0 +1,260 @@
+//! Field-by-field conversions used by `#[derive(IntoJsValue, TryFromJsValue)]`.
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::convert::TryFromJsValue;
+use crate::{JsCast, JsError, JsValue};
+
+/// Conversion of a Rust value into a plain JS value, as done for the fields
+/// of types deriving `IntoJsValue`.
+pub trait IntoJsField {
+    /// Converts `self` into a JS value.
+    fn into_js_field(self) -> JsValue;
+}
+
+/// Conversion of a plain JS value into a Rust value, as done for the fields
+/// of types deriving `TryFromJsValue`.
+pub trait TryFromJsField: Sized {
+    /// Converts `value`, describing where and why it failed on error.
+    fn try_from_js_field(value: &JsValue) -> Result<Self, JsFieldError>;
+}
+
+/// The error returned when a JS value doesn't have the shape of a type
+/// deriving `TryFromJsValue`.
+///
+/// It displays the path to the offending value followed by what was
+/// expected there, e.g. `items[3].name: expected string`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsFieldError {
+    /// Path segments from the innermost outwards.
+    path: Vec<PathSegment>,
+    expected: &'static str,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    Field(&'static str),
+    Index(usize),
+}
+
+impl JsFieldError {
+    /// Creates an error for a value which isn't what was `expected`.
+    pub fn expected(expected: &'static str) -> Self {
+        JsFieldError {
+            path: Vec::new(),
+            expected,
+        }
+    }
+
+    /// Prefixes the path of the error with the field `name`.
+    pub fn field(mut self, name: &'static str) -> Self {
+        self.path.push(PathSegment::Field(name));
+        self
+    }
+
+    /// Prefixes the path of the error with the array `index`.
+    pub fn index(mut self, index: usize) -> Self {
+        self.path.push(PathSegment::Index(index));
+        self
+    }
+}
+
+impl fmt::Display for JsFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.path.iter().rev().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => f.write_str(name)?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        if !self.path.is_empty() {
+            f.write_str(": ")?;
+        }
+        write!(f, "expected {}", self.expected)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for JsFieldError {}
+
+impl From<JsFieldError> for JsValue {
+    fn from(err: JsFieldError) -> JsValue {
+        JsError::new(&err.to_string()).into()
+    }
+}
+
+macro_rules! primitives {
+    ($($expected:literal => $($t:ty)*;)*) => ($($(
+        impl IntoJsField for $t {
+            #[inline]
+            fn into_js_field(self) -> JsValue {
+                self.into()
+            }
+        }
+
+        impl TryFromJsField for $t {
+            #[inline]
+            fn try_from_js_field(value: &JsValue) -> Result<Self, JsFieldError> {
+                <$t>::try_from_js_value_ref(value).ok_or(JsFieldError::expected($expected))
+            }
+        }
+    )*)*)
+}
+
+primitives! {
+    "number" => i8 u8 i16 u16 i32 u32 isize usize f32 f64;
+    "bigint" => i64 u64 i128 u128;
+    "boolean" => bool;
+    "string" => String;
+}
+
+impl IntoJsField for char {
+    #[inline]
+    fn into_js_field(self) -> JsValue {
+        JsValue::from_str(self.encode_utf8(&mut [0; 4]))
+    }
+}
+
+impl TryFromJsField for char {
+    #[inline]
+    fn try_from_js_field(value: &JsValue) -> Result<Self, JsFieldError> {
+        char::try_from_js_value_ref(value).ok_or(JsFieldError::expected("single character string"))
+    }
+}
+
+impl IntoJsField for () {
+    #[inline]
+    fn into_js_field(self) -> JsValue {
+        JsValue::UNDEFINED
+    }
+}
+
+impl TryFromJsField for () {
+    #[inline]
+    fn try_from_js_field(value: &JsValue) -> Result<Self, JsFieldError> {
+        <()>::try_from_js_value_ref(value).ok_or(JsFieldError::expected("undefined"))
+    }
+}
+
+impl IntoJsField for &str {
+    #[inline]
+    fn into_js_field(self) -> JsValue {
+        JsValue::from_str(self)
+    }
+}
+
+/// `None` is converted to `undefined`, and both `undefined` and `null` are
+/// converted to `None`.
+impl<T: IntoJsField> IntoJsField for Option<T> {
+    #[inline]
+    fn into_js_field(self) -> JsValue {
+        match self {
+            Some(value) => value.into_js_field(),
+            None => JsValue::UNDEFINED,
+        }
+    }
+}
+
+impl<T: TryFromJsField> TryFromJsField for Option<T> {
+    #[inline]
+    fn try_from_js_field(value: &JsValue) -> Result<Self, JsFieldError> {
+        if value.is_null_or_undefined() {
+            Ok(None)
+        } else {
+            T::try_from_js_field(value).map(Some)
+        }
+    }
+}
+
+/// Vectors are converted to and from JS arrays.
+impl<T: IntoJsField> IntoJsField for Vec<T> {
+    fn into_js_field(self) -> JsValue {
+        let array = __new_array();
+        for (i, value) in self.into_iter().enumerate() {
+            __set_index(&array, i, value.into_js_field());
+        }
+        array
+    }
+}
+
+impl<T: TryFromJsField> TryFromJsField for Vec<T> {
+    fn try_from_js_field(value: &JsValue) -> Result<Self, JsFieldError> {
+        if !value.is_array() {
+            return Err(JsFieldError::expected("array"));
+        }
+        let len = __get(value, "length").as_f64().unwrap_or(0.) as usize;
+        (0..len)
+            .map(|i| T::try_from_js_field(&__get_index(value, i)).map_err(|err| err.index(i)))
+            .collect()
+    }
+}
+
+/// Imported JS types are passed through as is.
+impl<T: JsCast> IntoJsField for T {
+    #[inline]
+    fn into_js_field(self) -> JsValue {
+        self.into()
+    }
+}
+
+impl<T: JsCast> TryFromJsField for T {
+    #[inline]
+    fn try_from_js_field(value: &JsValue) -> Result<Self, JsFieldError> {
+        if T::is_type_of(value) {
+            Ok(T::unchecked_from_js(value.clone()))
+        } else {
+            let name = core::any::type_name::<T>();
+            let name = name.split('<').next().unwrap_or(name);
+            let name = name.rsplit("::").next().unwrap_or(name);
+            Err(JsFieldError::expected(name))
+        }
+    }
+}
+
+#[doc(hidden)]
+pub fn __new_object() -> JsValue {
+    crate::__wbindgen_object_new()
+}
+
+#[doc(hidden)]
+pub fn __new_array() -> JsValue {
+    crate::__wbindgen_array_new()
+}
+
+#[doc(hidden)]
+pub fn __get(target: &JsValue, key: &str) -> JsValue {
+    crate::__wbindgen_reflect_get(target, &JsValue::from_str(key))
+}
+
+#[doc(hidden)]
+pub fn __set(target: &JsValue, key: &str, value: JsValue) {
+    crate::__wbindgen_reflect_set(target, &JsValue::from_str(key), &value);
+}
+
+#[doc(hidden)]
+pub fn __has(target: &JsValue, key: &str) -> bool {
+    JsValue::from_str(key).js_in(target)
+}
+
+#[doc(hidden)]
+pub fn __get_index(target: &JsValue, index: usize) -> JsValue {
+    crate::__wbindgen_reflect_get(target, &JsValue::from(index))
+}
+
+#[doc(hidden)]
+pub fn __set_index(target: &JsValue, index: usize, value: JsValue) {
+    crate::__wbindgen_reflect_set(target, &JsValue::from(index), &value);
+}
+
+/// Fails unless `value` is an object whose fields can be read.
+#[doc(hidden)]
+pub fn __expect_object(value: &JsValue, expected: &'static str) -> Result<(), JsFieldError> {
+    if value.is_object() {
+        Ok(())
+    } else {
+        Err(JsFieldError::expected(expected))
+    }
+}
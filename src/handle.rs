@@ -0,0 +1,241 @@
+//! Strong and weak handles to exported structs, shared between Rust and JS.
+
+use alloc::rc::{Rc, Weak};
+use core::fmt;
+
+use crate::__rt::WasmRefCell;
+use crate::closure::Closure;
+use crate::convert::{
+    FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi, RefMutFromWasmAbi,
+};
+use crate::describe::WasmDescribe;
+use crate::JsValue;
+
+/// Structs exported with `#[wasm_bindgen]`, which JS holds through an
+/// `Rc<WasmRefCell<Self>>`.
+///
+/// This is implemented by `#[wasm_bindgen]` and can't be implemented by hand.
+///
+/// # Safety
+///
+/// The ABI of `Self` must be a pointer to an `Rc<WasmRefCell<Self>>`.
+pub unsafe trait ExportedStruct: WasmDescribe + Sized + 'static {
+    #[doc(hidden)]
+    fn describe_weak_handle();
+}
+
+/// A strong reference to an exported struct which is shared between Rust and
+/// JS.
+///
+/// Returning an exported struct by value to JS moves it into a new instance of
+/// its class, so Rust can't hold on to it. `StrongHandle` instead keeps it in
+/// the same `Rc` that class instances use: converting a `StrongHandle` to JS
+/// creates an instance which shares the value, and a `StrongHandle` taken from
+/// JS takes over the instance's reference, like taking the struct by value
+/// would.
+///
+/// The value is dropped once all handles and instances sharing it are gone.
+/// [`downgrade`](StrongHandle::downgrade) creates a [`WeakHandle`] which
+/// doesn't keep it alive.
+pub struct StrongHandle<T: ExportedStruct> {
+    rc: Rc<WasmRefCell<T>>,
+}
+
+impl<T: ExportedStruct> StrongHandle<T> {
+    /// Moves `value` into a new handle.
+    pub fn new(value: T) -> StrongHandle<T> {
+        StrongHandle {
+            rc: Rc::new(WasmRefCell::new(value)),
+        }
+    }
+
+    /// Creates a weak handle to the same value.
+    pub fn downgrade(&self) -> WeakHandle<T> {
+        WeakHandle {
+            weak: Rc::downgrade(&self.rc),
+        }
+    }
+
+    /// Calls `f` with a shared borrow of the value.
+    ///
+    /// This throws an error if a method of one of the value's instances is
+    /// borrowing it mutably, like calling a `&self` method would.
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        f(&self.rc.borrow())
+    }
+
+    /// Calls `f` with a mutable borrow of the value.
+    ///
+    /// This throws an error if a method of one of the value's instances is
+    /// borrowing it, like calling a `&mut self` method would. `reentrant`
+    /// structs can't be borrowed mutably.
+    pub fn with_mut<R>(&self, f: impl FnOnce(&mut T) -> R) -> R
+    where
+        T: RefMutFromWasmAbi,
+    {
+        f(&mut self.rc.borrow_mut())
+    }
+}
+
+impl<T: ExportedStruct> Clone for StrongHandle<T> {
+    fn clone(&self) -> StrongHandle<T> {
+        StrongHandle {
+            rc: self.rc.clone(),
+        }
+    }
+}
+
+impl<T: ExportedStruct> fmt::Debug for StrongHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StrongHandle").finish_non_exhaustive()
+    }
+}
+
+impl<T: ExportedStruct> From<StrongHandle<T>> for JsValue {
+    fn from(handle: StrongHandle<T>) -> JsValue {
+        crate::__rt::wbg_cast(handle)
+    }
+}
+
+impl<T: ExportedStruct> WasmDescribe for StrongHandle<T> {
+    fn describe() {
+        T::describe()
+    }
+}
+
+impl<T: ExportedStruct> IntoWasmAbi for StrongHandle<T> {
+    type Abi = u32;
+
+    #[inline]
+    fn into_abi(self) -> u32 {
+        Rc::into_raw(self.rc) as u32
+    }
+}
+
+impl<T: ExportedStruct> FromWasmAbi for StrongHandle<T> {
+    type Abi = u32;
+
+    #[inline]
+    unsafe fn from_abi(js: u32) -> StrongHandle<T> {
+        let ptr = js as *mut WasmRefCell<T>;
+        crate::__rt::assert_not_null(ptr);
+        StrongHandle {
+            rc: Rc::from_raw(ptr),
+        }
+    }
+}
+
+impl<T: ExportedStruct> OptionIntoWasmAbi for StrongHandle<T> {
+    #[inline]
+    fn none() -> u32 {
+        0
+    }
+}
+
+impl<T: ExportedStruct> OptionFromWasmAbi for StrongHandle<T> {
+    #[inline]
+    fn is_none(js: &u32) -> bool {
+        *js == 0
+    }
+}
+
+/// A weak reference to an exported struct, which doesn't keep it alive.
+///
+/// This lets Rust hand out references to objects it owns through
+/// [`StrongHandle`]s without JS being able to keep them alive. In JS a
+/// `WeakHandle` is an object whose `deref()` method returns a new instance of
+/// the struct's class sharing the value, or `undefined` if the value was
+/// dropped, typed in TypeScript as `{ deref(): Class | undefined }`.
+///
+/// An instance returned by `deref()` keeps the value alive until it's freed,
+/// like any other instance.
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::prelude::*;
+/// use wasm_bindgen::{StrongHandle, WeakHandle};
+///
+/// #[wasm_bindgen]
+/// pub struct Item {
+///     pub id: u32,
+/// }
+///
+/// #[wasm_bindgen]
+/// pub struct Registry {
+///     items: Vec<StrongHandle<Item>>,
+/// }
+///
+/// #[wasm_bindgen]
+/// impl Registry {
+///     pub fn add(&mut self, id: u32) -> WeakHandle<Item> {
+///         let item = StrongHandle::new(Item { id });
+///         let handle = item.downgrade();
+///         self.items.push(item);
+///         handle
+///     }
+///
+///     pub fn clear(&mut self) {
+///         // Handles given to JS now `deref()` to `undefined`.
+///         self.items.clear();
+///     }
+/// }
+/// ```
+pub struct WeakHandle<T: ExportedStruct> {
+    weak: Weak<WasmRefCell<T>>,
+}
+
+impl<T: ExportedStruct> WeakHandle<T> {
+    /// Returns a strong handle to the value, if it's still alive.
+    pub fn upgrade(&self) -> Option<StrongHandle<T>> {
+        self.weak.upgrade().map(|rc| StrongHandle { rc })
+    }
+}
+
+impl<T: ExportedStruct> Clone for WeakHandle<T> {
+    fn clone(&self) -> WeakHandle<T> {
+        WeakHandle {
+            weak: self.weak.clone(),
+        }
+    }
+}
+
+impl<T: ExportedStruct> fmt::Debug for WeakHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WeakHandle").finish_non_exhaustive()
+    }
+}
+
+impl<T: ExportedStruct> From<WeakHandle<T>> for JsValue {
+    fn from(handle: WeakHandle<T>) -> JsValue {
+        let deref = Closure::<dyn FnMut() -> JsValue>::own(move || match handle.upgrade() {
+            Some(strong) => JsValue::from(strong),
+            None => JsValue::UNDEFINED,
+        });
+        let obj = crate::__wbindgen_object_new();
+        crate::__wbindgen_reflect_set(&obj, &JsValue::from_str("deref"), &deref.into_js_value());
+        obj
+    }
+}
+
+impl<T: ExportedStruct> WasmDescribe for WeakHandle<T> {
+    fn describe() {
+        T::describe_weak_handle()
+    }
+}
+
+impl<T: ExportedStruct> IntoWasmAbi for WeakHandle<T> {
+    type Abi = u32;
+
+    #[inline]
+    fn into_abi(self) -> u32 {
+        JsValue::from(self).into_abi()
+    }
+}
+
+impl<T: ExportedStruct> OptionIntoWasmAbi for WeakHandle<T> {
+    #[inline]
+    fn none() -> u32 {
+        <JsValue as OptionIntoWasmAbi>::none()
+    }
+}
@@ -0,0 +1,364 @@
+//! A borrowed view of a JS string which is only transcoded on demand.
+
+use alloc::string::String;
+use core::cell::OnceCell;
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem::{self, ManuallyDrop};
+use core::ops::Deref;
+
+use crate::convert::{
+    FromWasmAbi, IntoWasmAbi, OptionFromWasmAbi, OptionIntoWasmAbi, RefFromWasmAbi, TryFromJsValue,
+};
+use crate::describe::{inform, WasmDescribe, JS_STRING};
+use crate::JsValue;
+
+/// A JS string held by reference, without copying it into Rust memory.
+///
+/// Passing a `String` or `&str` from JS to Rust transcodes the whole string
+/// from UTF-16 to UTF-8 into a fresh allocation. `JsStr` instead keeps the JS
+/// string alive in the externref heap and answers queries like [`len`],
+/// [`char_code_at`] and [`starts_with`] by asking JS, so read-mostly code only
+/// pays for what it looks at. The string is transcoded at most once, the first
+/// time [`as_str`] is called.
+///
+/// In TypeScript `JsStr` is typed as `string`. When the bindings are generated
+/// with `--debug`, passing anything else from JS throws an error.
+///
+/// [`len`]: JsStr::len
+/// [`char_code_at`]: JsStr::char_code_at
+/// [`starts_with`]: JsStr::starts_with
+/// [`as_str`]: JsStr::as_str
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::prelude::*;
+/// use wasm_bindgen::JsStr;
+///
+/// #[wasm_bindgen]
+/// pub fn is_comment(line: &JsStr) -> bool {
+///     // Only the prefix is sent to JS, `line` is never copied.
+///     line.starts_with("//")
+/// }
+/// ```
+pub struct JsStr {
+    value: JsValue,
+    utf8: OnceCell<String>,
+}
+
+impl JsStr {
+    fn new(value: JsValue) -> JsStr {
+        JsStr {
+            value,
+            utf8: OnceCell::new(),
+        }
+    }
+
+    /// Returns the length of the string in UTF-16 code units, like
+    /// `String.prototype.length`.
+    pub fn len(&self) -> usize {
+        self.value.__wbindgen_string_length() as usize
+    }
+
+    /// Returns whether the string is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the UTF-16 code unit at `index`, or `None` if it is out of
+    /// bounds.
+    pub fn char_code_at(&self, index: usize) -> Option<u16> {
+        let unit = self.value.__wbindgen_string_char_code_at(index as u32);
+        if unit.is_nan() {
+            None
+        } else {
+            Some(unit as u16)
+        }
+    }
+
+    /// Returns the character starting at the UTF-16 code unit `index`.
+    ///
+    /// Returns `None` if `index` is out of bounds or points at a lone or
+    /// trailing surrogate.
+    pub fn char_at(&self, index: usize) -> Option<char> {
+        let code_point = self.value.__wbindgen_string_code_point_at(index as u32)?;
+        char::from_u32(code_point)
+    }
+
+    /// Returns whether the string starts with `prefix`.
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.value.__wbindgen_string_starts_with(prefix)
+    }
+
+    /// Returns an iterator over the UTF-16 code units of the string.
+    ///
+    /// Every code unit is fetched from JS separately, so prefer [`as_str`]
+    /// when iterating over most of a long string.
+    ///
+    /// [`as_str`]: JsStr::as_str
+    pub fn code_units(&self) -> CodeUnits<'_> {
+        CodeUnits {
+            string: self,
+            front: 0,
+            back: self.len(),
+        }
+    }
+
+    /// Returns the string transcoded to UTF-8.
+    ///
+    /// The string is transcoded the first time this is called and the result
+    /// is kept for later calls. Lone surrogates are replaced with U+FFFD.
+    pub fn as_str(&self) -> &str {
+        self.utf8
+            .get_or_init(|| self.value.as_string().unwrap_or_default())
+    }
+
+    /// Returns the underlying JS string.
+    pub fn as_js_value(&self) -> &JsValue {
+        &self.value
+    }
+}
+
+impl From<&str> for JsStr {
+    fn from(s: &str) -> JsStr {
+        JsStr::new(JsValue::from_str(s))
+    }
+}
+
+impl From<String> for JsStr {
+    fn from(s: String) -> JsStr {
+        let value = JsValue::from_str(&s);
+        JsStr {
+            value,
+            utf8: OnceCell::from(s),
+        }
+    }
+}
+
+impl From<JsStr> for JsValue {
+    fn from(s: JsStr) -> JsValue {
+        s.value
+    }
+}
+
+impl AsRef<JsValue> for JsStr {
+    fn as_ref(&self) -> &JsValue {
+        &self.value
+    }
+}
+
+impl TryFrom<JsValue> for JsStr {
+    type Error = JsValue;
+
+    /// Fails with the original value if it isn't a string.
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        if value.is_string() {
+            Ok(JsStr::new(value))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl TryFromJsValue for JsStr {
+    fn try_from_js_value(value: JsValue) -> Result<Self, JsValue> {
+        JsStr::try_from(value)
+    }
+
+    fn try_from_js_value_ref(value: &JsValue) -> Option<Self> {
+        JsStr::try_from(value.clone()).ok()
+    }
+}
+
+impl Clone for JsStr {
+    fn clone(&self) -> JsStr {
+        JsStr {
+            value: self.value.clone(),
+            utf8: self.utf8.clone(),
+        }
+    }
+}
+
+impl PartialEq for JsStr {
+    fn eq(&self, other: &JsStr) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for JsStr {}
+
+impl PartialEq<str> for JsStr {
+    fn eq(&self, other: &str) -> bool {
+        match self.utf8.get() {
+            Some(s) => s == other,
+            None => self.value == JsValue::from_str(other),
+        }
+    }
+}
+
+impl PartialEq<&str> for JsStr {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+/// Strings are ordered by their UTF-16 code units, like JS's `<`.
+impl PartialOrd for JsStr {
+    fn partial_cmp(&self, other: &JsStr) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsStr {
+    fn cmp(&self, other: &JsStr) -> Ordering {
+        if self == other {
+            Ordering::Equal
+        } else if crate::__wbindgen_lt(&self.value, &other.value) {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        }
+    }
+}
+
+impl fmt::Debug for JsStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl fmt::Display for JsStr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An iterator over the UTF-16 code units of a [`JsStr`], created by
+/// [`JsStr::code_units`].
+#[derive(Debug, Clone)]
+pub struct CodeUnits<'a> {
+    string: &'a JsStr,
+    front: usize,
+    back: usize,
+}
+
+impl Iterator for CodeUnits<'_> {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        if self.front == self.back {
+            return None;
+        }
+        let unit = self.string.char_code_at(self.front);
+        self.front += 1;
+        unit
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for CodeUnits<'_> {
+    fn next_back(&mut self) -> Option<u16> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        self.string.char_code_at(self.back)
+    }
+}
+
+impl ExactSizeIterator for CodeUnits<'_> {}
+
+impl FusedIterator for CodeUnits<'_> {}
+
+impl WasmDescribe for JsStr {
+    fn describe() {
+        inform(JS_STRING)
+    }
+}
+
+impl IntoWasmAbi for JsStr {
+    type Abi = u32;
+
+    #[inline]
+    fn into_abi(self) -> u32 {
+        self.value.into_abi()
+    }
+}
+
+impl IntoWasmAbi for &JsStr {
+    type Abi = u32;
+
+    #[inline]
+    fn into_abi(self) -> u32 {
+        (&self.value).into_abi()
+    }
+}
+
+impl FromWasmAbi for JsStr {
+    type Abi = u32;
+
+    #[inline]
+    unsafe fn from_abi(js: u32) -> JsStr {
+        JsStr::new(JsValue::from_abi(js))
+    }
+}
+
+impl OptionIntoWasmAbi for JsStr {
+    #[inline]
+    fn none() -> u32 {
+        <JsValue as OptionIntoWasmAbi>::none()
+    }
+}
+
+impl OptionIntoWasmAbi for &JsStr {
+    #[inline]
+    fn none() -> u32 {
+        <JsValue as OptionIntoWasmAbi>::none()
+    }
+}
+
+impl OptionFromWasmAbi for JsStr {
+    #[inline]
+    fn is_none(js: &u32) -> bool {
+        <JsValue as OptionFromWasmAbi>::is_none(js)
+    }
+}
+
+/// Keeps a borrowed [`JsStr`] alive for the duration of a call.
+///
+/// Unlike `ManuallyDrop<JsStr>` this still frees the transcoded string, if
+/// any, while leaving the borrowed JS string alone.
+pub struct JsStrAnchor(ManuallyDrop<JsStr>);
+
+impl Deref for JsStrAnchor {
+    type Target = JsStr;
+
+    fn deref(&self) -> &JsStr {
+        &self.0
+    }
+}
+
+impl Drop for JsStrAnchor {
+    fn drop(&mut self) {
+        // SAFETY: `self.0` is never used again.
+        let JsStr { value, utf8 } = unsafe { ManuallyDrop::take(&mut self.0) };
+        mem::forget(value);
+        drop(utf8);
+    }
+}
+
+impl RefFromWasmAbi for JsStr {
+    type Abi = u32;
+    type Anchor = JsStrAnchor;
+
+    #[inline]
+    unsafe fn ref_from_abi(js: u32) -> JsStrAnchor {
+        JsStrAnchor(ManuallyDrop::new(JsStr::new(JsValue::_new(js))))
+    }
+}
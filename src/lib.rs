@@ -11,9 +11,23 @@
 //!
 //! Enables the internal cache for [`wasm_bindgen::intern`].
 //!
+//! The cache is bounded and evicts the least recently used strings, see
+//! [`wasm_bindgen::set_intern_capacity`], can intern frequently sent strings
+//! automatically with [`wasm_bindgen::set_auto_intern_threshold`] and reports
+//! its hit rate through [`wasm_bindgen::intern_stats`].
+//!
 //! This feature currently enables the `std` feature, meaning that it is not
 //! compatible with `no_std` environments.
 //!
+//! ### `closure-diagnostics`
+//!
+//! Records where every [`closure::ScopedClosure`] is created, which is listed
+//! for live closures by [`wasm_bindgen::live_closures`]. With bindings
+//! generated with `--debug`, calling a closure after it was dropped also
+//! reports where it was created.
+//!
+//! This feature currently enables the `std` feature.
+//!
 //! ### `std` (default)
 //!
 //! Enabling this feature will make the crate depend on the Rust standard library.
@@ -123,6 +137,7 @@ pub mod prelude {
 }
 
 pub use wasm_bindgen_macro::link_to;
+pub use wasm_bindgen_macro::{IntoJsValue, TryFromJsValue};
 
 pub mod closure;
 pub mod convert;
@@ -141,13 +156,28 @@ pub use crate::convert::JsGeneric;
 mod cast;
 pub use crate::cast::JsCast;
 
+mod jsstr;
+pub use jsstr::{CodeUnits, JsStr};
+
+mod handle;
+pub use handle::{ExportedStruct, StrongHandle, WeakHandle};
+
+#[cfg(all(feature = "std", target_feature = "atomics"))]
+pub mod thread;
+
+mod view;
+pub use view::{TypedArrayView, ViewElement};
+
 mod cache;
-pub use cache::intern::{intern, unintern};
+pub use cache::intern::{
+    intern, intern_stats, set_auto_intern_threshold, set_intern_capacity, unintern, InternStats,
+};
 
 #[doc(hidden)]
 #[path = "rt/mod.rs"]
 pub mod __rt;
 use __rt::wbg_cast;
+pub use __rt::{record_panic_location, set_panic_location_hook};
 
 /// Representation of an object owned by JS.
 ///
@@ -1206,6 +1236,30 @@ extern "C" {
 
     #[wasm_bindgen(js_name = Number)]
     fn __wbindgen_as_number(v: &JsValue) -> f64;
+
+    #[wasm_bindgen(js_name = Object)]
+    fn __wbindgen_object_new() -> JsValue;
+
+    #[wasm_bindgen(js_name = Array)]
+    fn __wbindgen_array_new() -> JsValue;
+
+    #[wasm_bindgen(js_namespace = Reflect, js_name = get)]
+    fn __wbindgen_reflect_get(target: &JsValue, key: &JsValue) -> JsValue;
+
+    #[wasm_bindgen(js_namespace = Reflect, js_name = set)]
+    fn __wbindgen_reflect_set(target: &JsValue, key: &JsValue, value: &JsValue) -> bool;
+
+    #[wasm_bindgen(method, getter, js_class = "String", js_name = length)]
+    fn __wbindgen_string_length(this: &JsValue) -> u32;
+
+    #[wasm_bindgen(method, js_class = "String", js_name = charCodeAt)]
+    fn __wbindgen_string_char_code_at(this: &JsValue, index: u32) -> f64;
+
+    #[wasm_bindgen(method, js_class = "String", js_name = codePointAt)]
+    fn __wbindgen_string_code_point_at(this: &JsValue, index: u32) -> Option<u32>;
+
+    #[wasm_bindgen(method, js_class = "String", js_name = startsWith)]
+    fn __wbindgen_string_starts_with(this: &JsValue, prefix: &str) -> bool;
 }
 
 // Intrinsics which are handled by cli-support but for which we can use
@@ -1505,6 +1559,45 @@ pub fn anyref_heap_live_count() -> u32 {
     externref_heap_live_count()
 }
 
+/// Get the count of live owned closures.
+///
+/// ## Usage
+///
+/// This is intended for debugging and writing tests, in the same way as
+/// [`externref_heap_live_count`].
+///
+/// ## What is Counted
+///
+/// Every closure created with [`Closure::new`], [`Closure::own`] and friends is
+/// counted until its Rust data is freed. That happens when the `Closure` is
+/// dropped and JS no longer calls it, or when a closure handed over to JS with
+/// [`Closure::into_js_value`] is garbage collected. Closures leaked with
+/// [`Closure::forget`] stay counted forever.
+///
+/// Borrowed closures, like [`ScopedClosure::borrow`], are not counted.
+///
+/// See [`live_closures`] to find out where the counted closures were created.
+pub fn closure_live_count() -> u32 {
+    closure::live_count()
+}
+
+/// Get the creation sites of the live owned closures of the current thread.
+///
+/// This lists the closures counted by [`closure_live_count`], which makes it
+/// possible to find the closures leaked with [`Closure::forget`] or
+/// [`Closure::into_js_value`] and never called or collected:
+///
+/// ```no_run
+/// let leaked = wasm_bindgen::live_closures();
+/// assert!(leaked.is_empty(), "leaked closures: {leaked:?}");
+/// ```
+///
+/// The list is always empty unless the `closure-diagnostics` feature is
+/// enabled.
+pub fn live_closures() -> alloc::vec::Vec<closure::LiveClosure> {
+    closure::live()
+}
+
 /// An extension trait for `Option<T>` and `Result<T, E>` for unwrapping the `T`
 /// value, or throwing a JS error if it is not available.
 ///
@@ -1,11 +1,13 @@
-use crate::convert::{FromWasmAbi, IntoWasmAbi, WasmAbi, WasmRet};
-use crate::describe::inform;
+use crate::convert::{FromWasmAbi, IntoWasmAbi, RefMutFromWasmAbi, WasmAbi, WasmRet};
+use crate::describe::{inform, EXTERNREF};
+use crate::ExportedStruct;
 use crate::JsValue;
 #[cfg(all(target_arch = "wasm32", feature = "std", panic = "unwind"))]
 use core::any::Any;
 use core::borrow::{Borrow, BorrowMut};
 use core::cell::{Cell, UnsafeCell};
 use core::convert::Infallible;
+use core::marker::PhantomData;
 use core::ops::{Deref, DerefMut};
 use core::panic::{RefUnwindSafe, UnwindSafe};
 #[cfg(target_feature = "atomics")]
@@ -23,6 +25,7 @@ pub extern crate std;
 
 pub mod marker;
 
+pub use crate::cache::intern::DeferEvictions;
 pub use wasm_bindgen_macro::BindgenedStruct;
 
 /// Wrapper implementation for JsValue errors, with atomics and std handling
@@ -132,13 +135,17 @@ impl<T, F: FnOnce() -> T> LazyCell<T, F> {
     pub fn force(this: &Self) -> &T {
         &this.0 .0
     }
+
+    pub fn get(this: &Self) -> Option<&T> {
+        Lazy::get(&this.0 .0)
+    }
 }
 
 impl<T> Deref for LazyCell<T> {
     type Target = T;
 
     fn deref(&self) -> &T {
-        ::once_cell::unsync::Lazy::force(&self.0 .0)
+        LazyCell::force(self)
     }
 }
 
@@ -222,6 +229,43 @@ unsafe impl<T, F: Sync> Sync for LazyLock<T, F> {}
 #[cfg(target_feature = "atomics")]
 unsafe impl<T, F: Send> Send for LazyLock<T, F> {}
 
+/// Destructors of thread-local values of this thread, run in reverse order of
+/// registration when the thread is destroyed with `__wbindgen_thread_destroy`.
+#[cfg(target_feature = "atomics")]
+#[thread_local]
+static THREAD_DTORS: core::cell::RefCell<alloc::vec::Vec<(*mut u8, unsafe fn(*mut u8))>> =
+    core::cell::RefCell::new(alloc::vec::Vec::new());
+
+/// Registers `dtor` to be called with `data` when this thread is destroyed.
+///
+/// `#[thread_local]` statics never drop their values, and the standard library
+/// doesn't run the destructors of `thread_local!`s on this target, so this is
+/// how thread-local values owned by `wasm-bindgen` are freed.
+///
+/// # Safety
+///
+/// `data` must stay valid for `dtor` until this thread is destroyed.
+#[cfg(target_feature = "atomics")]
+pub unsafe fn register_thread_dtor(data: *mut u8, dtor: unsafe fn(*mut u8)) {
+    THREAD_DTORS.borrow_mut().push((data, dtor));
+}
+
+/// Runs the destructors registered with [`register_thread_dtor`], called by
+/// `__wbindgen_thread_destroy` before it frees this thread's TLS and stack.
+#[cfg(target_feature = "atomics")]
+#[no_mangle]
+pub unsafe extern "C" fn __wbindgen_thread_dtors() {
+    // Destructors may register more destructors, so pop them one at a time.
+    loop {
+        let next = THREAD_DTORS.borrow_mut().pop();
+        match next {
+            Some((data, dtor)) => dtor(data),
+            None => break,
+        }
+    }
+    drop(core::mem::take(&mut *THREAD_DTORS.borrow_mut()));
+}
+
 #[macro_export]
 #[doc(hidden)]
 #[cfg(not(target_feature = "atomics"))]
@@ -243,7 +287,26 @@ macro_rules! __wbindgen_thread_local {
         static _VAL: $wasm_bindgen::__rt::LazyCell<$actual_ty> =
             $wasm_bindgen::__rt::LazyCell::new(init);
         $wasm_bindgen::JsThreadLocal {
-            __inner: || unsafe { $wasm_bindgen::__rt::LazyCell::force(&_VAL) as *const $actual_ty },
+            __inner: || unsafe {
+                if let Some(value) = $wasm_bindgen::__rt::LazyCell::get(&_VAL) {
+                    return value as *const $actual_ty;
+                }
+                let value = $wasm_bindgen::__rt::LazyCell::force(&_VAL) as *const $actual_ty;
+                // `#[thread_local]` statics never drop their values, so drop
+                // this one when the thread is destroyed.
+                unsafe fn drop_val(data: *mut u8) {
+                    unsafe {
+                        $wasm_bindgen::__rt::core::ptr::drop_in_place(
+                            data.cast::<$wasm_bindgen::__rt::LazyCell<$actual_ty>>(),
+                        );
+                    }
+                }
+                $wasm_bindgen::__rt::register_thread_dtor(
+                    $wasm_bindgen::__rt::core::ptr::addr_of!(_VAL) as *mut u8,
+                    drop_val,
+                );
+                value
+            },
         }
     }};
 }
@@ -300,6 +363,10 @@ fn throw_null() -> ! {
 /// function in this crate which raises an error in JS.
 pub struct WasmRefCell<T: ?Sized> {
     borrow: Cell<usize>,
+    /// The exported methods holding the outstanding borrows which were
+    /// recorded with a [`BorrowSite`], outermost first.
+    #[cfg(debug_assertions)]
+    borrowers: UnsafeCell<alloc::vec::Vec<Borrower>>,
     value: UnsafeCell<T>,
 }
 
@@ -314,6 +381,8 @@ impl<T: ?Sized> WasmRefCell<T> {
         WasmRefCell {
             value: UnsafeCell::new(value),
             borrow: Cell::new(0),
+            #[cfg(debug_assertions)]
+            borrowers: UnsafeCell::new(alloc::vec::Vec::new()),
         }
     }
 
@@ -323,8 +392,8 @@ impl<T: ?Sized> WasmRefCell<T> {
 
     pub fn borrow(&self) -> Ref<'_, T> {
         unsafe {
-            if self.borrow.get() == usize::MAX {
-                borrow_fail();
+            if self.conflicts(false) {
+                self.borrow_fail(None);
             }
             self.borrow.set(self.borrow.get() + 1);
             Ref {
@@ -336,8 +405,8 @@ impl<T: ?Sized> WasmRefCell<T> {
 
     pub fn borrow_mut(&self) -> RefMut<'_, T> {
         unsafe {
-            if self.borrow.get() != 0 {
-                borrow_fail();
+            if self.conflicts(true) {
+                self.borrow_fail(None);
             }
             self.borrow.set(usize::MAX);
             RefMut {
@@ -353,6 +422,177 @@ impl<T: ?Sized> WasmRefCell<T> {
     {
         self.value.into_inner()
     }
+
+    /// Whether a new borrow, mutable or not, would conflict with the
+    /// outstanding ones.
+    fn conflicts(&self, mutable: bool) -> bool {
+        if mutable {
+            self.borrow.get() != 0
+        } else {
+            self.borrow.get() >= ASYNC_BORROW
+        }
+    }
+
+    /// Describes why a new borrow conflicts with the outstanding ones.
+    fn conflict_reason(&self) -> &'static str {
+        if self.borrow.get() == ASYNC_BORROW {
+            ASYNC_BUSY
+        } else {
+            BORROW_FAIL
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    fn borrow_fail(&self, _new: Option<(&str, bool)>) -> ! {
+        borrow_fail(self.conflict_reason())
+    }
+
+    #[cfg(debug_assertions)]
+    fn borrow_fail(&self, new: Option<(&str, bool)>) -> ! {
+        borrow_fail(&self.conflict_message(new))
+    }
+
+    /// Creates the error a conflicting borrow rejects a promise with.
+    #[cfg(not(debug_assertions))]
+    fn conflict_error(&self, _new: Option<(&str, bool)>) -> JsValue {
+        crate::__wbindgen_error_new(self.conflict_reason())
+    }
+
+    #[cfg(debug_assertions)]
+    fn conflict_error(&self, new: Option<(&str, bool)>) -> JsValue {
+        crate::__wbindgen_error_new(&self.conflict_message(new))
+    }
+
+    /// Describes a conflicting borrow along with the outstanding borrows and
+    /// the new one, `(exported method, mutable)`, if they're known, and the JS
+    /// stacks of where each of them was borrowed.
+    #[cfg(debug_assertions)]
+    fn conflict_message(&self, new: Option<(&str, bool)>) -> alloc::string::String {
+        use alloc::string::String;
+        use core::fmt::Write;
+
+        fn kind(mutable: bool) -> &'static str {
+            if mutable {
+                "mutable"
+            } else {
+                "shared"
+            }
+        }
+
+        let mut msg = String::from(self.conflict_reason());
+        for existing in unsafe { &*self.borrowers.get() } {
+            let _ = write!(
+                msg,
+                "\n\nexisting borrow: {}, by `{}`",
+                kind(existing.mutable),
+                existing.name
+            );
+            if let Some(stack) =
+                crate::__wbindgen_reflect_get(&existing.stack, &"stack".into()).as_string()
+            {
+                let _ = write!(msg, "\n{stack}");
+            }
+        }
+        if let Some((name, mutable)) = new {
+            let _ = write!(msg, "\n\nnew borrow: {}, by `{name}`", kind(mutable));
+            let stack = crate::__wbindgen_error_new("");
+            if let Some(stack) = crate::__wbindgen_reflect_get(&stack, &"stack".into()).as_string()
+            {
+                let _ = write!(msg, "\n{stack}");
+            }
+        }
+        msg
+    }
+}
+
+/// The exported method holding a borrow of a [`WasmRefCell`].
+#[cfg(debug_assertions)]
+struct Borrower {
+    name: &'static str,
+    mutable: bool,
+    /// An `Error` created when the borrow started, whose `stack` is only
+    /// formatted if a later borrow conflicts with it.
+    stack: JsValue,
+}
+
+/// Records the exported method borrowing a `WasmRefCell` for as long as it
+/// lives, so that a conflicting borrow can report both methods.
+///
+/// Exported methods taking `self` by reference create one of these before
+/// borrowing `self`. In release builds this does nothing.
+pub struct BorrowSite<T: 'static> {
+    /// Keeps the cell alive until this site is removed from its borrowers.
+    #[cfg(debug_assertions)]
+    cell: Rc<WasmRefCell<T>>,
+    #[cfg(debug_assertions)]
+    name: &'static str,
+    #[cfg(not(debug_assertions))]
+    _marker: core::marker::PhantomData<T>,
+}
+
+impl<T: 'static> BorrowSite<T> {
+    /// Checks that `name` can borrow the `WasmRefCell<T>` at `ptr`, throwing
+    /// an error describing both borrows if not.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a pointer to a live `Rc<WasmRefCell<T>>`, as passed to
+    /// exported methods.
+    #[cfg(debug_assertions)]
+    pub unsafe fn enter(ptr: u32, name: &'static str, mutable: bool) -> Self {
+        let ptr = ptr as *mut WasmRefCell<T>;
+        assert_not_null(ptr);
+        let cell = &*ptr;
+        if cell.conflicts(mutable) {
+            cell.borrow_fail(Some((name, mutable)));
+        }
+        (*cell.borrowers.get()).push(Borrower {
+            name,
+            mutable,
+            stack: crate::__wbindgen_error_new(""),
+        });
+        Rc::increment_strong_count(ptr);
+        BorrowSite {
+            cell: Rc::from_raw(ptr),
+            name,
+        }
+    }
+
+    #[cfg(not(debug_assertions))]
+    #[inline]
+    pub unsafe fn enter(_ptr: u32, _name: &'static str, _mutable: bool) -> Self {
+        BorrowSite {
+            _marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Like [`enter`](Self::enter) for async methods taking `&self`, but
+    /// returns the error to reject their promise with instead of throwing it.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a pointer to a live `Rc<WasmRefCell<T>>`, as passed to
+    /// exported methods.
+    pub unsafe fn enter_async(ptr: u32, name: &'static str) -> Result<Self, JsValue> {
+        let cell = ptr as *mut WasmRefCell<T>;
+        assert_not_null(cell);
+        if (*cell).conflicts(false) {
+            return Err((*cell).conflict_error(Some((name, false))));
+        }
+        Ok(Self::enter(ptr, name, false))
+    }
+}
+
+#[cfg(debug_assertions)]
+impl<T: 'static> Drop for BorrowSite<T> {
+    fn drop(&mut self) {
+        // Async methods keep borrowing the cell after they returned, so
+        // borrows don't always end in the reverse order they started in.
+        let borrowers = unsafe { &mut *self.cell.borrowers.get() };
+        if let Some(i) = borrowers.iter().rposition(|b| b.name == self.name) {
+            borrowers.remove(i);
+        }
+    }
 }
 
 pub struct Ref<'b, T: ?Sized + 'b> {
@@ -423,20 +663,24 @@ impl<T: ?Sized> Drop for RefMut<'_, T> {
     }
 }
 
+const BORROW_FAIL: &str =
+    "recursive use of an object detected which would lead to unsafe aliasing in rust";
+
+const ASYNC_BUSY: &str =
+    "object is busy in an async call, wait for the promise it returned to settle before using it";
+
+/// The borrow count of a `WasmRefCell` borrowed mutably by an async method,
+/// which is only told apart from `usize::MAX` to explain conflicts.
+const ASYNC_BORROW: usize = usize::MAX - 1;
+
 #[cfg(panic = "unwind")]
-fn borrow_fail() -> ! {
-    panic!(
-        "recursive use of an object detected which would lead to \
-		 unsafe aliasing in rust",
-    )
+fn borrow_fail(msg: &str) -> ! {
+    panic!("{msg}")
 }
 
 #[cfg(not(panic = "unwind"))]
-fn borrow_fail() -> ! {
-    super::throw_str(
-        "recursive use of an object detected which would lead to \
-		 unsafe aliasing in rust",
-    );
+fn borrow_fail(msg: &str) -> ! {
+    super::throw_str(msg);
 }
 
 /// A type that encapsulates an `Rc<WasmRefCell<T>>` as well as a `Ref`
@@ -482,6 +726,112 @@ impl<T: ?Sized> Borrow<T> for RcRef<T> {
     }
 }
 
+/// The anchor of `&mut self` in async methods, which keeps `self` borrowed
+/// until the method's future completes or is dropped.
+pub struct RcRefMutAsync<T: 'static> {
+    // Like in `RcRef`, this goes before `Rc` so that it gets dropped first.
+    ref_: RefMut<'static, T>,
+    _rc: Rc<WasmRefCell<T>>,
+}
+
+impl<T: RefMutFromWasmAbi + 'static> RcRefMutAsync<T> {
+    /// Borrows the `WasmRefCell<T>` at `ptr` for the async method `name`,
+    /// returning the error to reject its promise with if that conflicts with
+    /// an outstanding borrow.
+    ///
+    /// `T: RefMutFromWasmAbi` excludes `reentrant` structs, which can't be
+    /// borrowed mutably.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be a pointer to a live `Rc<WasmRefCell<T>>`, as passed to
+    /// exported methods.
+    pub unsafe fn new(ptr: u32, name: &'static str) -> Result<Self, JsValue> {
+        let ptr = ptr as *mut WasmRefCell<T>;
+        assert_not_null(ptr);
+        let cell = &*ptr;
+        if cell.conflicts(true) {
+            return Err(cell.conflict_error(Some((name, true))));
+        }
+        #[cfg(debug_assertions)]
+        {
+            (*cell.borrowers.get()).push(Borrower {
+                name,
+                mutable: true,
+                stack: crate::__wbindgen_error_new(""),
+            });
+        }
+        cell.borrow.set(ASYNC_BORROW);
+        Rc::increment_strong_count(ptr);
+        let rc = Rc::from_raw(ptr);
+        let ref_ = RefMut {
+            value: &mut *cell.value.get(),
+            borrow: &cell.borrow,
+        };
+        Ok(Self { ref_, _rc: rc })
+    }
+}
+
+#[cfg(debug_assertions)]
+impl<T: 'static> Drop for RcRefMutAsync<T> {
+    fn drop(&mut self) {
+        unsafe { (*self._rc.borrowers.get()).pop() };
+    }
+}
+
+impl<T> Deref for RcRefMutAsync<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.ref_
+    }
+}
+
+impl<T> DerefMut for RcRefMutAsync<T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.ref_
+    }
+}
+
+/// A type that encapsulates an `Rc<WasmRefCell<T>>` and gives shared access
+/// to its contents without borrowing the `WasmRefCell`.
+///
+/// This is the anchor of `&self` for `#[wasm_bindgen(reentrant)]` structs,
+/// which can't be borrowed mutably from JS, so that shared borrows can't
+/// conflict with anything and don't need to be counted.
+pub struct RcShared<T: ?Sized + 'static> {
+    rc: Rc<WasmRefCell<T>>,
+}
+
+impl<T: ?Sized> UnwindSafe for RcShared<T> {}
+
+impl<T: ?Sized> RcShared<T> {
+    /// # Safety
+    ///
+    /// The `WasmRefCell` must never be borrowed mutably while this lives.
+    pub unsafe fn new(rc: Rc<WasmRefCell<T>>) -> Self {
+        Self { rc }
+    }
+}
+
+impl<T: ?Sized> Deref for RcShared<T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        unsafe { &*self.rc.value.get() }
+    }
+}
+
+impl<T: ?Sized> Borrow<T> for RcShared<T> {
+    #[inline]
+    fn borrow(&self) -> &T {
+        self
+    }
+}
+
 /// A type that encapsulates an `Rc<WasmRefCell<T>>` as well as a
 /// `RefMut` to the contents of that `WasmRefCell`.
 ///
@@ -745,6 +1095,29 @@ impl<E: core::fmt::Debug> Main for &mut MainWrapper<Result<(), E>> {
     }
 }
 
+/// An internal helper struct for describing the error type of exported
+/// functions returning `Result<T, E>`, so that it can be documented as what the
+/// function throws if `E` is an exported struct.
+pub struct ThrowsWrapper<E>(pub PhantomData<E>);
+
+pub trait DescribeThrows {
+    fn describe_throws(&self);
+}
+
+impl<E: ExportedStruct> DescribeThrows for &ThrowsWrapper<E> {
+    #[inline]
+    fn describe_throws(&self) {
+        E::describe();
+    }
+}
+
+impl<E> DescribeThrows for ThrowsWrapper<E> {
+    #[inline]
+    fn describe_throws(&self) {
+        inform(EXTERNREF);
+    }
+}
+
 pub const fn flat_len<T, const SIZE: usize>(slices: [&[T]; SIZE]) -> usize {
     let mut len = 0;
     let mut i = 0;
@@ -797,8 +1170,54 @@ extern "C" {
     fn __wbindgen_panic_error(msg: &JsValue) -> JsValue;
 }
 
+#[cfg(all(target_arch = "wasm32", feature = "std", panic = "unwind"))]
+std::thread_local! {
+    /// Where the last panic on this thread happened, recorded by
+    /// [`record_panic_location`].
+    static PANIC_LOCATION: core::cell::RefCell<Option<std::string::String>> =
+        const { core::cell::RefCell::new(None) };
+}
+
+/// Records where the panic currently being handled happened, so that the
+/// `PanicError` it turns into can report it.
+///
+/// This is meant to be called from a panic hook, see
+/// [`set_panic_location_hook`] for one which chains to the current hook.
+#[cfg(all(target_arch = "wasm32", feature = "std", panic = "unwind"))]
+pub fn record_panic_location(location: Option<&core::panic::Location<'_>>) {
+    if let Some(location) = location {
+        let location = std::format!("{location}");
+        PANIC_LOCATION.with(|l| *l.borrow_mut() = Some(location));
+    }
+}
+
+#[cfg(not(all(target_arch = "wasm32", feature = "std", panic = "unwind")))]
+pub fn record_panic_location(_location: Option<&core::panic::Location<'_>>) {}
+
+/// Installs a panic hook, chained to the current one, which records where
+/// panics happen so that `PanicError`s can report it.
+///
+/// Nothing records panic locations unless this is called, or a custom hook
+/// calls [`record_panic_location`]. A hook installed afterwards replaces this
+/// one unless it chains to it. Like [`std::panic::take_hook`], this panics
+/// when called from a panicking thread.
+///
+/// This does nothing unless compiled with `panic = "unwind"` for Wasm.
+#[cfg(all(target_arch = "wasm32", feature = "std", panic = "unwind"))]
+pub fn set_panic_location_hook() {
+    let prev = std::panic::take_hook();
+    std::panic::set_hook(std::boxed::Box::new(move |info| {
+        record_panic_location(info.location());
+        prev(info);
+    }));
+}
+
+#[cfg(not(all(target_arch = "wasm32", feature = "std", panic = "unwind")))]
+pub fn set_panic_location_hook() {}
+
 #[cfg(all(target_arch = "wasm32", feature = "std", panic = "unwind"))]
 pub fn panic_to_panic_error(val: std::boxed::Box<dyn Any + Send>) -> JsValue {
+    let location = PANIC_LOCATION.with(|l| l.borrow_mut().take());
     #[cfg(not(target_feature = "atomics"))]
     {
         if let Some(s) = val.downcast_ref::<JsValue>() {
@@ -815,11 +1234,27 @@ pub fn panic_to_panic_error(val: std::boxed::Box<dyn Any + Send>) -> JsValue {
     let err: JsValue = __wbindgen_panic_error(&JsValue::from_str(
         maybe_panic_msg.unwrap_or("No panic message available"),
     ));
+    if let Some(location) = location {
+        crate::__wbindgen_reflect_set(
+            &err,
+            &JsValue::from_str("location"),
+            &JsValue::from(location),
+        );
+    }
     err
 }
 
+/// Runs `f`, turning a panic into a thrown `PanicError`.
+///
+/// Every exported function runs in its own call to this, so a panic only
+/// fails that call: unwinding drops everything it held, including the
+/// `WasmRefCell` borrows of `self` and other arguments, and the module stays
+/// usable for later calls.
 #[cfg(all(target_arch = "wasm32", feature = "std", panic = "unwind"))]
 pub fn maybe_catch_unwind<F: FnOnce() -> R + std::panic::UnwindSafe, R>(f: F) -> R {
+    // Forget a location left over from a panic caught elsewhere, e.g. by a
+    // `catch_unwind` in user code, so it isn't reported for this call.
+    PANIC_LOCATION.with(|l| l.borrow_mut().take());
     let result = std::panic::catch_unwind(f);
     match result {
         Ok(val) => val,
@@ -0,0 +1,165 @@
+//! Spawning threads which run Rust closures, for Wasm built with `atomics`.
+//!
+//! Each thread is a worker (a Web Worker on the web, a `worker_threads`
+//! `Worker` in Node.js) which instantiates this Wasm module with the same
+//! shared memory, then runs the closure it was spawned with and exits.
+
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::{Cell, RefCell};
+use core::fmt;
+use core::future::poll_fn;
+use core::panic::AssertUnwindSafe;
+use core::task::{Context, Poll, Waker};
+use std::sync::{Arc, Mutex};
+
+use crate::closure::Closure;
+use crate::JsValue;
+
+#[wasm_bindgen_macro::wasm_bindgen(wasm_bindgen = crate, raw_module = "__wbindgen_placeholder__")]
+extern "C" {
+    #[wasm_bindgen(catch)]
+    fn __wbindgen_spawn_thread(
+        module: &JsValue,
+        memory: &JsValue,
+        work: u32,
+        on_exit: &JsValue,
+    ) -> Result<(), JsValue>;
+}
+
+type Work = Box<dyn FnOnce() + Send>;
+
+/// Spawns a new thread which runs `f`, returning a [`JoinHandle`] for it.
+///
+/// The thread is a new worker which shares this module's memory, so `f` can
+/// use anything `Send` from the spawning thread, but not its JS values. The
+/// worker exits once `f` returns.
+///
+/// This is supported with `--target web` and `--target nodejs`, which use
+/// `import.meta.url` or `__filename` to load the generated JS in the worker.
+///
+/// # Panics
+///
+/// Panics if the worker can't be created.
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::prelude::*;
+///
+/// #[wasm_bindgen]
+/// pub async fn sum_in_thread(numbers: Vec<u32>) -> Result<u32, JsValue> {
+///     wasm_bindgen::thread::spawn(move || numbers.iter().sum())
+///         .join_async()
+///         .await
+/// }
+/// ```
+pub fn spawn<F, T>(f: F) -> JoinHandle<T>
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let result = Arc::new(Mutex::new(None));
+    let their_result = result.clone();
+    let work: Box<Work> = Box::new(Box::new(move || {
+        let value = f();
+        *their_result.lock().unwrap() = Some(value);
+    }));
+
+    let exit = Rc::new(Exit::default());
+    let on_exit = {
+        let exit = AssertUnwindSafe(exit.clone());
+        Closure::once_into_js(move |error: JsValue| exit.finish(error))
+    };
+
+    let work = Box::into_raw(work);
+    if let Err(e) =
+        __wbindgen_spawn_thread(&crate::module(), &crate::memory(), work as u32, &on_exit)
+    {
+        drop(unsafe { Box::from_raw(work) });
+        panic!("failed to spawn thread: {e:?}");
+    }
+
+    JoinHandle { result, exit }
+}
+
+/// Runs the closure a thread was spawned with, called by the worker's JS once
+/// it instantiated the module.
+#[cfg(all(target_arch = "wasm32", any(target_os = "unknown", target_os = "none")))]
+#[no_mangle]
+pub unsafe extern "C" fn __wbindgen_thread_entry(work: u32) {
+    let work = Box::from_raw(work as *mut Work);
+    work();
+}
+
+/// How a thread exited, set by the spawning thread when its worker exits.
+#[derive(Default)]
+struct Exit {
+    exited: Cell<bool>,
+    error: RefCell<Option<JsValue>>,
+    waker: RefCell<Option<Waker>>,
+}
+
+impl Exit {
+    fn finish(&self, error: JsValue) {
+        self.exited.set(true);
+        if !error.is_undefined() {
+            *self.error.borrow_mut() = Some(error);
+        }
+        if let Some(waker) = self.waker.borrow_mut().take() {
+            waker.wake();
+        }
+    }
+}
+
+/// A handle to a thread started with [`spawn`].
+///
+/// Dropping the handle detaches the thread, which keeps running.
+///
+/// A thread's exit is noticed by the event loop of the thread which spawned
+/// it, so the handle can't be sent to other threads, and
+/// [`join_async`](JoinHandle::join_async) only completes once the spawning
+/// thread yields to its event loop.
+pub struct JoinHandle<T> {
+    result: Arc<Mutex<Option<T>>>,
+    exit: Rc<Exit>,
+}
+
+impl<T> JoinHandle<T> {
+    /// Returns whether the thread exited.
+    pub fn is_finished(&self) -> bool {
+        self.exit.exited.get()
+    }
+
+    /// Waits for the thread to exit, returning the value its closure returned.
+    ///
+    /// If the thread failed, for example because its closure panicked, this
+    /// returns the error its worker reported.
+    pub async fn join_async(self) -> Result<T, JsValue> {
+        poll_fn(|cx| self.poll_join(cx)).await
+    }
+
+    fn poll_join(&self, cx: &mut Context<'_>) -> Poll<Result<T, JsValue>> {
+        if !self.exit.exited.get() {
+            *self.exit.waker.borrow_mut() = Some(cx.waker().clone());
+            return Poll::Pending;
+        }
+        if let Some(error) = self.exit.error.borrow_mut().take() {
+            return Poll::Ready(Err(error));
+        }
+        Poll::Ready(match self.result.lock().unwrap().take() {
+            Some(value) => Ok(value),
+            None => Err(crate::__wbindgen_error_new(
+                "thread exited without returning a value",
+            )),
+        })
+    }
+}
+
+impl<T> fmt::Debug for JoinHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinHandle")
+            .field("finished", &self.is_finished())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,148 @@
+//! Typed array views into Wasm memory which are kept valid across memory
+//! growth.
+
+use core::cell::{Cell, RefCell};
+
+use crate::convert::IntoWasmAbi;
+use crate::describe::WasmDescribe;
+use crate::JsValue;
+
+/// A JS typed array viewing a Rust slice, without copying it.
+///
+/// Views into Wasm memory, like the ones returned by the `unsafe`
+/// `js_sys::Float32Array::view`, are detached whenever the memory grows, which
+/// any allocation can do. Reading a detached view doesn't fail, it just finds
+/// an empty array.
+///
+/// `TypedArrayView` mutably borrows the slice for as long as it lives, so the
+/// data can't be moved, freed or accessed from Rust while JS may read or write
+/// it through the view. The JS view itself never leaves the guard: a
+/// `&TypedArrayView` is passed to imports like a `&JsValue`, and every time it
+/// is passed the guard checks whether memory grew since the view was created,
+/// creating a new one if so.
+///
+/// When the bindings are generated with `--debug`, passing a view which was
+/// detached by memory growth to a function taking a slice throws an error
+/// instead of silently passing an empty slice.
+///
+/// # Example
+///
+/// ```no_run
+/// use wasm_bindgen::prelude::*;
+/// use wasm_bindgen::TypedArrayView;
+///
+/// #[wasm_bindgen]
+/// extern "C" {
+///     fn upload(samples: &TypedArrayView<f32>);
+/// }
+///
+/// let mut samples = vec![0.0f32; 1024];
+/// let view = TypedArrayView::new(&mut samples);
+/// upload(&view);
+/// // Growing memory here detaches the previous view, so a new one is passed.
+/// let more = vec![0u8; 1 << 20];
+/// upload(&view);
+/// drop(view);
+/// # drop(more);
+/// samples[0] = 1.0;
+/// ```
+pub struct TypedArrayView<'a, T: ViewElement> {
+    slice: &'a mut [T],
+    /// The memory size in pages when `view` was created.
+    pages: Cell<usize>,
+    view: RefCell<Option<JsValue>>,
+}
+
+impl<'a, T: ViewElement> TypedArrayView<'a, T> {
+    /// Creates a guard viewing `slice`. The JS view is only created once the
+    /// guard is passed to JS.
+    pub fn new(slice: &'a mut [T]) -> Self {
+        TypedArrayView {
+            slice,
+            pages: Cell::new(0),
+            view: RefCell::new(None),
+        }
+    }
+
+    /// Returns the index of the typed array viewing the slice, e.g. a
+    /// `Float32Array` for `f32`s.
+    ///
+    /// The view is created the first time this is called, and created again
+    /// whenever memory grew since then.
+    fn idx(&self) -> u32 {
+        let pages = memory_pages();
+        let mut view = self.view.borrow_mut();
+        match &*view {
+            Some(view) if self.pages.get() == pages => view.idx,
+            _ => {
+                let new = T::view(self.slice);
+                let idx = new.idx;
+                self.pages.set(pages);
+                *view = Some(new);
+                idx
+            }
+        }
+    }
+}
+
+impl<T: ViewElement> core::fmt::Debug for TypedArrayView<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TypedArrayView")
+            .field("len", &self.slice.len())
+            .finish()
+    }
+}
+
+impl<T: ViewElement> WasmDescribe for TypedArrayView<'_, T> {
+    #[cfg_attr(wasm_bindgen_unstable_test_coverage, coverage(off))]
+    fn describe() {
+        JsValue::describe();
+    }
+}
+
+impl<T: ViewElement> IntoWasmAbi for &TypedArrayView<'_, T> {
+    type Abi = u32;
+
+    #[inline]
+    fn into_abi(self) -> u32 {
+        self.idx()
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn memory_pages() -> usize {
+    core::arch::wasm32::memory_size::<0>()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn memory_pages() -> usize {
+    0
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Element types which have a JS typed array, for use with
+/// [`TypedArrayView`].
+pub trait ViewElement: sealed::Sealed + Sized {
+    #[doc(hidden)]
+    fn view(slice: &[Self]) -> JsValue;
+}
+
+macro_rules! view_elements {
+    ($($t:ty)*) => ($(
+        impl sealed::Sealed for $t {}
+
+        impl ViewElement for $t {
+            #[inline]
+            fn view(slice: &[$t]) -> JsValue {
+                crate::__rt::wbg_cast(slice)
+            }
+        }
+    )*)
+}
+
+view_elements! {
+    u8 i8 u16 i16 u32 i32 u64 i64 f32 f64
+}
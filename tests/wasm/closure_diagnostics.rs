@@ -0,0 +1,47 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+fn live_at(line: u32) -> usize {
+    wasm_bindgen::live_closures()
+        .iter()
+        .filter(|c| c.location.file() == file!() && c.location.line() == line)
+        .count()
+}
+
+#[wasm_bindgen_test]
+fn records_creation_site() {
+    let line = line!() + 1;
+    let closure = Closure::<dyn Fn()>::new(|| {});
+    assert_eq!(live_at(line), 1);
+    let live = wasm_bindgen::live_closures();
+    let entry = live.iter().find(|c| c.location.line() == line).unwrap();
+    assert_eq!(
+        entry.to_string(),
+        format!(
+            "closure created at {}:{line}:{}",
+            file!(),
+            entry.location.column()
+        )
+    );
+
+    drop(closure);
+    assert_eq!(live_at(line), 0);
+}
+
+#[wasm_bindgen_test]
+fn forgotten_closures_stay_listed() {
+    let line = line!() + 1;
+    let closure = Closure::<dyn FnMut(u32)>::new(move |_| {});
+    let count = wasm_bindgen::closure_live_count();
+    closure.forget();
+    assert_eq!(wasm_bindgen::closure_live_count(), count);
+    assert_eq!(live_at(line), 1);
+}
+
+#[wasm_bindgen_test]
+fn borrowed_closures_are_not_listed() {
+    let before = wasm_bindgen::live_closures().len();
+    let f = || {};
+    let _closure = ScopedClosure::borrow(&f);
+    assert_eq!(wasm_bindgen::live_closures().len(), before);
+}
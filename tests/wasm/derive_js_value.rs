@@ -0,0 +1,182 @@
+use js_sys::JSON;
+use wasm_bindgen::convert::TryFromJsValue;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::{IntoJsValue, TryFromJsValue};
+use wasm_bindgen_test::*;
+
+#[derive(IntoJsValue, TryFromJsValue, Debug, PartialEq)]
+struct Item {
+    name: String,
+    #[js(rename = "itemCount")]
+    count: u32,
+    label: Option<String>,
+}
+
+#[derive(IntoJsValue, TryFromJsValue, Debug, PartialEq)]
+struct Order {
+    id: Id,
+    items: Vec<Item>,
+    status: Status,
+    position: Point,
+    created: js_sys::Date,
+}
+
+#[derive(IntoJsValue, TryFromJsValue, Debug, PartialEq)]
+struct Id(u64);
+
+#[derive(IntoJsValue, TryFromJsValue, Debug, PartialEq)]
+struct Point(f64, f64);
+
+#[derive(IntoJsValue, TryFromJsValue, Debug, PartialEq, Clone)]
+enum Status {
+    Open,
+    #[js(rename = "done")]
+    Closed,
+    Shipped(String),
+    Returned {
+        reason: String,
+    },
+}
+
+#[derive(IntoJsValue, TryFromJsValue, Debug, PartialEq)]
+struct Wrapper<T> {
+    value: T,
+}
+
+fn json(value: impl Into<JsValue>) -> String {
+    JSON::stringify(&value.into()).unwrap().into()
+}
+
+fn parse(json: &str) -> JsValue {
+    JSON::parse(json).unwrap()
+}
+
+fn error<T: TryFromJsValue + core::fmt::Debug>(json: &str) -> String {
+    let err = T::try_from_js_value(parse(json)).unwrap_err();
+    err.unchecked_into::<js_sys::Error>().message().into()
+}
+
+fn item(name: &str, count: u32, label: Option<&str>) -> Item {
+    Item {
+        name: name.into(),
+        count,
+        label: label.map(Into::into),
+    }
+}
+
+#[wasm_bindgen_test]
+fn structs() {
+    assert_eq!(
+        json(item("a", 1, Some("x"))),
+        r#"{"name":"a","itemCount":1,"label":"x"}"#
+    );
+    // `None` is converted to `undefined`, which `JSON.stringify` skips.
+    assert_eq!(json(item("a", 1, None)), r#"{"name":"a","itemCount":1}"#);
+    assert_eq!(
+        Item::try_from_js_value(parse(r#"{"name":"a","itemCount":1}"#)).unwrap(),
+        item("a", 1, None)
+    );
+    assert_eq!(
+        Item::try_from_js_value(parse(r#"{"name":"a","itemCount":1,"label":null}"#)).unwrap(),
+        item("a", 1, None)
+    );
+
+    assert_eq!(JsValue::from(Id(5)), JsValue::from(5u64));
+    assert_eq!(json(Point(1., 2.)), "[1,2]");
+    assert_eq!(
+        Point::try_from_js_value(parse("[1,2]")).unwrap(),
+        Point(1., 2.)
+    );
+    assert_eq!(
+        json(Wrapper {
+            value: vec![true, false]
+        }),
+        r#"{"value":[true,false]}"#
+    );
+    assert_eq!(
+        Wrapper::<Vec<bool>>::try_from_js_value(parse(r#"{"value":[true]}"#)).unwrap(),
+        Wrapper { value: vec![true] }
+    );
+}
+
+#[wasm_bindgen_test]
+fn enums() {
+    assert_eq!(json(Status::Open), r#""Open""#);
+    assert_eq!(json(Status::Closed), r#""done""#);
+    assert_eq!(json(Status::Shipped("x".into())), r#"{"Shipped":"x"}"#);
+    assert_eq!(
+        json(Status::Returned { reason: "y".into() }),
+        r#"{"Returned":{"reason":"y"}}"#
+    );
+
+    for status in [
+        Status::Open,
+        Status::Closed,
+        Status::Shipped("x".into()),
+        Status::Returned { reason: "y".into() },
+    ] {
+        assert_eq!(
+            Status::try_from_js_value(parse(&json(status.clone()))).unwrap(),
+            status
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+fn round_trip() {
+    let order = Order {
+        id: Id(7),
+        items: vec![item("a", 1, None), item("b", 2, Some("c"))],
+        status: Status::Shipped("today".into()),
+        position: Point(0.5, 1.5),
+        created: js_sys::Date::new(&JsValue::from(0)),
+    };
+    let value = JsValue::from(Order {
+        created: order.created.clone(),
+        ..order
+    });
+    assert!(js_sys::Reflect::get(&value, &"created".into())
+        .unwrap()
+        .is_instance_of::<js_sys::Date>());
+    let order = Order::try_from_js_value(value).unwrap();
+    assert_eq!(order.items[1], item("b", 2, Some("c")));
+    assert_eq!(order.status, Status::Shipped("today".into()));
+}
+
+#[wasm_bindgen_test]
+fn error_paths() {
+    assert_eq!(error::<Item>("1"), "expected object");
+    assert_eq!(
+        error::<Item>(r#"{"name":1,"itemCount":1}"#),
+        "name: expected string"
+    );
+    assert_eq!(
+        error::<Item>(r#"{"name":"a"}"#),
+        "itemCount: expected number"
+    );
+    assert_eq!(error::<Point>(r#"[1,"2"]"#), "[1]: expected number");
+    assert_eq!(
+        error::<Status>(r#""Closed""#),
+        r#"expected one of "Open", "done", { Shipped }, { Returned }"#
+    );
+    assert_eq!(
+        error::<Status>(r#"{"Returned":{}}"#),
+        "Returned.reason: expected string"
+    );
+    assert_eq!(
+        error::<Order>(
+            r#"{"id":1,"items":[{"name":"a","itemCount":1},{"name":2}],"status":"Open","position":[0,0]}"#
+        ),
+        "id: expected bigint"
+    );
+    assert_eq!(
+        error::<Wrapper<Vec<Item>>>(
+            r#"{"value":[{"name":"a","itemCount":1},{"name":"b","itemCount":2},{"name":"c","itemCount":3},{"name":4}]}"#
+        ),
+        "value[3].name: expected string"
+    );
+    assert_eq!(
+        error::<Wrapper<js_sys::Date>>(r#"{"value":0}"#),
+        "value: expected Date"
+    );
+}
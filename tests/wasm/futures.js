@@ -15,9 +15,30 @@ exports.call_exports = async function(catchUnwind) {
   await assert.rejects(wasm.async_throw_message(), /async message/);
   await assert.rejects(wasm.async_throw_jserror(), /async message/);
   await assert.rejects(wasm.async_throw_custom_error(), /custom error/);
+  await assert.rejects(wasm.async_throw_exported_error(), (e) => {
+    assert.ok(e instanceof wasm.AsyncExportedError);
+    assert.strictEqual(e.code, 8);
+    e.free();
+    return true;
+  });
+  await assert.rejects(wasm.async_throw_annotated(), /annotated/);
   assert.strictEqual("Hi, Jim!", await wasm.async_take_reference("Jim"));
   const foo = await new wasm.AsyncStruct();
   assert.strictEqual(42, await foo.method());
+
+  // An async `&mut self` method keeps the object borrowed until it completes.
+  const buffer = new wasm.AsyncBuffer();
+  let ready;
+  const first = buffer.append(1, new Promise((resolve) => { ready = resolve; }));
+  await assert.rejects(buffer.append(2, Promise.resolve()), /object is busy in an async call/);
+  assert.throws(() => buffer.count(), /object is busy in an async call/);
+  await assert.rejects(buffer.count_async(), /object is busy in an async call/);
+  ready();
+  assert.strictEqual(await first, 1);
+  assert.strictEqual(await buffer.append(2, Promise.resolve()), 2);
+  assert.strictEqual(buffer.count(), 2);
+  assert.strictEqual(await buffer.count_async(), 2);
+  buffer.free();
   await wasm.async_take_js_reference(42);
   if (!catchUnwind) {
       const buffer = new Int32Array([1, 2, 3, 4]);
@@ -118,6 +118,21 @@ pub async fn async_throw_custom_error() -> Result<AsyncCustomReturn, AsyncCustom
     })
 }
 
+#[wasm_bindgen]
+pub struct AsyncExportedError {
+    pub code: u32,
+}
+
+#[wasm_bindgen]
+pub async fn async_throw_exported_error() -> Result<u32, AsyncExportedError> {
+    Err(AsyncExportedError { code: 8 })
+}
+
+#[wasm_bindgen(throws = js_sys::Error)]
+pub async fn async_throw_annotated() -> Result<u32, JsValue> {
+    Err(js_sys::Error::new("annotated").into())
+}
+
 #[wasm_bindgen]
 pub async fn async_take_reference(x: &str) -> String {
     format!("Hi, {x}!")
@@ -139,6 +154,35 @@ impl AsyncStruct {
     }
 }
 
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct AsyncBuffer {
+    chunks: Vec<u8>,
+}
+
+#[wasm_bindgen]
+impl AsyncBuffer {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> AsyncBuffer {
+        AsyncBuffer::default()
+    }
+
+    /// Waits for `ready` while holding a mutable borrow of `self`.
+    pub async fn append(&mut self, chunk: u8, ready: js_sys::Promise) -> usize {
+        wasm_bindgen_futures::JsFuture::from(ready).await.unwrap();
+        self.chunks.push(chunk);
+        self.chunks.len()
+    }
+
+    pub fn count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub async fn count_async(&self) -> usize {
+        self.chunks.len()
+    }
+}
+
 #[wasm_bindgen]
 pub async fn async_take_js_reference(x: &JsValue) {
     assert_eq!(*x, 42);
@@ -0,0 +1,34 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_weak_handles = (registry) => {
+  const handle = registry.add(1);
+  registry.add(2);
+
+  const item = handle.deref();
+  assert.ok(item instanceof wasm.HandleItem);
+  assert.strictEqual(item.id, 1);
+
+  // Instances share the value held by the registry.
+  item.id = 10;
+  const first = registry.get(0);
+  assert.strictEqual(first.id, 10);
+  first.free();
+  assert.strictEqual(registry.total(), 12);
+  item.free();
+
+  // Passing an instance to Rust by value takes over its reference.
+  const adopted = new wasm.HandleRegistry();
+  adopted.adopt(registry.get(1));
+  assert.strictEqual(adopted.total(), 2);
+
+  // Once the registry drops its items, only the adopted one is alive.
+  const second = registry.add(3);
+  registry.clear();
+  assert.strictEqual(handle.deref(), undefined);
+  assert.strictEqual(second.deref(), undefined);
+  assert.strictEqual(registry.total(), 0);
+
+  adopted.free();
+  registry.free();
+};
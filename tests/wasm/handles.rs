@@ -0,0 +1,68 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::{StrongHandle, WeakHandle};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/handles.js")]
+extern "C" {
+    fn js_weak_handles(registry: HandleRegistry);
+}
+
+#[wasm_bindgen]
+pub struct HandleItem {
+    pub id: u32,
+}
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct HandleRegistry {
+    items: Vec<StrongHandle<HandleItem>>,
+}
+
+#[wasm_bindgen]
+impl HandleRegistry {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> HandleRegistry {
+        HandleRegistry::default()
+    }
+
+    pub fn add(&mut self, id: u32) -> WeakHandle<HandleItem> {
+        let item = StrongHandle::new(HandleItem { id });
+        let handle = item.downgrade();
+        self.items.push(item);
+        handle
+    }
+
+    pub fn get(&self, index: usize) -> Option<StrongHandle<HandleItem>> {
+        self.items.get(index).cloned()
+    }
+
+    pub fn adopt(&mut self, item: StrongHandle<HandleItem>) {
+        self.items.push(item);
+    }
+
+    pub fn total(&self) -> u32 {
+        self.items
+            .iter()
+            .map(|item| item.with(|item| item.id))
+            .sum()
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+}
+
+#[wasm_bindgen_test]
+fn weak_handles() {
+    js_weak_handles(HandleRegistry::new());
+}
+
+#[wasm_bindgen_test]
+fn upgrade_after_drop() {
+    let item = StrongHandle::new(HandleItem { id: 1 });
+    let weak = item.downgrade();
+    item.with_mut(|item| item.id += 1);
+    assert_eq!(weak.upgrade().unwrap().with(|item| item.id), 2);
+    drop(item);
+    assert!(weak.upgrade().is_none());
+}
@@ -0,0 +1,113 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::{
+    intern, intern_stats, set_auto_intern_threshold, set_intern_capacity, unintern,
+};
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen_test]
+fn stats() {
+    let before = intern_stats();
+    intern("intern-stats");
+    JsValue::from_str("intern-stats");
+    JsValue::from_str("intern-stats-miss");
+    let after = intern_stats();
+    assert_eq!(after.hits - before.hits, 1);
+    assert_eq!(after.misses - before.misses, 1);
+    assert_eq!(after.size - before.size, 1);
+
+    unintern("intern-stats");
+    assert_eq!(intern_stats().size, before.size);
+}
+
+#[wasm_bindgen_test]
+fn lru_eviction() {
+    let capacity = intern_stats().capacity;
+    set_intern_capacity(2);
+    assert!(intern_stats().size <= 2);
+
+    intern("lru-a");
+    intern("lru-b");
+    JsValue::from_str("lru-a");
+    let before = intern_stats();
+    intern("lru-c");
+    let after = intern_stats();
+    assert_eq!(after.size, 2);
+    assert_eq!(after.evictions - before.evictions, 1);
+
+    // `lru-b` was the least recently used string, so it was evicted.
+    JsValue::from_str("lru-a");
+    JsValue::from_str("lru-b");
+    JsValue::from_str("lru-c");
+    let end = intern_stats();
+    assert_eq!(end.hits - after.hits, 2);
+    assert_eq!(end.misses - after.misses, 1);
+
+    set_intern_capacity(0);
+    assert_eq!(intern_stats().size, 0);
+    intern("lru-d");
+    assert_eq!(intern_stats().size, 0);
+    set_intern_capacity(capacity);
+}
+
+#[wasm_bindgen_test]
+fn unintern_then_evict() {
+    let capacity = intern_stats().capacity;
+    set_intern_capacity(2);
+
+    intern("unintern-a");
+    intern("unintern-b");
+    unintern("unintern-a");
+    intern("unintern-c");
+    let before = intern_stats();
+    intern("unintern-d");
+    let after = intern_stats();
+    assert_eq!(after.size, 2);
+    assert_eq!(after.evictions - before.evictions, 1);
+
+    // `unintern-b` was evicted, `unintern-c` is still cached.
+    JsValue::from_str("unintern-b");
+    JsValue::from_str("unintern-c");
+    let end = intern_stats();
+    assert_eq!(end.hits - after.hits, 1);
+    assert_eq!(end.misses - after.misses, 1);
+
+    set_intern_capacity(capacity);
+}
+
+#[wasm_bindgen_test]
+fn auto_intern() {
+    set_auto_intern_threshold(Some(3));
+    for _ in 0..3 {
+        JsValue::from_str("auto-interned");
+    }
+    set_auto_intern_threshold(None);
+
+    let before = intern_stats();
+    JsValue::from_str("auto-interned");
+    assert_eq!(intern_stats().hits - before.hits, 1);
+    unintern("auto-interned");
+}
+
+#[wasm_bindgen(inline_js = "export function intern_concat(a, b) { return a + b; }")]
+extern "C" {
+    fn intern_concat(a: &str, b: &str) -> String;
+}
+
+#[wasm_bindgen_test]
+fn auto_intern_evicts_earlier_argument() {
+    let capacity = intern_stats().capacity;
+    set_intern_capacity(1);
+    set_auto_intern_threshold(Some(1));
+
+    // The first argument is passed from the cache, then interning the second
+    // one evicts it before JS reads either of them.
+    intern("evict-a");
+    for _ in 0..3 {
+        assert_eq!(intern_concat("evict-a", "evict-b"), "evict-aevict-b");
+        assert_eq!(intern_concat("evict-b", "evict-a"), "evict-bevict-a");
+    }
+    assert!(intern_stats().evictions > 0);
+
+    set_auto_intern_threshold(None);
+    set_intern_capacity(capacity);
+}
@@ -0,0 +1,15 @@
+const wasm = require('wasm-bindgen-test.js');
+const assert = require('assert');
+
+exports.js_greeting = () => 'hello 😀';
+
+exports.js_works = () => {
+    assert.strictEqual(wasm.rust_len('😀'), 2);
+    assert.strictEqual(wasm.rust_len(''), 0);
+    assert.strictEqual(wasm.rust_identity('abc'), 'abc');
+    assert.strictEqual(wasm.rust_option_len('ab'), 2);
+    assert.strictEqual(wasm.rust_option_len(undefined), undefined);
+    assert.strictEqual(wasm.rust_option_len(null), undefined);
+    assert.strictEqual(wasm.rust_is_comment('// note'), true);
+    assert.strictEqual(wasm.rust_is_comment('let x;'), false);
+};
@@ -0,0 +1,74 @@
+use std::cmp::Ordering;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsStr;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/js_str.js")]
+extern "C" {
+    fn js_greeting() -> JsStr;
+    fn js_works();
+}
+
+#[wasm_bindgen]
+pub fn rust_len(s: &JsStr) -> usize {
+    s.len()
+}
+
+#[wasm_bindgen]
+pub fn rust_identity(s: JsStr) -> JsStr {
+    s
+}
+
+#[wasm_bindgen]
+pub fn rust_option_len(s: Option<JsStr>) -> Option<usize> {
+    s.map(|s| s.len())
+}
+
+#[wasm_bindgen]
+pub fn rust_is_comment(line: &JsStr) -> bool {
+    line.starts_with("//")
+}
+
+#[wasm_bindgen_test]
+fn works() {
+    js_works();
+}
+
+#[wasm_bindgen_test]
+fn lazy_access() {
+    let s = js_greeting();
+    assert_eq!(s.len(), 8);
+    assert!(!s.is_empty());
+    assert_eq!(s.char_code_at(0), Some('h' as u16));
+    assert_eq!(s.char_code_at(8), None);
+    assert_eq!(s.char_at(6), Some('😀'));
+    assert_eq!(s.char_at(7), None);
+    assert!(s.starts_with("hello"));
+    assert!(!s.starts_with("world"));
+    assert_eq!(
+        s.code_units().collect::<Vec<_>>(),
+        "hello 😀".encode_utf16().collect::<Vec<_>>()
+    );
+    assert_eq!(s.code_units().next_back(), "😀".encode_utf16().last());
+    assert_eq!(s.as_str(), "hello 😀");
+    assert_eq!(s.to_string(), "hello 😀");
+}
+
+#[wasm_bindgen_test]
+fn comparisons() {
+    let a = JsStr::from("apple");
+    let b = JsStr::from(String::from("banana"));
+    assert_eq!(a, JsStr::from("apple"));
+    assert_eq!(a, "apple");
+    assert_ne!(a, b);
+    assert_eq!(a.cmp(&b), Ordering::Less);
+    assert_eq!(b.cmp(&a), Ordering::Greater);
+    assert_eq!(a.cmp(&a.clone()), Ordering::Equal);
+}
+
+#[wasm_bindgen_test]
+fn conversions() {
+    assert!(JsStr::try_from(JsValue::from(1)).is_err());
+    let s = JsStr::try_from(JsValue::from("x")).unwrap();
+    assert_eq!(JsValue::from(s), JsValue::from("x"));
+}
@@ -22,8 +22,11 @@ pub mod async_vecs;
 pub mod bigint;
 pub mod char;
 pub mod classes;
+#[cfg(feature = "closure-diagnostics")]
+pub mod closure_diagnostics;
 pub mod closures;
 pub mod comments;
+pub mod derive_js_value;
 pub mod duplicate_deps;
 pub mod duplicates;
 pub mod enum_vecs;
@@ -34,14 +37,18 @@ pub mod futures;
 pub mod gc;
 pub mod generics;
 pub mod getters_and_setters;
+pub mod handles;
 pub mod ignore;
 pub mod import_class;
 pub mod imports;
 pub mod inner_self;
+#[cfg(feature = "enable-interning")]
+pub mod intern;
 pub mod intrinsics;
 pub mod js_keywords;
 pub mod js_namespace_exports;
 pub mod js_objects;
+pub mod js_str;
 pub mod js_vec;
 pub mod jscast;
 pub mod link_to;
@@ -53,6 +60,7 @@ pub mod node;
 pub mod nullable;
 pub mod option;
 pub mod optional_primitives;
+pub mod reentrant;
 pub mod reexport;
 pub mod result;
 pub mod result_jserror;
@@ -66,6 +74,7 @@ pub mod struct_vecs;
 pub mod structural;
 pub mod truthy_falsy;
 pub mod try_from_js_value;
+pub mod typed_array_view;
 #[cfg(all(panic = "unwind", feature = "std"))]
 pub mod unwind;
 pub mod usize;
@@ -0,0 +1,47 @@
+// Calls `count` while `update` holds a mutable borrow of `counter`, returning
+// the error this throws.
+exports.js_reenter_mutable = (counter) => {
+  let error;
+  try {
+    counter.update(() => counter.count());
+  } catch (e) {
+    error = e;
+  }
+  counter.free();
+  return error;
+};
+
+// Calls `update` while two nested calls of `peek` hold shared borrows of
+// `counter`, returning the error this throws.
+exports.js_reenter_nested = (counter) => {
+  let error;
+  try {
+    counter.peek(() => counter.peek(() => counter.update(() => {})));
+  } catch (e) {
+    error = e;
+  }
+  counter.free();
+  return error;
+};
+
+exports.js_reenter_shared = (shared) => {
+  const value = shared.with(() => shared.with(() => shared.value_plus(2)));
+  shared.free();
+  return value;
+};
+
+// Frees `counter` while `peek` holds a shared borrow of it, returning the
+// error this throws.
+exports.js_free_during_call_counter = (counter) => {
+  let error;
+  try {
+    counter.peek(() => counter.free());
+  } catch (e) {
+    error = e;
+  }
+  return error;
+};
+
+// Frees `shared` while `value_after` holds a shared borrow of it, which then
+// still reads its value.
+exports.js_free_during_call_shared = (shared) => shared.value_after(() => shared.free());
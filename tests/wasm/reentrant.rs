@@ -0,0 +1,132 @@
+use js_sys::{Function, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/reentrant.js")]
+extern "C" {
+    fn js_reenter_mutable(counter: ReentrantCounter) -> JsValue;
+    fn js_reenter_nested(counter: ReentrantCounter) -> JsValue;
+    fn js_reenter_shared(shared: ReentrantShared) -> u32;
+    fn js_free_during_call_counter(counter: ReentrantCounter) -> JsValue;
+    fn js_free_during_call_shared(shared: ReentrantShared) -> u32;
+}
+
+#[wasm_bindgen]
+pub struct ReentrantCounter {
+    count: u32,
+}
+
+#[wasm_bindgen]
+impl ReentrantCounter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> ReentrantCounter {
+        ReentrantCounter { count: 0 }
+    }
+
+    pub fn update(&mut self, f: &Function) -> Result<JsValue, JsValue> {
+        self.count += 1;
+        f.call0(&JsValue::NULL)
+    }
+
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    pub fn peek(&self, f: &Function) -> Result<JsValue, JsValue> {
+        f.call0(&JsValue::NULL)
+    }
+}
+
+#[wasm_bindgen(reentrant)]
+pub struct ReentrantShared {
+    #[wasm_bindgen(readonly)]
+    pub value: u32,
+}
+
+#[wasm_bindgen]
+impl ReentrantShared {
+    #[wasm_bindgen(constructor)]
+    pub fn new(value: u32) -> ReentrantShared {
+        ReentrantShared { value }
+    }
+
+    pub fn with(&self, f: &Function) -> Result<JsValue, JsValue> {
+        f.call0(&JsValue::NULL)
+    }
+
+    pub fn value_plus(&self, n: u32) -> u32 {
+        self.value + n
+    }
+
+    pub fn value_after(&self, f: &Function) -> Result<u32, JsValue> {
+        f.call0(&JsValue::NULL)?;
+        Ok(self.value)
+    }
+}
+
+#[wasm_bindgen_test]
+fn conflicting_borrow_names_both_methods() {
+    let err = js_reenter_mutable(ReentrantCounter::new());
+    let message = Reflect::get(&err, &"message".into())
+        .unwrap()
+        .as_string()
+        .unwrap();
+    assert!(message.starts_with("recursive use of an object detected"));
+    if cfg!(debug_assertions) {
+        assert!(
+            message.contains("existing borrow: mutable, by `ReentrantCounter.update`\nError"),
+            "{message}"
+        );
+        assert!(
+            message.contains("new borrow: shared, by `ReentrantCounter.count`"),
+            "{message}"
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+fn conflicting_borrow_names_nested_borrows() {
+    let err = js_reenter_nested(ReentrantCounter::new());
+    let message = Reflect::get(&err, &"message".into())
+        .unwrap()
+        .as_string()
+        .unwrap();
+    assert!(message.starts_with("recursive use of an object detected"));
+    if cfg!(debug_assertions) {
+        assert_eq!(
+            message
+                .matches("existing borrow: shared, by `ReentrantCounter.peek`")
+                .count(),
+            2,
+            "{message}"
+        );
+        assert!(
+            message.contains("new borrow: mutable, by `ReentrantCounter.update`"),
+            "{message}"
+        );
+    }
+}
+
+#[wasm_bindgen_test]
+fn reentrant_shared_borrows() {
+    assert_eq!(js_reenter_shared(ReentrantShared::new(40)), 42);
+}
+
+#[wasm_bindgen_test]
+fn reentrant_free_during_shared_borrow() {
+    // Freeing a struct from a callback of one of its methods claims ownership
+    // of it, which fails while the method borrows it.
+    let err = js_free_during_call_counter(ReentrantCounter::new());
+    let message = Reflect::get(&err, &"message".into())
+        .unwrap()
+        .as_string()
+        .unwrap();
+    assert_eq!(
+        message,
+        "attempted to take ownership of Rust value while it was borrowed"
+    );
+
+    // `reentrant` structs are only ever borrowed shared, so they're freed once
+    // the method borrowing them returns instead.
+    assert_eq!(js_free_during_call_shared(ReentrantShared::new(40)), 40);
+}
@@ -305,3 +305,24 @@ fn externref_heap_live_count() {
     drop(y);
     assert_eq!(x, wasm_bindgen::externref_heap_live_count());
 }
+
+#[wasm_bindgen_test]
+fn closure_live_count() {
+    let x = wasm_bindgen::closure_live_count();
+    let y = Closure::<dyn Fn()>::new(|| {});
+    assert_eq!(wasm_bindgen::closure_live_count(), x + 1);
+    drop(y);
+    assert_eq!(x, wasm_bindgen::closure_live_count());
+}
+
+#[wasm_bindgen_test(check_leaks)]
+fn check_leaks_ok() {
+    let _x = JsValue::from("foo");
+    let _y = Closure::<dyn Fn()>::new(|| {});
+}
+
+#[wasm_bindgen_test(check_leaks)]
+#[should_panic(expected = "test leaked resources:\n  closures: ")]
+fn check_leaks_forgotten_closure() {
+    Closure::<dyn Fn()>::new(|| {}).forget();
+}
@@ -0,0 +1,7 @@
+exports.js_sum = view => view.reduce((sum, x) => sum + Number(x), 0);
+
+exports.js_kind = view => view.constructor.name;
+
+exports.js_byte_length = view => view.byteLength;
+
+exports.js_fill = (view, value) => view.fill(value);
@@ -0,0 +1,53 @@
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::TypedArrayView;
+use wasm_bindgen_test::*;
+
+#[wasm_bindgen(module = "tests/wasm/typed_array_view.js")]
+extern "C" {
+    fn js_sum(view: &TypedArrayView<f32>) -> f64;
+    #[wasm_bindgen(js_name = js_kind)]
+    fn js_kind_u8(view: &TypedArrayView<u8>) -> String;
+    #[wasm_bindgen(js_name = js_kind)]
+    fn js_kind_i64(view: &TypedArrayView<i64>) -> String;
+    #[wasm_bindgen(js_name = js_sum)]
+    fn js_sum_u32(view: &TypedArrayView<u32>) -> f64;
+    fn js_byte_length(view: &TypedArrayView<u32>) -> u32;
+    fn js_fill(view: &TypedArrayView<u8>, value: u8);
+}
+
+#[wasm_bindgen_test]
+fn views_slice() {
+    let mut data = vec![1.0f32, 2.0, 3.5];
+    let view = TypedArrayView::new(&mut data);
+    assert_eq!(js_sum(&view), 6.5);
+
+    let mut bytes = [1u8, 2, 3];
+    assert_eq!(js_kind_u8(&TypedArrayView::new(&mut bytes)), "Uint8Array");
+    let mut ints = [1i64, 2];
+    assert_eq!(
+        js_kind_i64(&TypedArrayView::new(&mut ints)),
+        "BigInt64Array"
+    );
+}
+
+#[wasm_bindgen_test]
+fn writes_are_visible_after_drop() {
+    let mut bytes = [1u8, 2, 3];
+    let view = TypedArrayView::new(&mut bytes);
+    js_fill(&view, 7);
+    drop(view);
+    assert_eq!(bytes, [7, 7, 7]);
+}
+
+#[wasm_bindgen_test]
+fn recreated_after_growth() {
+    let mut data = vec![1u32, 2, 3, 4];
+    let view = TypedArrayView::new(&mut data);
+    assert_eq!(js_byte_length(&view), 16);
+
+    // Allocate enough to force memory to grow.
+    let big = vec![0u8; 16 << 20];
+    assert_eq!(js_byte_length(&view), 16);
+    assert_eq!(js_sum_u32(&view), 10.);
+    drop(big);
+}
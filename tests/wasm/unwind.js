@@ -1,4 +1,5 @@
 const wasm = require('wasm-bindgen-test');
+const assert = require('assert');
 
 // Throws an error - used to test that JS throws trigger Rust unwinding
 exports.js_throw_error = () => {
@@ -21,3 +22,19 @@ exports.js_reset_dropped = () => {
 exports.js_trigger_unwind_test = () => {
   wasm.rust_call_throwing_js();
 };
+
+exports.js_recover_after_panic = () => {
+  const counter = new wasm.UnwindCounter();
+  assert.strictEqual(counter.increment(false), 1);
+
+  assert.throws(() => counter.increment(true), (e) => {
+    assert.ok(e instanceof wasm.PanicError);
+    assert.strictEqual(e.message, 'increment failed');
+    assert.match(e.location, /unwind\.rs:\d+:\d+$/);
+    return true;
+  });
+
+  // The panic released the borrow of `counter`, so it can be used again.
+  assert.strictEqual(counter.increment(false), 2);
+  counter.free();
+};
@@ -318,3 +318,38 @@ fn js_throw_triggers_unwind_and_drop() {
         "Should not have continued after JS throw"
     );
 }
+
+#[wasm_bindgen]
+#[derive(Default)]
+pub struct UnwindCounter {
+    count: u32,
+}
+
+#[wasm_bindgen]
+impl UnwindCounter {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> UnwindCounter {
+        UnwindCounter { count: 0 }
+    }
+
+    pub fn increment(&mut self, fail: bool) -> u32 {
+        if fail {
+            panic!("increment failed");
+        }
+        self.count += 1;
+        self.count
+    }
+}
+
+#[wasm_bindgen(module = "tests/wasm/unwind.js")]
+extern "C" {
+    #[wasm_bindgen(catch)]
+    fn js_recover_after_panic() -> Result<(), JsValue>;
+}
+
+/// A panic in a `&mut self` method fails that call only: the borrow of `self`
+/// is released and the object stays usable.
+#[wasm_bindgen_test]
+fn recover_after_panic() {
+    js_recover_after_panic().unwrap();
+}